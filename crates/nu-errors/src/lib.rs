@@ -90,7 +90,11 @@ pub enum ArgumentError {
     /// The command specified a mandatory flag, but it was missing.
     MissingMandatoryFlag(String),
     /// The command specified a mandatory positional argument, but it was missing.
-    MissingMandatoryPositional(String),
+    MissingMandatoryPositional { name: String, usage: String },
+    /// The command needs at least one rest argument, but none were given.
+    MissingMandatoryRest { usage: String },
+    /// More positional arguments were given than the command accepts.
+    UnexpectedArgument { argument: String, usage: String },
     /// A flag was found, and it should have been followed by a value, but no value was found
     MissingValueForName(String),
     /// A sequence of characters was found that was not syntactically valid (but would have
@@ -106,11 +110,19 @@ impl PrettyDebug for ArgumentError {
                     + b::description(flag)
                     + b::description("` as mandatory flag")
             }
-            ArgumentError::MissingMandatoryPositional(pos) => {
+            ArgumentError::MissingMandatoryPositional { name, .. } => {
                 b::description("missing `")
-                    + b::description(pos)
+                    + b::description(name)
                     + b::description("` as mandatory positional argument")
             }
+            ArgumentError::MissingMandatoryRest { .. } => {
+                b::description("missing mandatory rest arguments")
+            }
+            ArgumentError::UnexpectedArgument { argument, .. } => {
+                b::description("unexpected argument `")
+                    + b::description(argument)
+                    + b::description("`")
+            }
             ArgumentError::MissingValueForName(name) => {
                 b::description("missing value for flag `")
                     + b::description(name)
@@ -421,7 +433,7 @@ impl ShellError {
                     ),
                 )
                 .with_label(Label::new_primary(command.span)),
-                ArgumentError::MissingMandatoryPositional(name) => Diagnostic::new(
+                ArgumentError::MissingMandatoryPositional { name, usage } => Diagnostic::new(
                     Severity::Error,
                     format!(
                         "{} requires {} parameter",
@@ -430,7 +442,28 @@ impl ShellError {
                     ),
                 )
                 .with_label(
-                    Label::new_primary(command.span).with_message(format!("requires {} parameter", name)),
+                    Label::new_primary(command.span).with_message(format!("usage: {}", usage)),
+                ),
+                ArgumentError::MissingMandatoryRest { usage } => Diagnostic::new(
+                    Severity::Error,
+                    format!(
+                        "{} requires at least one argument",
+                        Color::Cyan.paint(&command.item)
+                    ),
+                )
+                .with_label(
+                    Label::new_primary(command.span).with_message(format!("usage: {}", usage)),
+                ),
+                ArgumentError::UnexpectedArgument { argument, usage } => Diagnostic::new(
+                    Severity::Error,
+                    format!(
+                        "{} got an unexpected argument {}",
+                        Color::Cyan.paint(&command.item),
+                        Color::Green.bold().paint(argument.clone())
+                    ),
+                )
+                .with_label(
+                    Label::new_primary(command.span).with_message(format!("usage: {}", usage)),
                 ),
                 ArgumentError::MissingValueForName(name) => Diagnostic::new(
                     Severity::Error,
@@ -2,13 +2,14 @@ use crate::commands::classified::{ClassifiedCommand, InternalCommand};
 use crate::hir::TokensIterator;
 use crate::hir::{self, named::NamedValue, syntax_shape::*, NamedArguments};
 use crate::parse::files::Files;
+use crate::parse::operator::Operator;
 use crate::parse::token_tree_builder::{CurriedToken, TokenTreeBuilder as b};
 use crate::TokenNode;
 use derive_new::new;
 use indexmap::IndexMap;
-use nu_errors::ShellError;
+use nu_errors::{ArgumentError, ShellError};
 use nu_protocol::{PathMember, Signature, SyntaxShape};
-use nu_source::{HasSpan, Span, Tag, Text};
+use nu_source::{HasSpan, Span, SpannedItem, Tag, Text};
 use pretty_assertions::assert_eq;
 use std::fmt::Debug;
 
@@ -61,6 +62,35 @@ fn test_parse_path() {
     );
 }
 
+#[test]
+fn test_parse_bare_word() {
+    let tokens = b::token_list(vec![b::bare("hello")]);
+    let (tokens, source) = b::build(tokens);
+    let text = Text::from(source);
+
+    with_empty_context(&text, |context| {
+        let tokens = tokens.expect_list();
+        let mut iterator = TokensIterator::all(tokens.item, text.clone(), tokens.span);
+
+        let expr = expand_expr(&AnyExpressionShape, &mut iterator, &context);
+
+        assert_eq!(
+            expr.map_err(|_| "parse failed"),
+            Ok(hir::Expression::bare(tokens.item[0].span()))
+        );
+    });
+
+    with_empty_context(&text, |context| {
+        let context = context.strict(true);
+        let tokens = tokens.expect_list();
+        let mut iterator = TokensIterator::all(tokens.item, text.clone(), tokens.span);
+
+        let expr = expand_expr(&AnyExpressionShape, &mut iterator, &context);
+
+        assert!(expr.is_err());
+    });
+}
+
 #[test]
 fn test_parse_command() {
     parse_tokens(
@@ -90,6 +120,161 @@ fn test_parse_command() {
     );
 }
 
+#[test]
+fn test_parse_command_missing_argument() {
+    parse_error(ClassifiedCommandShape, vec![b::bare("nth")], |tokens| {
+        ShellError::argument_error(
+            "nth".spanned(tokens[0].span()),
+            ArgumentError::MissingMandatoryPositional {
+                name: "row number".to_string(),
+                usage: "nth <row number>".to_string(),
+            },
+        )
+    });
+
+    parse_error(ClassifiedCommandShape, vec![b::bare("pick")], |tokens| {
+        ShellError::argument_error(
+            "pick".spanned(tokens[0].span()),
+            ArgumentError::MissingMandatoryRest {
+                usage: "pick ...args".to_string(),
+            },
+        )
+    });
+
+    parse_error(ClassifiedCommandShape, vec![b::bare("scale")], |tokens| {
+        ShellError::argument_error(
+            "scale".spanned(tokens[0].span()),
+            ArgumentError::MissingMandatoryRest {
+                usage: "scale ...args {flags}".to_string(),
+            },
+        )
+    });
+}
+
+#[test]
+fn test_parse_command_unexpected_argument() {
+    parse_error(
+        ClassifiedCommandShape,
+        vec![
+            b::bare("ls"),
+            b::sp(),
+            b::pattern("*.txt"),
+            b::sp(),
+            b::bare("extra"),
+        ],
+        |tokens| {
+            ShellError::argument_error(
+                "ls".spanned(tokens[0].span()),
+                ArgumentError::UnexpectedArgument {
+                    argument: "extra".to_string(),
+                    usage: "ls (path) {flags}".to_string(),
+                },
+            )
+        },
+    );
+}
+
+#[test]
+fn test_parse_command_plus_as_argument() {
+    parse_tokens(
+        ClassifiedCommandShape,
+        vec![b::bare("echo"), b::sp(), b::op("+")],
+        |tokens| {
+            let bare = tokens[0].expect_bare();
+            let plus = tokens[2].span();
+
+            ClassifiedCommand::Internal(InternalCommand::new(
+                "echo".to_string(),
+                Tag {
+                    span: bare,
+                    anchor: None,
+                },
+                hir::Call {
+                    head: Box::new(hir::RawExpression::Command(bare).into_expr(bare)),
+                    positional: Some(vec![hir::Expression::bare(plus)]),
+                    named: None,
+                    span: bare.until(plus),
+                },
+            ))
+        },
+    );
+
+    parse_tokens(
+        ClassifiedCommandShape,
+        vec![
+            b::bare("str-collect"),
+            b::sp(),
+            b::flag("separator"),
+            b::sp(),
+            b::op("+"),
+        ],
+        |tokens| {
+            let bare = tokens[0].expect_bare();
+            let plus = tokens[4].span();
+
+            let mut map = IndexMap::new();
+            map.insert(
+                "separator".to_string(),
+                NamedValue::Value(hir::Expression::bare(plus)),
+            );
+
+            ClassifiedCommand::Internal(InternalCommand::new(
+                "str-collect".to_string(),
+                Tag {
+                    span: bare,
+                    anchor: None,
+                },
+                hir::Call {
+                    head: Box::new(hir::RawExpression::Command(bare).into_expr(bare)),
+                    positional: None,
+                    named: Some(NamedArguments { named: map }),
+                    span: bare.until(tokens[1].span()),
+                },
+            ))
+        },
+    );
+}
+
+#[test]
+fn test_parse_command_plus_between_operands() {
+    parse_tokens(
+        ClassifiedCommandShape,
+        vec![
+            b::bare("echo"),
+            b::sp(),
+            b::var("acc"),
+            b::sp(),
+            b::op("+"),
+            b::sp(),
+            b::var("it"),
+        ],
+        |tokens| {
+            let bare = tokens[0].expect_bare();
+            let (outer_acc, inner_acc) = tokens[2].expect_var();
+            let plus = tokens[4].span();
+            let (outer_it, inner_it) = tokens[6].expect_var();
+
+            ClassifiedCommand::Internal(InternalCommand::new(
+                "echo".to_string(),
+                Tag {
+                    span: bare,
+                    anchor: None,
+                },
+                hir::Call {
+                    head: Box::new(hir::RawExpression::Command(bare).into_expr(bare)),
+                    positional: Some(vec![hir::Expression::infix(
+                        hir::Expression::variable(inner_acc, outer_acc),
+                        Operator::Plus.spanned(plus),
+                        hir::Expression::it_variable(inner_it, outer_it),
+                    )]),
+                    named: None,
+                    span: bare.until(outer_it),
+                },
+            ))
+        },
+    );
+}
+
 #[derive(new)]
 struct TestRegistry {
     #[new(default)]
@@ -123,6 +308,41 @@ fn with_empty_context(source: &Text, callback: impl FnOnce(ExpandContext)) {
             )
             .switch("full", "list all available columns for each entry"),
     );
+    registry.insert(
+        "nth",
+        Signature::build("nth").required(
+            "row number",
+            SyntaxShape::Int,
+            "the number of the row to return",
+        ),
+    );
+    registry.insert(
+        "pick",
+        Signature::build("pick").required_rest(SyntaxShape::Any, "the columns to select"),
+    );
+    registry.insert(
+        "scale",
+        Signature::build("scale")
+            .required_rest(SyntaxShape::String, "the numeric columns to rescale")
+            .named(
+                "method",
+                SyntaxShape::String,
+                "minmax (the default) or zscore",
+            ),
+    );
+
+    registry.insert(
+        "echo",
+        Signature::build("echo").rest(SyntaxShape::Any, "the values to echo"),
+    );
+    registry.insert(
+        "str-collect",
+        Signature::build("str-collect").named(
+            "separator",
+            SyntaxShape::String,
+            "the text to put between the strings",
+        ),
+    );
 
     callback(ExpandContext::new(Box::new(registry), source, None))
 }
@@ -154,6 +374,26 @@ fn parse_tokens<T: Eq + HasSpan + Clone + Debug + 'static>(
     })
 }
 
+fn parse_error<T: Debug + 'static>(
+    shape: impl ExpandSyntax<Output = T>,
+    tokens: Vec<CurriedToken>,
+    expected: impl FnOnce(&[TokenNode]) -> ShellError,
+) {
+    let tokens = b::token_list(tokens);
+    let (tokens, source) = b::build(tokens);
+    let text = Text::from(source);
+
+    with_empty_context(&text, |context| {
+        let tokens = tokens.expect_list();
+        let mut iterator = TokensIterator::all(tokens.item, text.clone(), tokens.span);
+
+        match expand_syntax(&shape, &mut iterator, &context) {
+            Ok(expr) => panic!("Expected a parse error, got {:?}", expr),
+            Err(err) => assert_eq!(ShellError::from(err), expected(tokens.item)),
+        }
+    })
+}
+
 fn inner_string_span(span: Span) -> Span {
     Span::new(span.start() + 1, span.end() - 1)
 }
@@ -33,6 +33,7 @@ pub(crate) use self::expression::list::{BackoffColoringMode, ExpressionListShape
 pub(crate) use self::expression::number::{IntShape, NumberShape};
 pub(crate) use self::expression::pattern::{BarePatternShape, PatternShape};
 pub(crate) use self::expression::string::StringShape;
+pub use self::expression::unit::parse_unit;
 pub(crate) use self::expression::unit::{UnitShape, UnitSyntax};
 pub(crate) use self::expression::variable_path::{
     ColorableDotShape, ColumnPathShape, ColumnPathSyntax, DotShape, ExpressionContinuation,
@@ -186,12 +187,24 @@ pub struct ExpandContext<'context> {
     #[get = "pub(crate)"]
     source: &'context Text,
     homedir: Option<PathBuf>,
+    #[new(default)]
+    strict: bool,
 }
 
 impl<'context> ExpandContext<'context> {
     pub(crate) fn homedir(&self) -> Option<&Path> {
         self.homedir.as_ref().map(|h| h.as_path())
     }
+
+    /// In strict mode, a bare word where a value is expected is an error instead of a string.
+    pub fn strict(mut self, strict: bool) -> ExpandContext<'context> {
+        self.strict = strict;
+        self
+    }
+
+    pub(crate) fn is_strict(&self) -> bool {
+        self.strict
+    }
 }
 
 pub trait TestSyntax: std::fmt::Debug + Copy {
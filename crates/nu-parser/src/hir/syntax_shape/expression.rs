@@ -188,7 +188,12 @@ impl ExpandExpression for AnyExpressionStartShape {
         token_nodes: &mut TokensIterator<'_>,
         context: &ExpandContext,
     ) -> Result<hir::Expression, ParseError> {
-        let atom = expand_atom(token_nodes, "expression", context, ExpansionRule::new())?;
+        let rule = match context.is_strict() {
+            true => ExpansionRule::new().reject_bare_strings(),
+            false => ExpansionRule::new(),
+        };
+
+        let atom = expand_atom(token_nodes, "expression", context, rule)?;
 
         match atom.unspanned {
             UnspannedAtomicToken::Size { number, unit } => {
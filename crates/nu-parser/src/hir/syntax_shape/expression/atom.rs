@@ -3,6 +3,7 @@ use crate::hir::syntax_shape::{
     expand_syntax, expression::expand_file_path, parse_single_node, BarePathShape,
     BarePatternShape, ExpandContext, UnitShape, UnitSyntax,
 };
+use crate::parse::operator::Operator;
 use crate::parse::token_tree::{DelimitedNode, Delimiter, TokenNode};
 use crate::parse::tokens::UnspannedToken;
 use crate::parse::unit::Unit;
@@ -336,6 +337,7 @@ pub struct ExpansionRule {
     pub(crate) treat_size_as_word: bool,
     pub(crate) separate_members: bool,
     pub(crate) commit_errors: bool,
+    pub(crate) reject_bare_strings: bool,
     pub(crate) whitespace: WhitespaceHandling,
 }
 
@@ -349,6 +351,7 @@ impl ExpansionRule {
             treat_size_as_word: false,
             separate_members: false,
             commit_errors: false,
+            reject_bare_strings: false,
             whitespace: WhitespaceHandling::RejectWhitespace,
         }
     }
@@ -365,6 +368,7 @@ impl ExpansionRule {
             separate_members: false,
             treat_size_as_word: false,
             commit_errors: true,
+            reject_bare_strings: false,
             whitespace: WhitespaceHandling::AllowWhitespace,
         }
     }
@@ -429,6 +433,12 @@ impl ExpansionRule {
         self
     }
 
+    /// Bare words are errors rather than words, so that strings have to be quoted.
+    pub fn reject_bare_strings(mut self) -> ExpansionRule {
+        self.reject_bare_strings = true;
+        self
+    }
+
     #[allow(unused)]
     pub fn allow_whitespace(mut self) -> ExpansionRule {
         self.whitespace = WhitespaceHandling::AllowWhitespace;
@@ -543,6 +553,9 @@ fn expand_atom_inner<'me, 'content>(
                     // word, and we should try to parse it as a glob next
                 }
 
+                // in strict mode, a word has to be quoted to be used as a string
+                _ if rule.reject_bare_strings => return Err(bare_string_error(span)),
+
                 _ => return Ok(UnspannedAtomicToken::Word { text: span }.into_atomic_token(span)),
             }
         }
@@ -649,6 +662,11 @@ fn expand_atom_inner<'me, 'content>(
             // First, the error cases. Each error case corresponds to a expansion rule
             // flag that can be used to allow the case
 
+            // `+` is only lexed as an operator so it can go between two operands;
+            // anywhere an operator isn't allowed it's still the word it used to be
+            UnspannedToken::Operator(Operator::Plus) if !rule.allow_operator => {
+                UnspannedAtomicToken::Word { text: token_span }.into_atomic_token(token_span)
+            }
             // rule.allow_operator
             UnspannedToken::Operator(_) if !rule.allow_operator => return Err(err.error()),
             // rule.allow_external_command
@@ -665,6 +683,10 @@ fn expand_atom_inner<'me, 'content>(
                     "external word".spanned(token_span),
                 ))
             }
+            // rule.reject_bare_strings
+            UnspannedToken::Bare if rule.reject_bare_strings => {
+                return Err(bare_string_error(token_span))
+            }
 
             UnspannedToken::Number(number) => {
                 UnspannedAtomicToken::Number { number }.into_atomic_token(token_span)
@@ -696,3 +718,10 @@ fn expand_atom_inner<'me, 'content>(
         })
     })
 }
+
+fn bare_string_error(span: Span) -> ParseError {
+    ParseError::mismatch(
+        "quoted string (strict mode needs quotes around words)",
+        "bare word".spanned(span),
+    )
+}
@@ -3,6 +3,7 @@ use crate::hir::syntax_shape::{
     ExpansionRule, FallibleColorSyntax, FlatShape, TestSyntax, UnspannedAtomicToken,
 };
 use crate::hir::tokens_iterator::Peeked;
+use crate::parse::operator::Operator;
 use crate::parse::tokens::UnspannedToken;
 use crate::{hir, hir::TokensIterator};
 use nu_errors::{ParseError, ShellError};
@@ -90,6 +91,7 @@ impl ExpandExpression for StringShape {
     ) -> Result<hir::Expression, ParseError> {
         parse_single_node(token_nodes, "String", |token, token_span, err| {
             Ok(match token {
+                UnspannedToken::Operator(Operator::Plus) => hir::Expression::bare(token_span),
                 UnspannedToken::GlobPattern
                 | UnspannedToken::Operator(..)
                 | UnspannedToken::ExternalWord => return Err(err.error()),
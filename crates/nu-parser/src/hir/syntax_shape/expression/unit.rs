@@ -1,4 +1,5 @@
 use crate::hir::syntax_shape::{ExpandContext, ExpandSyntax};
+use crate::parse::parser::Number;
 use crate::parse::tokens::RawNumber;
 use crate::parse::tokens::Token;
 use crate::parse::tokens::UnspannedToken;
@@ -10,7 +11,9 @@ use nom::character::complete::digit1;
 use nom::combinator::{all_consuming, opt, value};
 use nom::IResult;
 use nu_errors::ParseError;
-use nu_source::{b, DebugDocBuilder, HasSpan, PrettyDebugWithSource, Span, Spanned, SpannedItem};
+use nu_source::{
+    b, DebugDocBuilder, HasSpan, PrettyDebugWithSource, Span, Spanned, SpannedItem, Text,
+};
 
 #[derive(Debug, Clone)]
 pub struct UnitSyntax {
@@ -73,6 +76,14 @@ impl ExpandSyntax for UnitShape {
     }
 }
 
+/// Parses a size or duration such as `5kb` or `10s`, accepting the same forms as `UnitShape`.
+pub fn parse_unit(input: &str) -> Option<(Number, Unit)> {
+    match unit_size(input, Span::new(0, input.len())) {
+        Ok((_, (number, unit))) => Some((number.to_number(&Text::from(input)), unit.item)),
+        Err(_) => None,
+    }
+}
+
 fn unit_size(input: &str, bare_span: Span) -> IResult<&str, (RawNumber, Spanned<Unit>)> {
     let (input, digits) = digit1(input)?;
 
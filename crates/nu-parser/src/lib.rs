@@ -6,7 +6,9 @@ pub mod parse_command;
 pub use crate::commands::classified::{ClassifiedCommand, ClassifiedPipeline, InternalCommand};
 pub use crate::commands::ExternalCommand;
 pub use crate::hir::syntax_shape::flat_shape::FlatShape;
-pub use crate::hir::syntax_shape::{expand_syntax, ExpandSyntax, PipelineShape, SignatureRegistry};
+pub use crate::hir::syntax_shape::{
+    expand_syntax, parse_unit, ExpandSyntax, PipelineShape, SignatureRegistry,
+};
 pub use crate::hir::tokens_iterator::TokensIterator;
 pub use crate::parse::files::Files;
 pub use crate::parse::flag::Flag;
@@ -15,6 +17,7 @@ pub use crate::parse::parser::pipeline;
 pub use crate::parse::parser::Number;
 pub use crate::parse::token_tree::{Delimiter, TokenNode};
 pub use crate::parse::token_tree_builder::TokenTreeBuilder;
+pub use crate::parse::unit::Unit;
 
 use nu_errors::ShellError;
 use nu_source::nom_input;
@@ -14,6 +14,8 @@ pub enum Operator {
     Dot,
     Contains,
     NotContains,
+    Plus,
+    Minus,
 }
 
 impl PrettyDebug for Operator {
@@ -38,6 +40,8 @@ impl Operator {
             Operator::Dot => ".",
             Operator::Contains => "=~",
             Operator::NotContains => "!~",
+            Operator::Plus => "+",
+            Operator::Minus => "-",
         }
     }
 }
@@ -61,6 +65,8 @@ impl FromStr for Operator {
             "." => Ok(Operator::Dot),
             "=~" => Ok(Operator::Contains),
             "!~" => Ok(Operator::NotContains),
+            "+" => Ok(Operator::Plus),
+            "-" => Ok(Operator::Minus),
             _ => Err(()),
         }
     }
@@ -238,9 +238,29 @@ pub fn raw_number(input: NomSpan) -> IResult<NomSpan, RawNumber> {
     }
 }
 
+/// `+` is only an operator on its own, so words like `+%s` still reach externals intact.
+#[tracable_parser]
+pub fn plus(input: NomSpan) -> IResult<NomSpan, TokenNode> {
+    let start = input.offset;
+    let (input, tag) = tag("+")(input)?;
+    let end = input.offset;
+
+    if !is_boundary(input.fragment.chars().next()) {
+        return Err(nom::Err::Error(nom::error::make_error(
+            input,
+            nom::error::ErrorKind::Tag,
+        )));
+    }
+
+    Ok((
+        input,
+        TokenTreeBuilder::spanned_op(tag.fragment, Span::new(start, end)),
+    ))
+}
+
 #[tracable_parser]
 pub fn operator(input: NomSpan) -> IResult<NomSpan, TokenNode> {
-    let (input, operator) = alt((gte, lte, neq, gt, lt, eq, cont, ncont))(input)?;
+    let (input, operator) = alt((gte, lte, neq, gt, lt, eq, cont, ncont, plus))(input)?;
 
     Ok((input, operator))
 }
@@ -846,6 +866,11 @@ mod tests {
             "=~" -> b::token_list(vec![b::op("=~")])
         }
 
+        equal_tokens! {
+            <nodes>
+            "+" -> b::token_list(vec![b::op("+")])
+        }
+
         equal_tokens! {
             <nodes>
             "!~" -> b::token_list(vec![b::op("!~")])
@@ -1145,6 +1170,13 @@ mod tests {
             ]])
         );
 
+        equal_tokens!(
+            "date +%s" ->
+            b::pipeline(vec![vec![
+                b::bare("date"), b::sp(), b::external_word("+%s")
+            ]])
+        );
+
         equal_tokens!(
             "rm foo%bar" ->
             b::pipeline(vec![vec![
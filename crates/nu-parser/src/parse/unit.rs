@@ -40,6 +40,43 @@ fn convert_number_to_u64(number: &Number) -> u64 {
 }
 
 impl Unit {
+    /// Every unit the parser recognizes, filesizes first
+    pub fn all() -> &'static [Unit] {
+        &[
+            Unit::Byte,
+            Unit::Kilobyte,
+            Unit::Megabyte,
+            Unit::Gigabyte,
+            Unit::Terabyte,
+            Unit::Petabyte,
+            Unit::Second,
+            Unit::Minute,
+            Unit::Hour,
+            Unit::Day,
+            Unit::Week,
+            Unit::Month,
+            Unit::Year,
+        ]
+    }
+
+    pub fn category(&self) -> &str {
+        match *self {
+            Unit::Byte
+            | Unit::Kilobyte
+            | Unit::Megabyte
+            | Unit::Gigabyte
+            | Unit::Terabyte
+            | Unit::Petabyte => "bytesize",
+            Unit::Second
+            | Unit::Minute
+            | Unit::Hour
+            | Unit::Day
+            | Unit::Week
+            | Unit::Month
+            | Unit::Year => "duration",
+        }
+    }
+
     pub fn as_str(&self) -> &str {
         match *self {
             Unit::Byte => "B",
@@ -8,7 +8,7 @@ use crate::{
     Flag,
 };
 use log::trace;
-use nu_source::{PrettyDebugWithSource, Span, Spanned, SpannedItem, Text};
+use nu_source::{HasSpan, PrettyDebugWithSource, Span, Spanned, SpannedItem, Text};
 
 use nu_errors::{ArgumentError, ParseError};
 use nu_protocol::{NamedType, PositionalType, Signature};
@@ -95,7 +95,10 @@ pub fn parse_command_tail(
                 if tail.at_end_possible_ws() {
                     return Err(ParseError::argument_error(
                         config.name.clone().spanned(command_span),
-                        ArgumentError::MissingMandatoryPositional(arg.0.name().to_string()),
+                        ArgumentError::MissingMandatoryPositional {
+                            name: arg.0.name().to_string(),
+                            usage: config.one_liner(),
+                        },
                     ));
                 }
             }
@@ -117,6 +120,15 @@ pub fn parse_command_tail(
     if let Some((syntax_type, _)) = config.rest_positional {
         let mut out = vec![];
 
+        if config.rest_required && tail.at_end_possible_ws() {
+            return Err(ParseError::argument_error(
+                config.name.clone().spanned(command_span),
+                ArgumentError::MissingMandatoryRest {
+                    usage: config.one_liner(),
+                },
+            ));
+        }
+
         loop {
             if tail.at_end_possible_ws() {
                 break;
@@ -132,6 +144,16 @@ pub fn parse_command_tail(
 
     trace_remaining("after rest", &tail, context.source());
 
+    if let Some(extra) = tail.peek_non_ws().node {
+        return Err(ParseError::argument_error(
+            config.name.clone().spanned(command_span),
+            ArgumentError::UnexpectedArgument {
+                argument: extra.span().slice(context.source()).to_string(),
+                usage: config.one_liner(),
+            },
+        ));
+    }
+
     trace!(target: "nu::parse", "Constructed positional={:?} named={:?}", positional, named);
 
     let positional = if positional.len() == 0 {
@@ -140,8 +162,6 @@ pub fn parse_command_tail(
         Some(positional)
     };
 
-    // TODO: Error if extra unconsumed positional arguments
-
     let named = if named.named.is_empty() {
         None
     } else {
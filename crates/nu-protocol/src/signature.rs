@@ -75,6 +75,8 @@ pub struct Signature {
     pub usage: String,
     pub positional: Vec<(PositionalType, Description)>,
     pub rest_positional: Option<(SyntaxShape, Description)>,
+    #[serde(default)]
+    pub rest_required: bool,
     pub named: IndexMap<String, (NamedType, Description)>,
     pub is_filter: bool,
 }
@@ -104,6 +106,7 @@ impl Signature {
             usage: String::new(),
             positional: vec![],
             rest_positional: None,
+            rest_required: false,
             named: IndexMap::new(),
             is_filter: false,
         }
@@ -186,4 +189,33 @@ impl Signature {
         self.rest_positional = Some((ty, desc.into()));
         self
     }
+
+    /// Like `rest`, but at least one argument has to be given
+    pub fn required_rest(mut self, ty: SyntaxShape, desc: impl Into<String>) -> Signature {
+        self.rest_positional = Some((ty, desc.into()));
+        self.rest_required = true;
+        self
+    }
+
+    /// A one line summary of how the command is called, eg `pick ...args`
+    pub fn one_liner(&self) -> String {
+        let mut parts = vec![self.name.clone()];
+
+        for positional in &self.positional {
+            match &positional.0 {
+                PositionalType::Mandatory(name, _m) => parts.push(format!("<{}>", name)),
+                PositionalType::Optional(name, _o) => parts.push(format!("({})", name)),
+            }
+        }
+
+        if self.rest_positional.is_some() {
+            parts.push("...args".to_string());
+        }
+
+        if self.named.len() > 0 {
+            parts.push("{flags}".to_string());
+        }
+
+        parts.join(" ")
+    }
 }
@@ -235,6 +235,7 @@ pub async fn cli() -> Result<(), Box<dyn Error>> {
         context.add_commands(vec![
             whole_stream_command(PWD),
             whole_stream_command(LS),
+            whole_stream_command(List),
             whole_stream_command(CD),
             whole_stream_command(Size),
             whole_stream_command(Nth),
@@ -309,6 +310,100 @@ pub async fn cli() -> Result<(), Box<dyn Error>> {
             whole_stream_command(What),
             whole_stream_command(Which),
             whole_stream_command(Debug),
+            whole_stream_command(Tail),
+            whole_stream_command(Sniff),
+            whole_stream_command(Partition),
+            whole_stream_command(StrCollect),
+            whole_stream_command(Pad),
+            whole_stream_command(ChunkBytes),
+            whole_stream_command(CumulativeSum),
+            whole_stream_command(ParseUnit),
+            whole_stream_command(Diff),
+            whole_stream_command(PivotWide),
+            whole_stream_command(Every),
+            whole_stream_command(ToTree),
+            whole_stream_command(Query),
+            whole_stream_command(RowsToRecord),
+            whole_stream_command(UpdateCells),
+            whole_stream_command(SplitDocs),
+            whole_stream_command(Summary),
+            whole_stream_command(NormalizeColumns),
+            whole_stream_command(ToMD),
+            whole_stream_command(ToHTML),
+            whole_stream_command(ExpandBytes),
+            whole_stream_command(Unify),
+            whole_stream_command(SplitPaths),
+            whole_stream_command(PathJoin),
+            whole_stream_command(PathParse),
+            whole_stream_command(PathSetExtension),
+            whole_stream_command(PathExpand),
+            whole_stream_command(PathType),
+            whole_stream_command(PathCanonicalize),
+            whole_stream_command(Du),
+            whole_stream_command(Watch),
+            whole_stream_command(Timeout),
+            whole_stream_command(Retry),
+            whole_stream_command(Benchmark),
+            whole_stream_command(Seq),
+            whole_stream_command(Repeat),
+            whole_stream_command(Cross),
+            whole_stream_command(Sample),
+            whole_stream_command(Frequency),
+            whole_stream_command(Items),
+            whole_stream_command(IntoBool),
+            whole_stream_command(Units),
+            whole_stream_command(ConvertUnit),
+            whole_stream_command(Capture),
+            whole_stream_command(StrDistance),
+            whole_stream_command(FuzzyFind),
+            whole_stream_command(Progress),
+            whole_stream_command(SortByKey),
+            whole_stream_command(Uniq),
+            whole_stream_command(DedupAdjacent),
+            whole_stream_command(Reduce),
+            whole_stream_command(Describe),
+            whole_stream_command(Unflatten),
+            whole_stream_command(Percentile),
+            whole_stream_command(Bin),
+            whole_stream_command(Schema),
+            whole_stream_command(ToFixedWidth),
+            whole_stream_command(FromCookie),
+            whole_stream_command(ToCookie),
+            whole_stream_command(MovingAverage),
+            whole_stream_command(SniffDelimiter),
+            whole_stream_command(SplitInto),
+            whole_stream_command(Correlation),
+            whole_stream_command(BaseConvert),
+            whole_stream_command(WrapText),
+            whole_stream_command(Delta),
+            whole_stream_command(Concat),
+            whole_stream_command(Mode),
+            whole_stream_command(Scale),
+            whole_stream_command(ToJSONL),
+            whole_stream_command(IntoDate),
+            whole_stream_command(Values),
+            whole_stream_command(Crosstab),
+            whole_stream_command(RowDiff),
+            whole_stream_command(ToFraction),
+            whole_stream_command(FromFraction),
+            whole_stream_command(Round),
+            whole_stream_command(Abs),
+            whole_stream_command(Sign),
+            whole_stream_command(Clamp),
+            whole_stream_command(Math),
+            whole_stream_command(StrCase),
+            whole_stream_command(Zfill),
+            whole_stream_command(StrSubstring),
+            whole_stream_command(StrIndexOf),
+            whole_stream_command(StrStartsWith),
+            whole_stream_command(StrEndsWith),
+            whole_stream_command(FillNulls),
+            whole_stream_command(SchemaDiff),
+            whole_stream_command(ToSQL),
+            whole_stream_command(JsonPointer),
+            whole_stream_command(CumulativeMin),
+            whole_stream_command(CumulativeMax),
+            whole_stream_command(Outliers),
         ]);
 
         cfg_if::cfg_if! {
@@ -601,7 +696,7 @@ async fn process_line(readline: Result<String, ReadlineError>, ctx: &mut Context
     }
 }
 
-fn classify_pipeline(
+pub(crate) fn classify_pipeline(
     pipeline: &TokenNode,
     context: &Context,
     source: &Text,
@@ -1,3 +1,34 @@
+pub(crate) mod abs;
+pub(crate) mod base_convert;
+pub(crate) mod benchmark;
+pub(crate) mod bin;
+pub(crate) mod capture;
+pub(crate) mod chunk_bytes;
+pub(crate) mod clamp;
+pub(crate) mod concat;
+pub(crate) mod convert_unit;
+pub(crate) mod correlation;
+pub(crate) mod cross;
+pub(crate) mod crosstab;
+pub(crate) mod cumulative_max;
+pub(crate) mod cumulative_min;
+pub(crate) mod cumulative_sum;
+pub(crate) mod dedup_adjacent;
+pub(crate) mod delta;
+pub(crate) mod describe;
+pub(crate) mod diff;
+pub(crate) mod du;
+pub(crate) mod every;
+pub(crate) mod expand_bytes;
+pub(crate) mod fill_nulls;
+pub(crate) mod frequency;
+pub(crate) mod from_cookie;
+pub(crate) mod from_fraction;
+pub(crate) mod fuzzy_find;
+pub(crate) mod into_bool;
+pub(crate) mod into_date;
+pub(crate) mod items;
+pub(crate) mod json_pointer;
 #[macro_use]
 pub(crate) mod macros;
 
@@ -45,54 +76,125 @@ pub(crate) mod histogram;
 pub(crate) mod history;
 pub(crate) mod last;
 pub(crate) mod lines;
+pub(crate) mod list;
 pub(crate) mod ls;
 #[allow(unused)]
 pub(crate) mod map_max_by;
+pub(crate) mod math;
 pub(crate) mod mkdir;
+pub(crate) mod mode;
+pub(crate) mod moving_average;
 pub(crate) mod mv;
 pub(crate) mod next;
+pub(crate) mod normalize_columns;
 pub(crate) mod nth;
 pub(crate) mod open;
+pub(crate) mod outliers;
+pub(crate) mod pad;
+pub(crate) mod parse_unit;
+pub(crate) mod partition;
+pub(crate) mod path_canonicalize;
+pub(crate) mod path_expand;
+pub(crate) mod path_join;
+pub(crate) mod path_parse;
+pub(crate) mod path_set_extension;
+pub(crate) mod path_type;
+pub(crate) mod percentile;
 pub(crate) mod pick;
 pub(crate) mod pivot;
+pub(crate) mod pivot_wide;
 pub(crate) mod plugin;
 pub(crate) mod post;
 pub(crate) mod prepend;
 pub(crate) mod prev;
+pub(crate) mod progress;
 pub(crate) mod pwd;
+pub(crate) mod query;
+pub(crate) mod reduce;
 #[allow(unused)]
 pub(crate) mod reduce_by;
 pub(crate) mod reject;
+pub(crate) mod repeat;
+pub(crate) mod retry;
 pub(crate) mod reverse;
 pub(crate) mod rm;
+pub(crate) mod round;
+pub(crate) mod row_diff;
+pub(crate) mod rows_to_record;
+pub(crate) mod sample;
 pub(crate) mod save;
+pub(crate) mod scale;
+pub(crate) mod schema;
+pub(crate) mod schema_diff;
+pub(crate) mod seq;
 pub(crate) mod shells;
+pub(crate) mod sign;
 pub(crate) mod size;
 pub(crate) mod skip_while;
+pub(crate) mod sniff;
+pub(crate) mod sniff_delimiter;
 pub(crate) mod sort_by;
+pub(crate) mod sort_by_key;
 pub(crate) mod split_by;
 pub(crate) mod split_column;
+pub(crate) mod split_docs;
+pub(crate) mod split_into;
+pub(crate) mod split_paths;
 pub(crate) mod split_row;
+pub(crate) mod str_case;
+pub(crate) mod str_collect;
+pub(crate) mod str_distance;
+pub(crate) mod str_ends_with;
+pub(crate) mod str_index_of;
+pub(crate) mod str_starts_with;
+pub(crate) mod str_substring;
+pub(crate) mod summary;
 #[allow(unused)]
 pub(crate) mod t_sort_by;
 pub(crate) mod table;
 pub(crate) mod tags;
+pub(crate) mod tail;
+pub(crate) mod timeout;
 pub(crate) mod to_bson;
+pub(crate) mod to_cookie;
 pub(crate) mod to_csv;
+pub(crate) mod to_fixed_width;
+pub(crate) mod to_fraction;
+pub(crate) mod to_html;
 pub(crate) mod to_json;
+pub(crate) mod to_jsonl;
+pub(crate) mod to_md;
+pub(crate) mod to_sql;
 pub(crate) mod to_sqlite;
 pub(crate) mod to_toml;
+pub(crate) mod to_tree;
 pub(crate) mod to_tsv;
 pub(crate) mod to_url;
 pub(crate) mod to_yaml;
 pub(crate) mod trim;
+pub(crate) mod unflatten;
+pub(crate) mod unify;
+pub(crate) mod uniq;
+pub(crate) mod units;
+pub(crate) mod update_cells;
+pub(crate) mod values;
 pub(crate) mod version;
+pub(crate) mod watch;
 pub(crate) mod what;
 pub(crate) mod where_;
 pub(crate) mod which_;
+pub(crate) mod wrap_text;
+pub(crate) mod zfill;
 
+pub(crate) use abs::Abs;
 pub(crate) use autoview::Autoview;
+pub(crate) use base_convert::BaseConvert;
+pub(crate) use benchmark::Benchmark;
+pub(crate) use bin::Bin;
+pub(crate) use capture::Capture;
 pub(crate) use cd::CD;
+pub(crate) use chunk_bytes::ChunkBytes;
+pub(crate) use clamp::Clamp;
 pub(crate) use command::{
     per_item_command, whole_stream_command, Command, PerItemCommand, RawCommandArgs,
     UnevaluatedCallInfo, WholeStreamCommand,
@@ -100,22 +202,41 @@ pub(crate) use command::{
 
 pub(crate) use append::Append;
 pub(crate) use compact::Compact;
+pub(crate) use concat::Concat;
 pub(crate) use config::Config;
+pub(crate) use convert_unit::ConvertUnit;
+pub(crate) use correlation::Correlation;
 pub(crate) use count::Count;
 pub(crate) use cp::Cpy;
+pub(crate) use cross::Cross;
+pub(crate) use crosstab::Crosstab;
+pub(crate) use cumulative_max::CumulativeMax;
+pub(crate) use cumulative_min::CumulativeMin;
+pub(crate) use cumulative_sum::CumulativeSum;
 pub(crate) use date::Date;
 pub(crate) use debug::Debug;
+pub(crate) use dedup_adjacent::DedupAdjacent;
 pub(crate) use default::Default;
+pub(crate) use delta::Delta;
+pub(crate) use describe::Describe;
+pub(crate) use diff::Diff;
+pub(crate) use du::Du;
 pub(crate) use echo::Echo;
 pub(crate) use enter::Enter;
 pub(crate) use env::Env;
 #[allow(unused)]
 pub(crate) use evaluate_by::EvaluateBy;
+pub(crate) use every::Every;
 pub(crate) use exit::Exit;
+pub(crate) use expand_bytes::ExpandBytes;
 pub(crate) use fetch::Fetch;
+pub(crate) use fill_nulls::FillNulls;
 pub(crate) use first::First;
+pub(crate) use frequency::Frequency;
 pub(crate) use from_bson::FromBSON;
+pub(crate) use from_cookie::FromCookie;
 pub(crate) use from_csv::FromCSV;
+pub(crate) use from_fraction::FromFraction;
 pub(crate) use from_ini::FromINI;
 pub(crate) use from_json::FromJSON;
 pub(crate) use from_sqlite::FromDB;
@@ -128,55 +249,124 @@ pub(crate) use from_xlsx::FromXLSX;
 pub(crate) use from_xml::FromXML;
 pub(crate) use from_yaml::FromYAML;
 pub(crate) use from_yaml::FromYML;
+pub(crate) use fuzzy_find::FuzzyFind;
 pub(crate) use get::Get;
 pub(crate) use group_by::GroupBy;
 pub(crate) use help::Help;
 pub(crate) use histogram::Histogram;
 pub(crate) use history::History;
+pub(crate) use into_bool::IntoBool;
+pub(crate) use into_date::IntoDate;
+pub(crate) use items::Items;
+pub(crate) use json_pointer::JsonPointer;
 pub(crate) use last::Last;
 pub(crate) use lines::Lines;
+pub(crate) use list::List;
 pub(crate) use ls::LS;
 #[allow(unused)]
 pub(crate) use map_max_by::MapMaxBy;
+pub(crate) use math::Math;
 pub(crate) use mkdir::Mkdir;
+pub(crate) use mode::Mode;
+pub(crate) use moving_average::MovingAverage;
 pub(crate) use mv::Move;
 pub(crate) use next::Next;
+pub(crate) use normalize_columns::NormalizeColumns;
 pub(crate) use nth::Nth;
 pub(crate) use open::Open;
+pub(crate) use outliers::Outliers;
+pub(crate) use pad::Pad;
+pub(crate) use parse_unit::ParseUnit;
+pub(crate) use partition::Partition;
+pub(crate) use path_canonicalize::PathCanonicalize;
+pub(crate) use path_expand::PathExpand;
+pub(crate) use path_join::PathJoin;
+pub(crate) use path_parse::PathParse;
+pub(crate) use path_set_extension::PathSetExtension;
+pub(crate) use path_type::PathType;
+pub(crate) use percentile::Percentile;
 pub(crate) use pick::Pick;
 pub(crate) use pivot::Pivot;
+pub(crate) use pivot_wide::PivotWide;
 pub(crate) use post::Post;
 pub(crate) use prepend::Prepend;
 pub(crate) use prev::Previous;
+pub(crate) use progress::Progress;
 pub(crate) use pwd::PWD;
+pub(crate) use query::Query;
+pub(crate) use reduce::Reduce;
 #[allow(unused)]
 pub(crate) use reduce_by::ReduceBy;
 pub(crate) use reject::Reject;
+pub(crate) use repeat::Repeat;
+pub(crate) use retry::Retry;
 pub(crate) use reverse::Reverse;
 pub(crate) use rm::Remove;
+pub(crate) use round::Round;
+pub(crate) use row_diff::RowDiff;
+pub(crate) use rows_to_record::RowsToRecord;
+pub(crate) use sample::Sample;
 pub(crate) use save::Save;
+pub(crate) use scale::Scale;
+pub(crate) use schema::Schema;
+pub(crate) use schema_diff::SchemaDiff;
+pub(crate) use seq::Seq;
 pub(crate) use shells::Shells;
+pub(crate) use sign::Sign;
 pub(crate) use size::Size;
 pub(crate) use skip_while::SkipWhile;
+pub(crate) use sniff::Sniff;
+pub(crate) use sniff_delimiter::SniffDelimiter;
 pub(crate) use sort_by::SortBy;
+pub(crate) use sort_by_key::SortByKey;
 pub(crate) use split_by::SplitBy;
 pub(crate) use split_column::SplitColumn;
+pub(crate) use split_docs::SplitDocs;
+pub(crate) use split_into::SplitInto;
+pub(crate) use split_paths::SplitPaths;
 pub(crate) use split_row::SplitRow;
+pub(crate) use str_case::StrCase;
+pub(crate) use str_collect::StrCollect;
+pub(crate) use str_distance::StrDistance;
+pub(crate) use str_ends_with::StrEndsWith;
+pub(crate) use str_index_of::StrIndexOf;
+pub(crate) use str_starts_with::StrStartsWith;
+pub(crate) use str_substring::StrSubstring;
+pub(crate) use summary::Summary;
 #[allow(unused)]
 pub(crate) use t_sort_by::TSortBy;
 pub(crate) use table::Table;
 pub(crate) use tags::Tags;
+pub(crate) use tail::Tail;
+pub(crate) use timeout::Timeout;
 pub(crate) use to_bson::ToBSON;
+pub(crate) use to_cookie::ToCookie;
 pub(crate) use to_csv::ToCSV;
+pub(crate) use to_fixed_width::ToFixedWidth;
+pub(crate) use to_fraction::ToFraction;
+pub(crate) use to_html::ToHTML;
 pub(crate) use to_json::ToJSON;
+pub(crate) use to_jsonl::ToJSONL;
+pub(crate) use to_md::ToMD;
+pub(crate) use to_sql::ToSQL;
 pub(crate) use to_sqlite::ToDB;
 pub(crate) use to_sqlite::ToSQLite;
 pub(crate) use to_toml::ToTOML;
+pub(crate) use to_tree::ToTree;
 pub(crate) use to_tsv::ToTSV;
 pub(crate) use to_url::ToURL;
 pub(crate) use to_yaml::ToYAML;
 pub(crate) use trim::Trim;
+pub(crate) use unflatten::Unflatten;
+pub(crate) use unify::Unify;
+pub(crate) use uniq::Uniq;
+pub(crate) use units::Units;
+pub(crate) use update_cells::UpdateCells;
+pub(crate) use values::Values;
 pub(crate) use version::Version;
+pub(crate) use watch::Watch;
 pub(crate) use what::What;
 pub(crate) use where_::Where;
 pub(crate) use which_::Which;
+pub(crate) use wrap_text::WrapText;
+pub(crate) use zfill::Zfill;
@@ -0,0 +1,111 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::value;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::Tagged;
+use num_traits::Signed;
+
+pub struct Abs;
+
+#[derive(Deserialize)]
+pub struct AbsArgs {
+    rest: Vec<Tagged<String>>,
+}
+
+impl WholeStreamCommand for Abs {
+    fn name(&self) -> &str {
+        "abs"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("abs").rest(
+            SyntaxShape::String,
+            "the columns holding the numbers (defaults to the values themselves)",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Replaces numbers with their absolute value."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, abs)?.run()
+    }
+}
+
+fn abs(
+    AbsArgs { rest }: AbsArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    Ok(input
+        .values
+        .map(move |value| {
+            let value = map_numbers(value, &rest, |number| match number {
+                Primitive::Int(i) => value::int(i.abs()),
+                Primitive::Decimal(d) => value::decimal(d.abs()),
+                _ => unreachable!("map_numbers only gives numbers"),
+            })?;
+
+            ReturnSuccess::value(value)
+        })
+        .to_output_stream())
+}
+
+/// Replaces the numbers in the given columns of a row, or the value itself when no columns
+/// are given. Anything that isn't a number is an error.
+pub(crate) fn map_numbers(
+    value: Value,
+    columns: &[Tagged<String>],
+    f: impl Fn(&Primitive) -> UntaggedValue,
+) -> Result<Value, ShellError> {
+    if columns.is_empty() {
+        let mapped = map_number(&value, &value, &f)?;
+        return Ok(mapped.into_value(value.tag()));
+    }
+
+    let mut row = value;
+
+    for column in columns {
+        let cell = value::cell_at(&row, column)?;
+
+        let mapped = map_number(&cell, &row, &f)?;
+
+        row = match row.insert_data_at_path(&column.item, mapped.into_value(cell.tag())) {
+            Some(row) => row,
+            None => {
+                return Err(ShellError::labeled_error(
+                    "Could not replace the number",
+                    "expected a row",
+                    row.tag(),
+                ))
+            }
+        };
+    }
+
+    Ok(row)
+}
+
+fn map_number(
+    cell: &Value,
+    row: &Value,
+    f: &impl Fn(&Primitive) -> UntaggedValue,
+) -> Result<UntaggedValue, ShellError> {
+    match &cell.value {
+        UntaggedValue::Primitive(number @ Primitive::Int(_))
+        | UntaggedValue::Primitive(number @ Primitive::Decimal(_)) => Ok(f(number)),
+        _ => Err(ShellError::labeled_error_with_secondary(
+            "Expected a number",
+            format!("found {} instead", cell.type_name()),
+            cell.tag(),
+            "row originates from here",
+            row.tag(),
+        )),
+    }
+}
@@ -0,0 +1,200 @@
+use crate::commands::get::get_column_path;
+use crate::commands::WholeStreamCommand;
+use crate::data::value;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue,
+    Value,
+};
+use nu_source::Tagged;
+use num_traits::{Num, Signed};
+
+pub struct BaseConvert;
+
+#[derive(Deserialize)]
+pub struct BaseConvertArgs {
+    column: ColumnPath,
+    to: Option<Tagged<u64>>,
+    from: Option<Tagged<u64>>,
+    prefix: bool,
+}
+
+impl WholeStreamCommand for BaseConvert {
+    fn name(&self) -> &str {
+        "base-convert"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("base-convert")
+            .required(
+                "column",
+                SyntaxShape::ColumnPath,
+                "the column holding the numbers to convert",
+            )
+            .named(
+                "to",
+                SyntaxShape::Int,
+                "write the integers as text in this base: 2, 8 or 16",
+            )
+            .named(
+                "from",
+                SyntaxShape::Int,
+                "read the text as integers in this base: 2, 8 or 16",
+            )
+            .switch(
+                "prefix",
+                "with --to, start the text with 0b, 0o or 0x for the base",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Converts integers to text in base 2, 8 or 16, or reads such text back into integers."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, base_convert)?.run()
+    }
+}
+
+enum Direction {
+    To(u32),
+    From(u32),
+}
+
+fn base_convert(
+    BaseConvertArgs {
+        column,
+        to,
+        from,
+        prefix,
+    }: BaseConvertArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let direction = match (to, from) {
+        (Some(to), None) => Direction::To(checked_base(&to)?),
+        (None, Some(from)) => Direction::From(checked_base(&from)?),
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Expected either --to or --from",
+                "give the base to convert to or from",
+                name,
+            ))
+        }
+    };
+
+    Ok(input
+        .values
+        .map(move |row| {
+            let cell = get_column_path(&column, &row)?;
+
+            let converted = match (&direction, &cell.value) {
+                (Direction::To(base), UntaggedValue::Primitive(Primitive::Int(i))) => {
+                    value::string(to_base(i, *base, prefix))
+                }
+                (Direction::From(base), UntaggedValue::Primitive(Primitive::String(s))) => {
+                    match from_base(s, *base) {
+                        Some(i) => value::int(i),
+                        None => {
+                            return Err(ShellError::labeled_error_with_secondary(
+                                format!("Could not read as a base {} integer", base),
+                                format!("`{}` isn't a base {} integer", s, base),
+                                cell.tag(),
+                                "row originates from here",
+                                row.tag(),
+                            ))
+                        }
+                    }
+                }
+                (direction, _) => {
+                    let expected = match direction {
+                        Direction::To(_) => "an integer",
+                        Direction::From(_) => "a string",
+                    };
+
+                    return Err(ShellError::labeled_error_with_secondary(
+                        format!("Expected {}", expected),
+                        format!("found {} instead", cell.type_name()),
+                        cell.tag(),
+                        "row originates from here",
+                        row.tag(),
+                    ));
+                }
+            };
+
+            match row.replace_data_at_column_path(&column, converted.into_value(cell.tag())) {
+                Some(row) => ReturnSuccess::value(row),
+                None => Err(ShellError::labeled_error(
+                    "base-convert could not find the column",
+                    "column name",
+                    &name,
+                )),
+            }
+        })
+        .to_output_stream())
+}
+
+fn checked_base(base: &Tagged<u64>) -> Result<u32, ShellError> {
+    match base.item {
+        2 => Ok(2),
+        8 => Ok(8),
+        16 => Ok(16),
+        _ => Err(ShellError::labeled_error(
+            "Unsupported base",
+            "expected 2, 8 or 16",
+            base.tag(),
+        )),
+    }
+}
+
+fn base_prefix(base: u32) -> &'static str {
+    match base {
+        2 => "0b",
+        8 => "0o",
+        _ => "0x",
+    }
+}
+
+/// Negative numbers keep their sign in front, eg -0xff, rather than being written in two's
+/// complement, since integers have no fixed width.
+fn to_base(i: &BigInt, base: u32, prefix: bool) -> String {
+    format!(
+        "{}{}{}",
+        if i.is_negative() { "-" } else { "" },
+        if prefix { base_prefix(base) } else { "" },
+        i.abs().to_str_radix(base)
+    )
+}
+
+/// Reads an optional sign and base prefix, then the digits. Integers don't overflow, however
+/// many digits there are.
+fn from_base(text: &str, base: u32) -> Option<BigInt> {
+    let text = text.trim();
+
+    let (negative, digits) = match text.chars().next() {
+        Some('-') => (true, &text[1..]),
+        Some('+') => (false, &text[1..]),
+        _ => (false, text),
+    };
+
+    let prefix = base_prefix(base);
+    let digits = match digits.get(..2) {
+        Some(start) if start.eq_ignore_ascii_case(prefix) => &digits[2..],
+        _ => digits,
+    };
+
+    let digits = digits.replace('_', "");
+
+    // from_str_radix reads a sign of its own, which would let a second one through
+    if digits.is_empty() || digits.starts_with(|c| c == '-' || c == '+') {
+        return None;
+    }
+
+    let parsed = BigInt::from_str_radix(&digits, base).ok()?;
+
+    Some(if negative { -parsed } else { parsed })
+}
@@ -0,0 +1,133 @@
+use crate::commands::classified::block::{block_source, run_to_end};
+use crate::commands::WholeStreamCommand;
+use crate::data::{value, TaggedDictBuilder};
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue};
+use nu_source::Tagged;
+use std::time::{Duration, Instant};
+
+#[derive(Deserialize)]
+struct BenchmarkArgs {
+    rounds: Option<Tagged<usize>>,
+}
+
+pub struct Benchmark;
+
+impl WholeStreamCommand for Benchmark {
+    fn name(&self) -> &str {
+        "benchmark"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("benchmark")
+            .required(
+                "block",
+                SyntaxShape::Block,
+                "the pipeline to time for each value, with the value as its input",
+            )
+            .named(
+                "rounds",
+                SyntaxShape::Int,
+                "run the block this many times, reporting the min, max and mean durations",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Runs the block's pipeline on each value, giving its output along with how long it took."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        Ok(args.process_raw(registry, benchmark)?.run())
+    }
+}
+
+fn benchmark(
+    BenchmarkArgs { rounds }: BenchmarkArgs,
+    RunnableContext {
+        input,
+        shell_manager,
+        host,
+        ctrl_c,
+        commands,
+        ..
+    }: RunnableContext,
+    raw_args: RawCommandArgs,
+) -> Result<OutputStream, ShellError> {
+    let source = block_source(&raw_args.call_info)?;
+    let rounds = rounds.map(|rounds| rounds.item.max(1));
+
+    let mut values = input.values;
+
+    let stream = async_stream! {
+        while let Some(row) = values.next().await {
+            let mut durations = vec![];
+            let mut output = vec![];
+
+            for _ in 0..rounds.unwrap_or(1) {
+                let mut context = Context::nested(
+                    commands.clone(),
+                    host.clone(),
+                    shell_manager.clone(),
+                );
+                context.ctrl_c = ctrl_c.clone();
+
+                let start = Instant::now();
+
+                match run_to_end(&source, &mut context, row.clone()).await {
+                    Ok(values) => output = values,
+                    Err(err) => {
+                        yield Err(err);
+                        return;
+                    }
+                }
+
+                durations.push(start.elapsed());
+            }
+
+            let mut timed = TaggedDictBuilder::new(row.tag());
+
+            if output.len() == 1 {
+                timed.insert_value("output", output.remove(0));
+            } else {
+                timed.insert_untagged("output", UntaggedValue::Table(output));
+            }
+
+            match rounds {
+                None => {
+                    timed.insert_untagged("duration", seconds(durations[0]));
+                    timed.insert_untagged("duration_ms", milliseconds(durations[0]));
+                }
+                Some(rounds) => {
+                    let total: Duration = durations.iter().sum();
+                    let mean = total / rounds as u32;
+                    let min = *durations.iter().min().unwrap_or(&mean);
+                    let max = *durations.iter().max().unwrap_or(&mean);
+
+                    for (name, duration) in &[("min", min), ("max", max), ("mean", mean)] {
+                        timed.insert_untagged(*name, seconds(*duration));
+                        timed.insert_untagged(format!("{}_ms", name), milliseconds(*duration));
+                    }
+                }
+            }
+
+            yield ReturnSuccess::value(timed.into_value());
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+/// Durations only hold whole seconds, so anything shorter is dropped.
+fn seconds(duration: Duration) -> UntaggedValue {
+    value::duration(duration.as_secs())
+}
+
+/// The time in milliseconds, for blocks that finish well within a second.
+fn milliseconds(duration: Duration) -> UntaggedValue {
+    value::int(duration.as_millis() as u64)
+}
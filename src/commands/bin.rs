@@ -0,0 +1,207 @@
+use crate::commands::get::get_column_path;
+use crate::commands::WholeStreamCommand;
+use crate::data::value;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, Value};
+use nu_source::Tagged;
+
+pub struct Bin;
+
+#[derive(Deserialize)]
+pub struct BinArgs {
+    column: ColumnPath,
+    bins: Option<Tagged<usize>>,
+    edges: Option<Vec<Value>>,
+    into: Option<Tagged<String>>,
+}
+
+impl WholeStreamCommand for Bin {
+    fn name(&self) -> &str {
+        "bin"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("bin")
+            .required(
+                "column",
+                SyntaxShape::ColumnPath,
+                "the column holding the numbers to bin",
+            )
+            .named(
+                "bins",
+                SyntaxShape::Int,
+                "how many bins of equal width to spread from the smallest to the largest value",
+            )
+            .named(
+                "edges",
+                SyntaxShape::Any,
+                "the edges of the bins, in increasing order, eg [0 10 50 100]",
+            )
+            .named(
+                "into",
+                SyntaxShape::String,
+                "the column to store the bin in (defaults to bin)",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Adds a column naming the range each number falls in, eg [10, 20)."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, bin)?.run()
+    }
+}
+
+fn bin(
+    BinArgs {
+        column,
+        bins,
+        edges,
+        into,
+    }: BinArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let into = match into {
+        Some(into) => into.item,
+        None => "bin".to_string(),
+    };
+
+    let edges = match (bins, edges) {
+        (Some(_), Some(_)) | (None, None) => {
+            return Err(ShellError::labeled_error(
+                "Expected either --bins or --edges",
+                "give how many bins to make or their edges",
+                name,
+            ))
+        }
+        (Some(bins), None) if bins.item == 0 => {
+            return Err(ShellError::labeled_error(
+                "Expected at least one bin",
+                "bins must be more than 0",
+                bins.tag(),
+            ))
+        }
+        (Some(bins), None) => Edges::EqualWidth(bins.item),
+        (None, Some(edges)) => Edges::Given(given_edges(&edges, &name)?),
+    };
+
+    let stream = async_stream! {
+        let rows: Vec<Value> = input.values.collect().await;
+        let mut numbers = Vec::with_capacity(rows.len());
+
+        for row in &rows {
+            match get_column_path(&column, row).and_then(|cell| value::number_in(&cell, row)) {
+                Ok(number) => numbers.push(number),
+                Err(err) => {
+                    yield Err(err);
+                    return;
+                }
+            }
+        }
+
+        let edges = match &edges {
+            Edges::Given(edges) => edges.clone(),
+            Edges::EqualWidth(bins) => equal_width_edges(&numbers, *bins),
+        };
+
+        for (row, number) in rows.into_iter().zip(numbers) {
+            let label = value::string(bin_label(&edges, number)).into_value(row.tag());
+
+            match row.insert_data_at_path(&into, label) {
+                Some(row) => yield ReturnSuccess::value(row),
+                None => {
+                    yield Err(ShellError::labeled_error(
+                        "Could not add the bin",
+                        "expected a row",
+                        row.tag(),
+                    ));
+                    return;
+                }
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+enum Edges {
+    EqualWidth(usize),
+    Given(Vec<f64>),
+}
+
+fn given_edges(edges: &[Value], name: &Tag) -> Result<Vec<f64>, ShellError> {
+    let edges = edges
+        .iter()
+        .map(|edge| {
+            value::as_f64(edge).ok_or_else(|| {
+                ShellError::labeled_error(
+                    "Expected a number",
+                    format!("found {} instead", edge.type_name()),
+                    edge.tag(),
+                )
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if edges.len() < 2 || edges.windows(2).any(|pair| pair[0] >= pair[1]) {
+        return Err(ShellError::labeled_error(
+            "Invalid edges",
+            "expected at least two edges, in increasing order",
+            name,
+        ));
+    }
+
+    Ok(edges)
+}
+
+fn equal_width_edges(numbers: &[f64], bins: usize) -> Vec<f64> {
+    if numbers.is_empty() {
+        return vec![];
+    }
+
+    let min = numbers.iter().cloned().fold(std::f64::INFINITY, f64::min);
+    let max = numbers
+        .iter()
+        .cloned()
+        .fold(std::f64::NEG_INFINITY, f64::max);
+
+    let width = (max - min) / bins as f64;
+
+    (0..=bins)
+        .map(|i| {
+            if i == bins {
+                max
+            } else {
+                min + width * i as f64
+            }
+        })
+        .collect()
+}
+
+/// Bins are half-open, including their lower edge but not their upper one, except the last
+/// bin which includes both. Numbers outside the edges get (-inf, first) or (last, inf).
+fn bin_label(edges: &[f64], number: f64) -> String {
+    let (first, last) = (edges[0], edges[edges.len() - 1]);
+
+    if number < first {
+        return format!("(-inf, {})", first);
+    }
+
+    if number > last {
+        return format!("({}, inf)", last);
+    }
+
+    for pair in edges[..edges.len() - 1].windows(2) {
+        if number < pair[1] {
+            return format!("[{}, {})", pair[0], pair[1]);
+        }
+    }
+
+    format!("[{}, {}]", edges[edges.len() - 2], last)
+}
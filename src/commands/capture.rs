@@ -0,0 +1,197 @@
+use crate::commands::get::get_column_path;
+use crate::commands::WholeStreamCommand;
+use crate::data::{value, TaggedDictBuilder};
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue,
+    Value,
+};
+use nu_source::Tagged;
+use regex::{Captures, Regex};
+
+pub struct Capture;
+
+#[derive(Deserialize)]
+pub struct CaptureArgs {
+    column: ColumnPath,
+    regex: Tagged<String>,
+    #[serde(rename(deserialize = "only-matches"))]
+    only_matches: bool,
+    all: bool,
+}
+
+impl WholeStreamCommand for Capture {
+    fn name(&self) -> &str {
+        "capture"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("capture")
+            .required(
+                "column",
+                SyntaxShape::ColumnPath,
+                "the column holding the text to match",
+            )
+            .required_named(
+                "regex",
+                SyntaxShape::String,
+                "the regex to match, eg \"(?P<user>\\w+)@(?P<host>\\w+)\"",
+            )
+            .switch("only-matches", "drop the rows that don't match")
+            .switch(
+                "all",
+                "add a matches column holding a table of every match instead",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Adds a column for each named group of the regex, holding what it captured."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, capture)?.run()
+    }
+}
+
+fn capture(
+    CaptureArgs {
+        column,
+        regex,
+        only_matches,
+        all,
+    }: CaptureArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let pattern = match Regex::new(&regex.item) {
+        Ok(pattern) => pattern,
+        Err(err) => {
+            return Err(ShellError::labeled_error(
+                "Invalid regex",
+                format!("{}", err),
+                regex.tag(),
+            ))
+        }
+    };
+
+    let groups: Vec<String> = pattern
+        .capture_names()
+        .filter_map(|name| name.map(|name| name.to_string()))
+        .collect();
+
+    if groups.is_empty() {
+        return Err(ShellError::labeled_error(
+            "Expected named groups",
+            "name the groups to capture, eg (?P<name>...)",
+            regex.tag(),
+        ));
+    }
+
+    let stream = async_stream! {
+        let mut values = input.values;
+
+        while let Some(row) = values.next().await {
+            match capture_row(&row, &column, &pattern, &groups, only_matches, all) {
+                Ok(Some(row)) => yield ReturnSuccess::value(row),
+                Ok(None) => {}
+                Err(err) => yield Err(err),
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+/// Adds the captures of the row's cell to the row, or gives `None` if the row should be dropped.
+fn capture_row(
+    row: &Value,
+    column: &ColumnPath,
+    pattern: &Regex,
+    groups: &[String],
+    only_matches: bool,
+    all: bool,
+) -> Result<Option<Value>, ShellError> {
+    let cell = get_column_path(column, row)?;
+
+    let text = match &cell.value {
+        UntaggedValue::Primitive(Primitive::String(s)) => s,
+        UntaggedValue::Primitive(Primitive::Line(s)) => s,
+        _ => {
+            return Err(ShellError::labeled_error_with_secondary(
+                "Expected a string",
+                format!("found {} instead", cell.type_name()),
+                cell.tag(),
+                "row originates from here",
+                row.tag(),
+            ))
+        }
+    };
+
+    let mut captured = vec![];
+
+    if all {
+        let matches: Vec<Value> = pattern
+            .captures_iter(text)
+            .map(|captures| {
+                let mut found = TaggedDictBuilder::new(cell.tag());
+
+                for (group, value) in group_values(&captures, groups) {
+                    found.insert_untagged(group, value);
+                }
+
+                found.into_value()
+            })
+            .collect();
+
+        if matches.is_empty() && only_matches {
+            return Ok(None);
+        }
+
+        captured.push(("matches", value::table(&matches)));
+    } else {
+        match pattern.captures(text) {
+            Some(captures) => captured.extend(group_values(&captures, groups)),
+            None if only_matches => return Ok(None),
+            None => captured.extend(
+                groups
+                    .iter()
+                    .map(|group| (group.as_str(), value::nothing())),
+            ),
+        }
+    }
+
+    let mut row = row.clone();
+
+    for (group, value) in captured {
+        row = match row.insert_data_at_path(group, value.into_value(cell.tag())) {
+            Some(row) => row,
+            None => {
+                return Err(ShellError::labeled_error(
+                    "Could not add the captures",
+                    "expected a row",
+                    row.tag(),
+                ))
+            }
+        };
+    }
+
+    Ok(Some(row))
+}
+
+fn group_values<'a>(captures: &Captures, groups: &'a [String]) -> Vec<(&'a str, UntaggedValue)> {
+    groups
+        .iter()
+        .map(|group| {
+            let value = match captures.name(group) {
+                Some(found) => value::string(found.as_str()),
+                None => value::nothing(),
+            };
+
+            (group.as_str(), value)
+        })
+        .collect()
+}
@@ -0,0 +1,149 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::value;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+
+pub struct ChunkBytes;
+
+#[derive(Deserialize)]
+pub struct ChunkBytesArgs {
+    size: Tagged<usize>,
+    pad: Option<Tagged<i64>>,
+}
+
+impl WholeStreamCommand for ChunkBytes {
+    fn name(&self) -> &str {
+        "chunk-bytes"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("chunk-bytes")
+            .required(
+                "size",
+                SyntaxShape::Int,
+                "the number of bytes in each chunk",
+            )
+            .named(
+                "pad",
+                SyntaxShape::Int,
+                "the byte value used to fill up the last chunk",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Splits binary data into chunks of the given size."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, chunk_bytes)?.run()
+    }
+}
+
+fn chunk_bytes(
+    ChunkBytesArgs { size, pad }: ChunkBytesArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    if size.item == 0 {
+        return Err(ShellError::labeled_error(
+            "Chunk size must be positive",
+            "chunk size of zero",
+            size.tag(),
+        ));
+    }
+
+    let pad = match pad {
+        Some(pad) if (0..=255).contains(&pad.item) => Some(pad.item as u8),
+        Some(pad) => {
+            return Err(ShellError::labeled_error(
+                "Pad must be a byte",
+                "expected a value from 0 to 255",
+                pad.tag(),
+            ))
+        }
+        None => None,
+    };
+
+    let size = size.item;
+
+    let stream = input
+        .values
+        .map(move |v| {
+            let mut result = VecDeque::new();
+
+            match v {
+                Value {
+                    value: UntaggedValue::Primitive(Primitive::Binary(bytes)),
+                    tag,
+                } => {
+                    for chunk in chunk(&bytes, size, pad) {
+                        result
+                            .push_back(ReturnSuccess::value(value::binary(chunk).into_value(&tag)));
+                    }
+                }
+                Value { tag, .. } => {
+                    result.push_back(Err(ShellError::labeled_error_with_secondary(
+                        "Expected binary data from pipeline",
+                        "requires binary data input",
+                        &name,
+                        "value originates from here",
+                        tag,
+                    )));
+                }
+            }
+
+            result
+        })
+        .flatten();
+
+    Ok(stream.to_output_stream())
+}
+
+fn chunk(bytes: &[u8], size: usize, pad: Option<u8>) -> Vec<Vec<u8>> {
+    bytes
+        .chunks(size)
+        .map(|chunk| {
+            let mut chunk = chunk.to_vec();
+
+            if let Some(pad) = pad {
+                chunk.resize(size, pad);
+            }
+
+            chunk
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::chunk;
+
+    #[test]
+    fn splits_evenly_divisible_bytes() {
+        assert_eq!(
+            chunk(&[1, 2, 3, 4, 5, 6], 2, None),
+            vec![vec![1, 2], vec![3, 4], vec![5, 6]]
+        );
+    }
+
+    #[test]
+    fn keeps_the_remainder_in_a_shorter_chunk() {
+        assert_eq!(
+            chunk(&[1, 2, 3, 4, 5], 2, None),
+            vec![vec![1, 2], vec![3, 4], vec![5]]
+        );
+    }
+
+    #[test]
+    fn pads_the_last_chunk_to_full_size() {
+        assert_eq!(
+            chunk(&[1, 2, 3, 4, 5], 2, Some(0)),
+            vec![vec![1, 2], vec![3, 4], vec![5, 0]]
+        );
+    }
+}
@@ -0,0 +1,112 @@
+use crate::commands::abs::map_numbers;
+use crate::commands::WholeStreamCommand;
+use crate::data::value;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_parser::Operator;
+use nu_protocol::{
+    Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::Tagged;
+
+pub struct Clamp;
+
+#[derive(Deserialize)]
+pub struct ClampArgs {
+    column: Tagged<String>,
+    min: Option<Value>,
+    max: Option<Value>,
+}
+
+impl WholeStreamCommand for Clamp {
+    fn name(&self) -> &str {
+        "clamp"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("clamp")
+            .required(
+                "column",
+                SyntaxShape::String,
+                "the column holding the numbers to clamp",
+            )
+            .named(
+                "min",
+                SyntaxShape::Number,
+                "raise numbers below this up to it",
+            )
+            .named(
+                "max",
+                SyntaxShape::Number,
+                "lower numbers above this down to it",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Limits the numbers in a column to a range, leaving those inside it unchanged."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, clamp)?.run()
+    }
+}
+
+fn clamp(
+    ClampArgs { column, min, max }: ClampArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let min = min.map(checked_bound).transpose()?;
+    let max = max.map(checked_bound).transpose()?;
+
+    if let (Some(min), Some(max)) = (&min, &max) {
+        if is_less(max, min) {
+            return Err(ShellError::labeled_error_with_secondary(
+                "Empty range",
+                "the minimum is greater than the maximum",
+                min.tag(),
+                "the maximum is here",
+                max.tag(),
+            ));
+        }
+    }
+
+    let columns = vec![column];
+
+    Ok(input
+        .values
+        .map(move |row| {
+            let row = map_numbers(row, &columns, |number| {
+                let number = UntaggedValue::Primitive(number.clone());
+
+                match (&min, &max) {
+                    (Some(min), _) if is_less(&number, min) => min.value.clone(),
+                    (_, Some(max)) if is_less(max, &number) => max.value.clone(),
+                    _ => number,
+                }
+            })?;
+
+            ReturnSuccess::value(row)
+        })
+        .to_output_stream())
+}
+
+fn checked_bound(bound: Value) -> Result<Value, ShellError> {
+    match &bound.value {
+        UntaggedValue::Primitive(Primitive::Int(_))
+        | UntaggedValue::Primitive(Primitive::Decimal(_)) => Ok(bound),
+        _ => Err(ShellError::labeled_error(
+            "Expected a number",
+            format!("found {} instead", bound.type_name()),
+            bound.tag(),
+        )),
+    }
+}
+
+/// Both sides are numbers, which always compare.
+fn is_less(left: &UntaggedValue, right: &UntaggedValue) -> bool {
+    value::compare_values(&Operator::LessThan, left, right).unwrap_or(false)
+}
@@ -0,0 +1,95 @@
+use crate::cli::classify_pipeline;
+use crate::commands::classified::pipeline::stream_pipeline;
+use crate::commands::classified::ClassifiedInputStream;
+use crate::commands::UnevaluatedCallInfo;
+use crate::context::Context;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_parser::hir::RawExpression;
+use nu_protocol::Value;
+
+/// The text inside the braces of the block, with everything around it blanked out so that
+/// errors in the block point at the right place in the line.
+pub(crate) fn block_source(call_info: &UnevaluatedCallInfo) -> Result<String, ShellError> {
+    let block = call_info
+        .args
+        .positional
+        .as_ref()
+        .and_then(|positional| positional.first());
+
+    match block {
+        Some(block) => match block.expr {
+            RawExpression::Block(_) => {
+                let source = call_info.source.to_string();
+                let start = block.span.start() + 1;
+                let end = block.span.end().saturating_sub(1).max(start);
+
+                Ok(format!(
+                    "{}{}{}",
+                    " ".repeat(start),
+                    &source[start..end],
+                    " ".repeat(source.len() - end)
+                ))
+            }
+            _ => Err(ShellError::labeled_error(
+                "Expected a block",
+                "expected a pipeline in braces, eg { watch }",
+                block.span,
+            )),
+        },
+        None => Err(ShellError::labeled_error(
+            "Expected a block",
+            "expected a pipeline to run, eg { watch }",
+            &call_info.name_tag,
+        )),
+    }
+}
+
+pub(crate) async fn run_block(
+    source: &str,
+    context: &mut Context,
+    input: InputStream,
+) -> Result<InputStream, ShellError> {
+    let tokens = nu_parser::parse(source)?;
+    let pipeline = classify_pipeline(&tokens, context, &Text::from(source))?;
+    let input = ClassifiedInputStream::from_input_stream(input);
+
+    let output = stream_pipeline(pipeline, context, input, source).await?;
+
+    Ok(output.objects)
+}
+
+/// Runs the block's pipeline on the row, giving back all of its output or the error that
+/// stopped it.
+pub(crate) async fn run_to_end(
+    source: &str,
+    context: &mut Context,
+    row: Value,
+) -> Result<Vec<Value>, ShellError> {
+    let output: Vec<Value> = run_block(source, context, vec![row].into())
+        .await?
+        .values
+        .collect()
+        .await;
+
+    if let Some(err) = take_error(context) {
+        return Err(err);
+    }
+
+    match output.iter().find(|value| value.is_error()) {
+        Some(err) => Err(err.expect_error()),
+        None => Ok(output),
+    }
+}
+
+/// The first error the block's pipeline ran into. Commands report their errors to the
+/// context rather than in their output, so these are only known once the output is drained.
+pub(crate) fn take_error(context: &mut Context) -> Option<ShellError> {
+    context.with_errors(|errors| {
+        if errors.is_empty() {
+            None
+        } else {
+            Some(errors.remove(0))
+        }
+    })
+}
@@ -1,6 +1,7 @@
 use crate::data::value;
 use crate::prelude::*;
 
+pub(crate) mod block;
 mod dynamic;
 pub(crate) mod external;
 pub(crate) mod internal;
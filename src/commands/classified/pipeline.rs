@@ -12,9 +12,41 @@ use std::sync::atomic::Ordering;
 pub(crate) async fn run_pipeline(
     pipeline: ClassifiedPipeline,
     ctx: &mut Context,
-    mut input: ClassifiedInputStream,
+    input: ClassifiedInputStream,
     line: &str,
 ) -> Result<(), ShellError> {
+    let input = stream_pipeline(pipeline, ctx, input, line).await?;
+
+    use futures::stream::TryStreamExt;
+    let mut output_stream: OutputStream = input.objects.into();
+    loop {
+        match output_stream.try_next().await {
+            Ok(Some(ReturnSuccess::Value(Value {
+                value: UntaggedValue::Error(e),
+                ..
+            }))) => return Err(e),
+            Ok(Some(_item)) => {
+                if ctx.ctrl_c.load(Ordering::SeqCst) {
+                    break;
+                }
+            }
+            _ => {
+                break;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Starts each command of the pipeline on the output of the one before, giving back the
+/// output of the last command.
+pub(crate) async fn stream_pipeline(
+    pipeline: ClassifiedPipeline,
+    ctx: &mut Context,
+    mut input: ClassifiedInputStream,
+    line: &str,
+) -> Result<ClassifiedInputStream, ShellError> {
     let mut iter = pipeline.commands.list.into_iter().peekable();
 
     loop {
@@ -51,24 +83,5 @@ pub(crate) async fn run_pipeline(
         };
     }
 
-    use futures::stream::TryStreamExt;
-    let mut output_stream: OutputStream = input.objects.into();
-    loop {
-        match output_stream.try_next().await {
-            Ok(Some(ReturnSuccess::Value(Value {
-                value: UntaggedValue::Error(e),
-                ..
-            }))) => return Err(e),
-            Ok(Some(_item)) => {
-                if ctx.ctrl_c.load(Ordering::SeqCst) {
-                    break;
-                }
-            }
-            _ => {
-                break;
-            }
-        }
-    }
-
-    Ok(())
+    Ok(input)
 }
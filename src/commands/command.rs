@@ -363,6 +363,7 @@ pub trait WholeStreamCommand: Send + Sync {
             usage: self.usage().to_string(),
             positional: vec![],
             rest_positional: None,
+            rest_required: false,
             named: indexmap::IndexMap::new(),
             is_filter: true,
         }
@@ -390,6 +391,7 @@ pub trait PerItemCommand: Send + Sync {
             usage: self.usage().to_string(),
             positional: vec![],
             rest_positional: None,
+            rest_required: false,
             named: indexmap::IndexMap::new(),
             is_filter: true,
         }
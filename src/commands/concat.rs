@@ -0,0 +1,109 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::value;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue};
+use nu_source::Tagged;
+
+pub struct Concat;
+
+#[derive(Deserialize)]
+pub struct ConcatArgs {
+    column: Tagged<String>,
+    key: Option<Tagged<String>>,
+}
+
+impl WholeStreamCommand for Concat {
+    fn name(&self) -> &str {
+        "concat"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("concat")
+            .required(
+                "column",
+                SyntaxShape::String,
+                "the column holding the tables to join together",
+            )
+            .named(
+                "key",
+                SyntaxShape::String,
+                "a column of the outer row to copy into each of its inner rows",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Joins the tables found in a column into one table."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, concat)?.run()
+    }
+}
+
+fn concat(
+    ConcatArgs { column, key }: ConcatArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let stream = async_stream! {
+        let mut values = input.values;
+
+        while let Some(row) = values.next().await {
+            let table = match value::cell_at(&row, &column) {
+                Ok(cell) => match cell.value {
+                    UntaggedValue::Table(table) => table,
+                    _ => {
+                        yield Err(ShellError::labeled_error_with_secondary(
+                            "Expected a table",
+                            format!("found {} instead", cell.type_name()),
+                            cell.tag(),
+                            "row originates from here",
+                            row.tag(),
+                        ));
+                        return;
+                    }
+                },
+                Err(err) => {
+                    yield Err(err);
+                    return;
+                }
+            };
+
+            let parent = match &key {
+                Some(key) => match value::cell_at(&row, key) {
+                    Ok(parent) => Some((key.item.clone(), parent)),
+                    Err(err) => {
+                        yield Err(err);
+                        return;
+                    }
+                },
+                None => None,
+            };
+
+            for inner in table {
+                let inner = match &parent {
+                    None => inner,
+                    Some((key, parent)) => match inner.insert_data_at_path(key, parent.clone()) {
+                        Some(inner) => inner,
+                        None => {
+                            yield Err(ShellError::labeled_error(
+                                "Could not add the key",
+                                "expected a row",
+                                inner.tag(),
+                            ));
+                            return;
+                        }
+                    },
+                };
+
+                yield ReturnSuccess::value(inner);
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
@@ -0,0 +1,117 @@
+use crate::commands::units::multiplier;
+use crate::commands::WholeStreamCommand;
+use crate::data::{value, TaggedDictBuilder};
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_parser::Unit;
+use nu_protocol::{
+    Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::Tagged;
+use std::str::FromStr;
+
+pub struct ConvertUnit;
+
+#[derive(Deserialize)]
+pub struct ConvertUnitArgs {
+    unit: Tagged<String>,
+    #[serde(rename(deserialize = "keep-type"))]
+    keep_type: bool,
+}
+
+impl WholeStreamCommand for ConvertUnit {
+    fn name(&self) -> &str {
+        "convert-unit"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("convert-unit")
+            .required(
+                "unit",
+                SyntaxShape::String,
+                "the unit to express the sizes or durations in, eg mb or h",
+            )
+            .switch(
+                "keep-type",
+                "round to a whole number of the unit, keeping the size or duration type",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Re-expresses sizes or durations in the given unit, as a value and unit row."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, convert_unit)?.run()
+    }
+}
+
+fn convert_unit(
+    ConvertUnitArgs { unit, keep_type }: ConvertUnitArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let target = match Unit::from_str(&unit.item) {
+        Ok(target) => target.tagged(unit.tag()),
+        Err(()) => {
+            return Err(ShellError::labeled_error(
+                "Unknown unit",
+                "expected a unit like kb, mb, s or h (see units)",
+                unit.tag(),
+            ))
+        }
+    };
+
+    Ok(input
+        .values
+        .map(move |v| ReturnSuccess::value(convert(&v, &target, keep_type)?))
+        .to_output_stream())
+}
+
+fn convert(v: &Value, target: &Tagged<Unit>, keep_type: bool) -> Result<Value, ShellError> {
+    let (amount, category) = match &v.value {
+        UntaggedValue::Primitive(Primitive::Bytes(bytes)) => (*bytes, "bytesize"),
+        UntaggedValue::Primitive(Primitive::Duration(secs)) => (*secs, "duration"),
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Expected a size or duration",
+                format!("found {} instead", v.type_name()),
+                v.tag(),
+            ))
+        }
+    };
+
+    if target.item.category() != category {
+        return Err(ShellError::labeled_error_with_secondary(
+            "Incompatible unit",
+            format!("can't express a {} in {}", category, target.item.as_str()),
+            target.tag(),
+            format!("this is a {}", category),
+            v.tag(),
+        ));
+    }
+
+    let multiplier = multiplier(target.item);
+    let converted = amount as f64 / multiplier as f64;
+
+    if keep_type {
+        let rounded = converted.round() as u64 * multiplier;
+
+        return Ok(match category {
+            "bytesize" => value::bytes(rounded),
+            _ => value::duration(rounded),
+        }
+        .into_value(v.tag()));
+    }
+
+    let mut row = TaggedDictBuilder::new(v.tag());
+    row.insert_untagged(
+        "value",
+        UntaggedValue::Primitive(Primitive::from(converted)),
+    );
+    row.insert_untagged("unit", value::string(target.item.as_str()));
+    Ok(row.into_value())
+}
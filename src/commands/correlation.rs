@@ -0,0 +1,131 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::{value, TaggedDictBuilder};
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+
+pub struct Correlation;
+
+#[derive(Deserialize)]
+pub struct CorrelationArgs {
+    left: Tagged<String>,
+    right: Tagged<String>,
+    #[serde(rename(deserialize = "report-skipped"))]
+    report_skipped: bool,
+}
+
+impl WholeStreamCommand for Correlation {
+    fn name(&self) -> &str {
+        "correlation"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("correlation")
+            .required("left", SyntaxShape::String, "the first numeric column")
+            .required("right", SyntaxShape::String, "the second numeric column")
+            .switch(
+                "report-skipped",
+                "return a row with the correlation and how many rows were skipped",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Computes the Pearson correlation of two numeric columns, skipping non-numeric rows."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, correlation)?.run()
+    }
+}
+
+fn correlation(
+    CorrelationArgs {
+        left,
+        right,
+        report_skipped,
+    }: CorrelationArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let stream = async_stream! {
+        let rows: Vec<Value> = input.values.collect().await;
+        let mut pairs = Vec::with_capacity(rows.len());
+        let mut skipped = 0;
+
+        for row in &rows {
+            match (value::number_at(row, &left), value::number_at(row, &right)) {
+                (Ok(x), Ok(y)) => pairs.push((x, y)),
+                _ => skipped += 1,
+            }
+        }
+
+        if pairs.len() < 2 {
+            yield Err(ShellError::labeled_error(
+                "Not enough data",
+                format!(
+                    "found {} rows with both columns numeric, need at least 2",
+                    pairs.len()
+                ),
+                &name,
+            ));
+            return;
+        }
+
+        let coefficient = match pearson(&pairs) {
+            Some(coefficient) => coefficient,
+            None => {
+                yield Err(ShellError::labeled_error(
+                    "Correlation is undefined",
+                    "one of the columns has the same value in every row",
+                    &name,
+                ));
+                return;
+            }
+        };
+
+        let coefficient = UntaggedValue::Primitive(Primitive::from(coefficient));
+
+        if report_skipped {
+            let mut row = TaggedDictBuilder::new(&name);
+            row.insert_untagged("correlation", coefficient);
+            row.insert_untagged("skipped", value::int(skipped));
+            yield ReturnSuccess::value(row.into_value());
+        } else {
+            yield ReturnSuccess::value(coefficient.into_value(&name));
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+/// None when either side doesn't vary, as the coefficient would divide by zero.
+fn pearson(pairs: &[(f64, f64)]) -> Option<f64> {
+    let n = pairs.len() as f64;
+    let mean_x = pairs.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = pairs.iter().map(|(_, y)| y).sum::<f64>() / n;
+
+    let mut covariance = 0.0;
+    let mut variance_x = 0.0;
+    let mut variance_y = 0.0;
+
+    for (x, y) in pairs {
+        covariance += (x - mean_x) * (y - mean_y);
+        variance_x += (x - mean_x).powi(2);
+        variance_y += (y - mean_y).powi(2);
+    }
+
+    if variance_x == 0.0 || variance_y == 0.0 {
+        return None;
+    }
+
+    // Rounding can push a perfect correlation just past 1.
+    Some(
+        (covariance / (variance_x * variance_y).sqrt())
+            .max(-1.0)
+            .min(1.0),
+    )
+}
@@ -0,0 +1,101 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::TaggedDictBuilder;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Evaluate, ReturnSuccess, Scope, Signature, SyntaxShape, UntaggedValue, Value};
+
+pub struct Cross;
+
+#[derive(Deserialize)]
+pub struct CrossArgs {
+    other: Evaluate,
+}
+
+impl WholeStreamCommand for Cross {
+    fn name(&self) -> &str {
+        "cross"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("cross").required(
+            "other",
+            SyntaxShape::Block,
+            "the block giving the table to pair each input row with",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Pairs every input row with every row of another table, merging their columns."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, cross)?.run()
+    }
+}
+
+fn cross(
+    CrossArgs { other }: CrossArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let stream = async_stream! {
+        // Only the other table is kept around; input rows are paired off as they arrive.
+        let right = match other.invoke(&Scope::empty()) {
+            Ok(Value { value: UntaggedValue::Table(rows), .. }) => rows,
+            Ok(value) => vec![value],
+            Err(err) => {
+                yield Err(err);
+                return;
+            }
+        };
+
+        let mut values = input.values;
+
+        while let Some(row) = values.next().await {
+            for other in &right {
+                yield ReturnSuccess::value(merge(&row, other, &name));
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+/// Joins the columns of both rows. A column of the second row that the first already has is
+/// suffixed with `_other`. Values that aren't rows are taken as a row with a `value` column.
+fn merge(row: &Value, other: &Value, tag: &Tag) -> Value {
+    let mut merged = TaggedDictBuilder::new(tag);
+    let mut taken = vec![];
+
+    for (column, value) in entries(row) {
+        taken.push(column.clone());
+        merged.insert_value(column, value);
+    }
+
+    for (column, value) in entries(other) {
+        let mut column = column;
+
+        while taken.contains(&column) {
+            column = format!("{}_other", column);
+        }
+
+        taken.push(column.clone());
+        merged.insert_value(column, value);
+    }
+
+    merged.into_value()
+}
+
+fn entries(value: &Value) -> Vec<(String, Value)> {
+    match &value.value {
+        UntaggedValue::Row(dict) => dict
+            .entries
+            .iter()
+            .map(|(column, value)| (column.clone(), value.clone()))
+            .collect(),
+        _ => vec![("value".to_string(), value.clone())],
+    }
+}
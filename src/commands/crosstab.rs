@@ -0,0 +1,123 @@
+use crate::commands::pivot_wide::text;
+use crate::commands::WholeStreamCommand;
+use crate::data::{value, TaggedDictBuilder};
+use crate::prelude::*;
+use indexmap::IndexMap;
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+
+pub struct Crosstab;
+
+#[derive(Deserialize)]
+pub struct CrosstabArgs {
+    rows: Tagged<String>,
+    columns: Tagged<String>,
+    normalize: bool,
+}
+
+impl WholeStreamCommand for Crosstab {
+    fn name(&self) -> &str {
+        "crosstab"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("crosstab")
+            .required(
+                "rows",
+                SyntaxShape::String,
+                "the column whose values become the rows",
+            )
+            .required(
+                "columns",
+                SyntaxShape::String,
+                "the column whose values become the new column names",
+            )
+            .switch("normalize", "give each count as a fraction of all the rows")
+    }
+
+    fn usage(&self) -> &str {
+        "Counts how often each pair of values from two columns occurs together."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, crosstab)?.run()
+    }
+}
+
+struct CountRow {
+    index: Value,
+    counts: IndexMap<String, usize>,
+}
+
+fn crosstab(
+    CrosstabArgs {
+        rows,
+        columns,
+        normalize,
+    }: CrosstabArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let stream = async_stream! {
+        let values: Vec<Value> = input.values.collect().await;
+
+        match count_pairs(&values, &rows, &columns) {
+            Ok((names, table)) => {
+                let total = values.len();
+
+                for row in table.values() {
+                    let mut out = TaggedDictBuilder::new(&name);
+                    out.insert_value(rows.item.clone(), row.index.clone());
+
+                    for column in &names {
+                        let count = row.counts.get(column).cloned().unwrap_or(0);
+
+                        let cell = if normalize {
+                            UntaggedValue::Primitive(Primitive::from(count as f64 / total as f64))
+                        } else {
+                            value::int(count as i64)
+                        };
+
+                        out.insert_untagged(column.clone(), cell);
+                    }
+
+                    yield ReturnSuccess::value(out.into_value());
+                }
+            }
+            Err(err) => yield Err(err),
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+fn count_pairs(
+    values: &[Value],
+    rows: &Tagged<String>,
+    columns: &Tagged<String>,
+) -> Result<(Vec<String>, IndexMap<String, CountRow>), ShellError> {
+    let mut names: Vec<String> = vec![];
+    let mut table: IndexMap<String, CountRow> = IndexMap::new();
+
+    for value in values {
+        let index = value::cell_at(value, rows)?;
+        let column = text(&value::cell_at(value, columns)?);
+
+        if !names.contains(&column) {
+            names.push(column.clone());
+        }
+
+        let entry = table.entry(text(&index)).or_insert_with(|| CountRow {
+            index: index.clone(),
+            counts: IndexMap::new(),
+        });
+
+        *entry.counts.entry(column).or_insert(0) += 1;
+    }
+
+    Ok((names, table))
+}
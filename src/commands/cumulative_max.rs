@@ -0,0 +1,39 @@
+use crate::commands::cumulative_min::{
+    cumulative_extreme, extreme_signature, CumulativeExtremeArgs,
+};
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::Signature;
+use std::cmp::Ordering;
+
+pub struct CumulativeMax;
+
+impl WholeStreamCommand for CumulativeMax {
+    fn name(&self) -> &str {
+        "cumulative-max"
+    }
+
+    fn signature(&self) -> Signature {
+        extreme_signature("cumulative-max", "the running maximum", "max")
+    }
+
+    fn usage(&self) -> &str {
+        "Adds a column with the largest value of the given column so far."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, cumulative_max)?.run()
+    }
+}
+
+fn cumulative_max(
+    args: CumulativeExtremeArgs,
+    context: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    cumulative_extreme(args, context, "max", Ordering::Greater)
+}
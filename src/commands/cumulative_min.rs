@@ -0,0 +1,111 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::base::coerce_compare;
+use crate::data::base::shape::TypeShape;
+use crate::data::value;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+use std::cmp::Ordering;
+
+pub struct CumulativeMin;
+
+#[derive(Deserialize)]
+pub struct CumulativeExtremeArgs {
+    column: Tagged<String>,
+    into: Option<Tagged<String>>,
+}
+
+impl WholeStreamCommand for CumulativeMin {
+    fn name(&self) -> &str {
+        "cumulative-min"
+    }
+
+    fn signature(&self) -> Signature {
+        extreme_signature("cumulative-min", "the running minimum", "min")
+    }
+
+    fn usage(&self) -> &str {
+        "Adds a column with the smallest value of the given column so far."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, cumulative_min)?.run()
+    }
+}
+
+fn cumulative_min(
+    args: CumulativeExtremeArgs,
+    context: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    cumulative_extreme(args, context, "min", Ordering::Less)
+}
+
+pub(crate) fn extreme_signature(name: &str, running: &str, suffix: &str) -> Signature {
+    Signature::build(name)
+        .required(
+            "column",
+            SyntaxShape::String,
+            "the column holding the values to compare",
+        )
+        .named(
+            "into",
+            SyntaxShape::String,
+            format!(
+                "the column to store {} in (defaults to <column>_{})",
+                running, suffix
+            ),
+        )
+}
+
+/// Adds the running extreme of a column, replacing the held value whenever a cell orders
+/// as `keep` against it.
+pub(crate) fn cumulative_extreme(
+    CumulativeExtremeArgs { column, into }: CumulativeExtremeArgs,
+    RunnableContext { input, .. }: RunnableContext,
+    suffix: &str,
+    keep: Ordering,
+) -> Result<OutputStream, ShellError> {
+    let into = match into {
+        Some(into) => into.item,
+        None => format!("{}_{}", column.item, suffix),
+    };
+
+    let mut held: Option<Value> = None;
+
+    Ok(input
+        .values
+        .map(move |row| {
+            let cell = value::cell_at(&row, &column)?;
+
+            let extreme = match held.take() {
+                Some(held) if compare(&cell.value, &held.value) != keep => held,
+                _ => cell,
+            };
+
+            held = Some(extreme.clone());
+
+            match row.insert_data_at_path(&into, extreme) {
+                Some(row) => ReturnSuccess::value(row),
+                None => Err(ShellError::labeled_error(
+                    "Could not add the running value",
+                    "expected a row",
+                    row.tag(),
+                )),
+            }
+        })
+        .to_output_stream())
+}
+
+/// Values that can't be compared with each other, like a string and a number, are
+/// ordered by their types instead.
+pub(crate) fn compare(left: &UntaggedValue, right: &UntaggedValue) -> Ordering {
+    match coerce_compare(left, right) {
+        Ok(coerced) => coerced.compare(),
+        Err(_) => TypeShape::from_value(left).cmp(&TypeShape::from_value(right)),
+    }
+}
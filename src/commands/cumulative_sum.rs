@@ -0,0 +1,108 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::value;
+use crate::evaluate::operator::apply_operator;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_parser::Operator;
+use nu_protocol::{
+    Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::Tagged;
+
+pub struct CumulativeSum;
+
+#[derive(Deserialize)]
+pub struct CumulativeSumArgs {
+    column: Tagged<String>,
+    into: Option<Tagged<String>>,
+}
+
+impl WholeStreamCommand for CumulativeSum {
+    fn name(&self) -> &str {
+        "cumulative-sum"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("cumulative-sum")
+            .required(
+                "column",
+                SyntaxShape::String,
+                "the column holding the numbers to add up",
+            )
+            .named(
+                "into",
+                SyntaxShape::String,
+                "the column to store the running total in (defaults to <column>_total)",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Adds a column with the running total of the given column."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, cumulative_sum)?.run()
+    }
+}
+
+fn cumulative_sum(
+    CumulativeSumArgs { column, into }: CumulativeSumArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let into = match into {
+        Some(into) => into.item,
+        None => format!("{}_total", column.item),
+    };
+
+    let mut total: Option<Value> = None;
+
+    Ok(input
+        .values
+        .map(move |row| {
+            let cell = value::cell_at(&row, &column)?;
+
+            match &cell.value {
+                UntaggedValue::Primitive(Primitive::Int(_))
+                | UntaggedValue::Primitive(Primitive::Decimal(_)) => {}
+                _ => {
+                    return Err(ShellError::labeled_error_with_secondary(
+                        "Expected a number",
+                        format!("found {} instead", cell.type_name()),
+                        cell.tag(),
+                        "row originates from here",
+                        row.tag(),
+                    ))
+                }
+            }
+
+            let sum = match &total {
+                Some(total) => match apply_operator(&Operator::Plus, total, &cell) {
+                    Ok(sum) => sum.into_value(cell.tag()),
+                    Err((left, right)) => {
+                        return Err(ShellError::labeled_error(
+                            "Could not add the values",
+                            format!("cannot add {} and {}", left, right),
+                            cell.tag(),
+                        ))
+                    }
+                },
+                None => cell.clone(),
+            };
+
+            total = Some(sum.clone());
+
+            match row.insert_data_at_path(&into, sum) {
+                Some(row) => ReturnSuccess::value(row),
+                None => Err(ShellError::labeled_error(
+                    "Could not add the running total",
+                    "expected a row",
+                    row.tag(),
+                )),
+            }
+        })
+        .to_output_stream())
+}
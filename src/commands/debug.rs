@@ -2,12 +2,18 @@ use crate::commands::WholeStreamCommand;
 use crate::data::value;
 use crate::prelude::*;
 use nu_errors::ShellError;
-use nu_protocol::{ReturnSuccess, Signature};
+use nu_parser::hir::syntax_shape::ExpandContext;
+use nu_parser::{expand_syntax, PipelineShape, TokensIterator};
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape};
+use nu_source::Tagged;
+use std::path::PathBuf;
 
 pub struct Debug;
 
 #[derive(Deserialize)]
-pub struct DebugArgs {}
+pub struct DebugArgs {
+    ast: Option<Tagged<String>>,
+}
 
 impl WholeStreamCommand for Debug {
     fn name(&self) -> &str {
@@ -15,7 +21,11 @@ impl WholeStreamCommand for Debug {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("debug")
+        Signature::build("debug").named(
+            "ast",
+            SyntaxShape::String,
+            "print how the given pipeline is parsed instead, eg \"ls | where size > 10\"",
+        )
     }
 
     fn usage(&self) -> &str {
@@ -32,11 +42,44 @@ impl WholeStreamCommand for Debug {
 }
 
 fn debug_value(
-    _args: DebugArgs,
-    RunnableContext { input, .. }: RunnableContext,
-) -> Result<impl ToOutputStream, ShellError> {
+    DebugArgs { ast }: DebugArgs,
+    RunnableContext {
+        input,
+        commands,
+        shell_manager,
+        ..
+    }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    if let Some(source) = ast {
+        let rendered = debug_ast(&source.item, &commands, shell_manager.homedir());
+
+        return Ok(OutputStream::one(ReturnSuccess::value(
+            value::string(rendered).into_value(source.tag()),
+        )));
+    }
+
     Ok(input
         .values
         .map(|v| ReturnSuccess::value(value::string(format!("{:?}", v)).into_untagged_value()))
         .to_output_stream())
 }
+
+/// Renders the classified pipeline of the source. If it doesn't classify, the token tree the
+/// parser got as far as is rendered instead, followed by the error.
+fn debug_ast(source: &str, registry: &CommandRegistry, homedir: Option<PathBuf>) -> String {
+    let text = Text::from(source);
+
+    let pipeline = match nu_parser::parse(source) {
+        Ok(pipeline) => pipeline,
+        Err(err) => return format!("{}", err),
+    };
+
+    let mut pipeline_list = vec![pipeline.clone()];
+    let mut iterator = TokensIterator::all(&mut pipeline_list, text.clone(), pipeline.span());
+    let context = ExpandContext::new(Box::new(registry.clone()), &text, homedir);
+
+    match expand_syntax(&PipelineShape, &mut iterator, &context) {
+        Ok(classified) => classified.debug(source),
+        Err(err) => format!("{}\n{}", pipeline.debug(source), ShellError::from(err)),
+    }
+}
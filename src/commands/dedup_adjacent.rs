@@ -0,0 +1,78 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::{value, TaggedDictBuilder};
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, Value};
+
+pub struct DedupAdjacent;
+
+#[derive(Deserialize)]
+pub struct DedupAdjacentArgs {
+    count: bool,
+}
+
+impl WholeStreamCommand for DedupAdjacent {
+    fn name(&self) -> &str {
+        "dedup-adjacent"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("dedup-adjacent").switch(
+            "count",
+            "return each run as a row with the value and how many times it repeated",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Collapses runs of the same value next to each other, leaving other repeats alone."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, dedup_adjacent)?.run()
+    }
+}
+
+fn dedup_adjacent(
+    DedupAdjacentArgs { count }: DedupAdjacentArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let stream = async_stream! {
+        let mut values = input.values;
+        let mut run: Option<(Value, usize)> = None;
+
+        while let Some(item) = values.next().await {
+            match run.take() {
+                Some((current, length)) if current.value == item.value => {
+                    run = Some((current, length + 1));
+                }
+                Some((current, length)) => {
+                    run = Some((item, 1));
+                    yield ReturnSuccess::value(finish_run(current, length, count));
+                }
+                None => run = Some((item, 1)),
+            }
+        }
+
+        if let Some((current, length)) = run {
+            yield ReturnSuccess::value(finish_run(current, length, count));
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+fn finish_run(current: Value, length: usize, count: bool) -> Value {
+    if !count {
+        return current;
+    }
+
+    let tag = current.tag();
+    let mut row = TaggedDictBuilder::new(&tag);
+    row.insert_value("value", current);
+    row.insert_untagged("count", value::int(length as i64));
+    row.into_value()
+}
@@ -0,0 +1,143 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::value;
+use crate::evaluate::operator::apply_operator;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_parser::Operator;
+use nu_protocol::{
+    Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::Tagged;
+
+pub struct Delta;
+
+#[derive(Deserialize)]
+pub struct DeltaArgs {
+    column: Tagged<String>,
+    into: Option<Tagged<String>>,
+    #[serde(rename(deserialize = "first-zero"))]
+    first_zero: bool,
+    percent: bool,
+}
+
+impl WholeStreamCommand for Delta {
+    fn name(&self) -> &str {
+        "delta"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("delta")
+            .required(
+                "column",
+                SyntaxShape::String,
+                "the column holding the numbers to compare",
+            )
+            .named(
+                "into",
+                SyntaxShape::String,
+                "the column to store the difference in (defaults to <column>_delta)",
+            )
+            .switch(
+                "first-zero",
+                "give the first row a difference of 0 instead of nothing",
+            )
+            .switch(
+                "percent",
+                "give the change as a percentage of the previous value",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Adds a column with the difference between each row's value and the previous row's."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, delta)?.run()
+    }
+}
+
+fn delta(
+    DeltaArgs {
+        column,
+        into,
+        first_zero,
+        percent,
+    }: DeltaArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let into = match into {
+        Some(into) => into.item,
+        None => format!("{}_delta", column.item),
+    };
+
+    let mut previous: Option<Value> = None;
+
+    Ok(input
+        .values
+        .map(move |row| {
+            let cell = value::cell_at(&row, &column)?;
+
+            match &cell.value {
+                UntaggedValue::Primitive(Primitive::Int(_))
+                | UntaggedValue::Primitive(Primitive::Decimal(_)) => {}
+                _ => {
+                    return Err(ShellError::labeled_error_with_secondary(
+                        "Expected a number",
+                        format!("found {} instead", cell.type_name()),
+                        cell.tag(),
+                        "row originates from here",
+                        row.tag(),
+                    ))
+                }
+            }
+
+            let difference = match &previous {
+                None if first_zero => value::int(0),
+                None => value::nothing(),
+                Some(previous) => {
+                    let difference = match apply_operator(&Operator::Minus, &cell, previous) {
+                        Ok(difference) => difference,
+                        Err((left, right)) => {
+                            return Err(ShellError::labeled_error(
+                                "Could not subtract the values",
+                                format!("cannot subtract {} from {}", right, left),
+                                cell.tag(),
+                            ))
+                        }
+                    };
+
+                    if percent {
+                        percentage(&difference, previous)
+                    } else {
+                        difference
+                    }
+                }
+            };
+
+            previous = Some(cell.clone());
+
+            match row.insert_data_at_path(&into, difference.into_value(cell.tag())) {
+                Some(row) => ReturnSuccess::value(row),
+                None => Err(ShellError::labeled_error(
+                    "Could not add the difference",
+                    "expected a row",
+                    row.tag(),
+                )),
+            }
+        })
+        .to_output_stream())
+}
+
+/// Change from zero has no percentage, so it is left as nothing.
+fn percentage(difference: &UntaggedValue, previous: &Value) -> UntaggedValue {
+    let (difference, previous) = match (value::as_f64(difference), value::as_f64(previous)) {
+        (Some(difference), Some(previous)) if previous != 0.0 => (difference, previous),
+        _ => return value::nothing(),
+    };
+
+    UntaggedValue::Primitive(Primitive::from(difference / previous.abs() * 100.0))
+}
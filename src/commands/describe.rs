@@ -0,0 +1,78 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::value::format_leaf;
+use crate::data::{value, TaggedDictBuilder};
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, ShellTypeName, Signature, UntaggedValue, Value};
+
+pub struct Describe;
+
+#[derive(Deserialize)]
+pub struct DescribeArgs {
+    inline: bool,
+}
+
+impl WholeStreamCommand for Describe {
+    fn name(&self) -> &str {
+        "describe"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("describe").switch(
+            "inline",
+            "show each value with its type alongside it, eg 5 (integer), annotating every cell of a row",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Describes the type of each value."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, describe)?.run()
+    }
+}
+
+fn describe(
+    DescribeArgs { inline }: DescribeArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    Ok(input
+        .values
+        .map(move |item| {
+            let tag = item.tag();
+
+            if !inline {
+                return ReturnSuccess::value(value::string(item.type_name()).into_value(tag));
+            }
+
+            match &item.value {
+                UntaggedValue::Row(row) => {
+                    let mut annotated = TaggedDictBuilder::new(&tag);
+
+                    for (column, cell) in row.entries.iter() {
+                        annotated.insert_value(
+                            column.clone(),
+                            value::string(annotate(cell)).into_value(cell.tag()),
+                        );
+                    }
+
+                    ReturnSuccess::value(annotated.into_value())
+                }
+                _ => ReturnSuccess::value(value::string(annotate(&item)).into_value(tag)),
+            }
+        })
+        .to_output_stream())
+}
+
+fn annotate(item: &Value) -> String {
+    format!(
+        "{} ({})",
+        format_leaf(&item.value).plain_string(100_000),
+        item.type_name()
+    )
+}
@@ -0,0 +1,266 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::{value, TaggedDictBuilder};
+use crate::prelude::*;
+use indexmap::IndexMap;
+use nu_errors::ShellError;
+use nu_protocol::{Evaluate, ReturnSuccess, Scope, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+
+pub struct Diff;
+
+#[derive(Deserialize)]
+pub struct DiffArgs {
+    other: Evaluate,
+    on: Option<Tagged<String>>,
+}
+
+impl WholeStreamCommand for Diff {
+    fn name(&self) -> &str {
+        "diff"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("diff")
+            .required(
+                "other",
+                SyntaxShape::Block,
+                "the block giving the table to compare against (the input table is $it)",
+            )
+            .named(
+                "on",
+                SyntaxShape::String,
+                "the column that identifies a row in both tables",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Shows the rows added, removed and changed between the input table and another table."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, diff)?.run()
+    }
+}
+
+fn diff(
+    DiffArgs { other, on }: DiffArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let stream = async_stream! {
+        let left: Vec<Value> = input.values.collect().await;
+
+        let right = match other_table(&other, &left, &name) {
+            Ok(right) => right,
+            Err(err) => {
+                yield Err(err);
+                return;
+            }
+        };
+
+        let changes = match &on {
+            Some(key) => diff_on_key(&left, &right, key, &name),
+            None => Ok(diff_rows(&left, &right, &name)),
+        };
+
+        match changes {
+            Ok(changes) => {
+                for change in changes {
+                    yield ReturnSuccess::value(change);
+                }
+            }
+            Err(err) => yield Err(err),
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+/// Evaluates a block that produces the second table of a two-table command.
+///
+/// The block sees the input table as `$it`. A block that gives back a single value is
+/// treated as a table with one row.
+pub fn other_table(
+    block: &Evaluate,
+    input: &[Value],
+    tag: impl Into<Tag>,
+) -> Result<Vec<Value>, ShellError> {
+    let scope = Scope::new(value::table(&input.to_vec()).into_value(tag));
+
+    match block.invoke(&scope)? {
+        Value {
+            value: UntaggedValue::Table(rows),
+            ..
+        } => Ok(rows),
+        other => Ok(vec![other]),
+    }
+}
+
+/// Compares two values while ignoring where they came from.
+pub fn same_value(left: &Value, right: &Value) -> bool {
+    match (&left.value, &right.value) {
+        (UntaggedValue::Row(l), UntaggedValue::Row(r)) => {
+            l.entries.len() == r.entries.len()
+                && l.entries.iter().all(|(key, l)| match r.entries.get(key) {
+                    Some(r) => same_value(l, r),
+                    None => false,
+                })
+        }
+        (UntaggedValue::Table(l), UntaggedValue::Table(r)) => {
+            l.len() == r.len() && l.iter().zip(r.iter()).all(|(l, r)| same_value(l, r))
+        }
+        (l, r) => l == r,
+    }
+}
+
+fn diff_rows(left: &[Value], right: &[Value], tag: &Tag) -> Vec<Value> {
+    let mut unmatched: Vec<&Value> = right.iter().collect();
+    let mut changes = vec![];
+
+    for row in left {
+        match unmatched.iter().position(|other| same_value(row, other)) {
+            Some(index) => {
+                unmatched.remove(index);
+            }
+            None => changes.push(change("removed", None, None, Some(row), None, tag)),
+        }
+    }
+
+    for row in unmatched {
+        changes.push(change("added", None, None, None, Some(row), tag));
+    }
+
+    changes
+}
+
+fn diff_on_key(
+    left: &[Value],
+    right: &[Value],
+    key: &Tagged<String>,
+    tag: &Tag,
+) -> Result<Vec<Value>, ShellError> {
+    let left = index_by_key(left, key)?;
+    let right = index_by_key(right, key)?;
+
+    let mut changes = vec![];
+
+    for (id, (key_value, row)) in &left {
+        match right.get(id) {
+            Some((_, other)) => {
+                for (column, old, new) in changed_columns(row, other) {
+                    changes.push(change(
+                        "changed",
+                        Some((&key.item, key_value)),
+                        Some(&column),
+                        Some(&old),
+                        Some(&new),
+                        tag,
+                    ));
+                }
+            }
+            None => changes.push(change(
+                "removed",
+                Some((&key.item, key_value)),
+                None,
+                Some(row),
+                None,
+                tag,
+            )),
+        }
+    }
+
+    for (id, (key_value, row)) in &right {
+        if !left.contains_key(id) {
+            changes.push(change(
+                "added",
+                Some((&key.item, key_value)),
+                None,
+                None,
+                Some(row),
+                tag,
+            ));
+        }
+    }
+
+    Ok(changes)
+}
+
+/// Indexes the rows by the text of their key, which has to be unique within the table.
+fn index_by_key(
+    rows: &[Value],
+    key: &Tagged<String>,
+) -> Result<IndexMap<String, (Value, Value)>, ShellError> {
+    let mut index = IndexMap::new();
+
+    for row in rows {
+        let key_value = value::cell_at(row, key)?;
+        let id = value::format_leaf(&key_value.value).plain_string(std::usize::MAX);
+
+        if index.contains_key(&id) {
+            return Err(ShellError::labeled_error_with_secondary(
+                "Duplicate key",
+                format!("more than one row has {} in this column", id),
+                key.tag(),
+                "repeated in this row",
+                row.tag(),
+            ));
+        }
+
+        index.insert(id, (key_value, row.clone()));
+    }
+
+    Ok(index)
+}
+
+/// The columns whose values differ, with a missing column reading as nothing.
+pub(crate) fn changed_columns(old: &Value, new: &Value) -> Vec<(String, Value, Value)> {
+    let mut columns = old.data_descriptors();
+
+    for column in new.data_descriptors() {
+        if !columns.contains(&column) {
+            columns.push(column);
+        }
+    }
+
+    columns
+        .into_iter()
+        .filter_map(|column| {
+            let before = old.get_data(&column).borrow().clone();
+            let after = new.get_data(&column).borrow().clone();
+
+            if same_value(&before, &after) {
+                None
+            } else {
+                Some((column, before, after))
+            }
+        })
+        .collect()
+}
+
+fn change(
+    status: &str,
+    key: Option<(&str, &Value)>,
+    column: Option<&str>,
+    old: Option<&Value>,
+    new: Option<&Value>,
+    tag: &Tag,
+) -> Value {
+    let mut change = TaggedDictBuilder::new(tag);
+
+    if let Some((key, key_value)) = key {
+        change.insert_value(key, key_value.clone());
+    }
+
+    change.insert_untagged("status", value::string(status));
+    change.insert_untagged("column", or_nothing(column.map(value::string)));
+    change.insert_untagged("old", or_nothing(old.map(|old| old.value.clone())));
+    change.insert_untagged("new", or_nothing(new.map(|new| new.value.clone())));
+    change.into_value()
+}
+
+fn or_nothing(cell: Option<UntaggedValue>) -> UntaggedValue {
+    cell.unwrap_or_else(value::nothing)
+}
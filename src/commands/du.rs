@@ -0,0 +1,216 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::{value, TaggedDictBuilder};
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, Value};
+use nu_source::Tagged;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::Ordering;
+
+#[derive(Deserialize)]
+struct DuArgs {
+    path: Option<Tagged<PathBuf>>,
+    recursive: bool,
+    #[serde(rename(deserialize = "by-entry"))]
+    by_entry: bool,
+    #[serde(rename(deserialize = "report-errors"))]
+    report_errors: bool,
+}
+
+pub struct Du;
+
+impl WholeStreamCommand for Du {
+    fn name(&self) -> &str {
+        "du"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("du")
+            .optional(
+                "path",
+                SyntaxShape::Pattern,
+                "the path or glob to measure (defaults to everything in the current directory)",
+            )
+            .switch("recursive", "descend into subdirectories")
+            .switch(
+                "by-entry",
+                "give a table with the size of each matching entry",
+            )
+            .switch(
+                "report-errors",
+                "add an errors column listing the entries that couldn't be read",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Sums the size of the files matching a path or glob."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, du)?.run()
+    }
+}
+
+#[derive(Default)]
+struct Usage {
+    size: u64,
+    errors: Vec<String>,
+}
+
+impl Usage {
+    fn into_value(self, name: Option<&Path>, report_errors: bool, tag: &Tag) -> Value {
+        if name.is_none() && !report_errors {
+            return value::bytes(self.size).into_value(tag);
+        }
+
+        let mut dict = TaggedDictBuilder::new(tag);
+
+        if let Some(name) = name {
+            dict.insert_untagged("name", value::string(name.to_string_lossy()));
+        }
+
+        dict.insert_untagged("size", value::bytes(self.size));
+
+        if report_errors {
+            let errors: Vec<Value> = self
+                .errors
+                .into_iter()
+                .map(|error| value::string(error).into_value(tag))
+                .collect();
+            dict.insert_untagged("errors", value::table(&errors));
+        }
+
+        dict.into_value()
+    }
+}
+
+fn du(
+    DuArgs {
+        path,
+        recursive,
+        by_entry,
+        report_errors,
+    }: DuArgs,
+    RunnableContext {
+        name,
+        shell_manager,
+        ctrl_c,
+        ..
+    }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let cwd = PathBuf::from(shell_manager.path());
+    let pattern = match &path {
+        Some(path) => cwd.join(&path.item),
+        None => cwd.join("*"),
+    };
+
+    let entries = glob::glob(&pattern.to_string_lossy()).map_err(|_| match &path {
+        Some(path) => ShellError::labeled_error("Invalid pattern", "invalid pattern", path.tag()),
+        None => ShellError::untagged_runtime_error("Invalid pattern."),
+    })?;
+
+    let mut visited = HashSet::new();
+    let mut total = Usage::default();
+    let mut output = VecDeque::new();
+
+    for entry in entries {
+        if ctrl_c.load(Ordering::SeqCst) {
+            break;
+        }
+
+        let mut usage = Usage::default();
+
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(err) => {
+                total.errors.push(err.to_string());
+                continue;
+            }
+        };
+
+        measure(&entry, true, recursive, &mut visited, &mut usage);
+
+        if by_entry {
+            let entry_name = entry.strip_prefix(&cwd).unwrap_or(&entry);
+            output.push_back(ReturnSuccess::value(usage.into_value(
+                Some(entry_name),
+                report_errors,
+                &name,
+            )));
+        } else {
+            total.size += usage.size;
+            total.errors.extend(usage.errors);
+        }
+    }
+
+    if !by_entry {
+        output.push_back(ReturnSuccess::value(total.into_value(
+            None,
+            report_errors,
+            &name,
+        )));
+    }
+
+    Ok(output.to_output_stream())
+}
+
+/// Adds the size of the path to `usage`, following symlinks. The entries of a directory are
+/// counted when it's a top-level match or when descending `recursive`ly. Directories are only
+/// ever measured once, so symlink loops end the walk instead of repeating it. Entries that
+/// can't be read count as zero and are recorded in `usage.errors`.
+fn measure(
+    path: &Path,
+    top_level: bool,
+    recursive: bool,
+    visited: &mut HashSet<PathBuf>,
+    usage: &mut Usage,
+) {
+    let metadata = match std::fs::metadata(path) {
+        Ok(metadata) => metadata,
+        Err(err) => {
+            usage.errors.push(format!("{}: {}", path.display(), err));
+            return;
+        }
+    };
+
+    if !metadata.is_dir() {
+        usage.size += metadata.len();
+        return;
+    }
+
+    if !top_level && !recursive {
+        return;
+    }
+
+    match dunce::canonicalize(path) {
+        Ok(canonical) => {
+            if !visited.insert(canonical) {
+                return;
+            }
+        }
+        Err(err) => {
+            usage.errors.push(format!("{}: {}", path.display(), err));
+            return;
+        }
+    }
+
+    let entries = match std::fs::read_dir(path) {
+        Ok(entries) => entries,
+        Err(err) => {
+            usage.errors.push(format!("{}: {}", path.display(), err));
+            return;
+        }
+    };
+
+    for entry in entries {
+        match entry {
+            Ok(entry) => measure(&entry.path(), false, recursive, visited, usage),
+            Err(err) => usage.errors.push(format!("{}: {}", path.display(), err)),
+        }
+    }
+}
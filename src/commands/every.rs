@@ -0,0 +1,88 @@
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape};
+use nu_source::Tagged;
+
+#[derive(Deserialize)]
+struct EveryArgs {
+    stride: Tagged<u64>,
+    offset: Option<Tagged<u64>>,
+    skip: bool,
+}
+
+pub struct Every;
+
+impl WholeStreamCommand for Every {
+    fn name(&self) -> &str {
+        "every"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("every")
+            .required(
+                "stride",
+                SyntaxShape::Int,
+                "return every nth row (a stride of 1 returns every row)",
+            )
+            .named(
+                "offset",
+                SyntaxShape::Int,
+                "the index of the first row to return",
+            )
+            .switch("skip", "drop the selected rows and return the rest instead")
+    }
+
+    fn usage(&self) -> &str {
+        "Return every nth row, starting at the first (or the given offset)"
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, every)?.run()
+    }
+}
+
+fn every(
+    EveryArgs {
+        stride,
+        offset,
+        skip,
+    }: EveryArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    if stride.item == 0 {
+        return Err(ShellError::labeled_error(
+            "Stride must be positive",
+            "stride of zero",
+            stride.tag(),
+        ));
+    }
+
+    let stride = stride.item;
+    let offset = offset.map(|offset| offset.item).unwrap_or(0);
+
+    let stream = input
+        .values
+        .enumerate()
+        .map(move |(idx, item)| {
+            let mut result = VecDeque::new();
+
+            if is_selected(idx as u64, stride, offset) != skip {
+                result.push_back(ReturnSuccess::value(item));
+            }
+
+            result
+        })
+        .flatten();
+
+    Ok(stream.to_output_stream())
+}
+
+fn is_selected(idx: u64, stride: u64, offset: u64) -> bool {
+    idx >= offset && (idx - offset) % stride == 0
+}
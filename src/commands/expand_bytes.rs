@@ -0,0 +1,95 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::{value, TaggedDictBuilder};
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, ReturnSuccess, Signature, UntaggedValue, Value};
+
+pub struct ExpandBytes;
+
+#[derive(Deserialize)]
+pub struct ExpandBytesArgs {
+    #[serde(rename(deserialize = "printable-only"))]
+    printable_only: bool,
+}
+
+impl WholeStreamCommand for ExpandBytes {
+    fn name(&self) -> &str {
+        "expand-bytes"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("expand-bytes")
+            .switch("printable-only", "only keep the printable ASCII bytes")
+    }
+
+    fn usage(&self) -> &str {
+        "Expands binary data into a table with one row per byte."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, expand_bytes)?.run()
+    }
+}
+
+fn expand_bytes(
+    ExpandBytesArgs { printable_only }: ExpandBytesArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let stream = input
+        .values
+        .map(move |v| {
+            let mut result = VecDeque::new();
+
+            match v {
+                Value {
+                    value: UntaggedValue::Primitive(Primitive::Binary(bytes)),
+                    tag,
+                } => {
+                    for (offset, byte) in bytes.iter().enumerate() {
+                        if printable_only && !is_printable(*byte) {
+                            continue;
+                        }
+
+                        let mut row = TaggedDictBuilder::new(&tag);
+                        row.insert_untagged("offset", value::int(offset));
+                        row.insert_untagged("byte", value::int(*byte));
+                        row.insert_untagged("hex", value::string(format!("{:02x}", byte)));
+                        row.insert_untagged("char", value::string(display_char(*byte)));
+
+                        result.push_back(ReturnSuccess::value(row.into_value()));
+                    }
+                }
+                Value { tag, .. } => {
+                    result.push_back(Err(ShellError::labeled_error_with_secondary(
+                        "Expected binary data from pipeline",
+                        "requires binary data input",
+                        &name,
+                        "value originates from here",
+                        tag,
+                    )));
+                }
+            }
+
+            result
+        })
+        .flatten();
+
+    Ok(stream.to_output_stream())
+}
+
+fn is_printable(byte: u8) -> bool {
+    (0x20..=0x7e).contains(&byte)
+}
+
+/// Printable ASCII bytes show as themselves and everything else as `.`, like a hexdump.
+fn display_char(byte: u8) -> String {
+    if is_printable(byte) {
+        (byte as char).to_string()
+    } else {
+        ".".to_string()
+    }
+}
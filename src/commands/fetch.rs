@@ -1,16 +1,23 @@
+use crate::commands::from_json::{convert_json_value_to_nu_value, DEFAULT_MAX_DEPTH};
+use crate::commands::get::get_column_path;
 use crate::commands::UnevaluatedCallInfo;
 use crate::data::value;
 use crate::prelude::*;
 use mime::Mime;
 use nu_errors::ShellError;
-use nu_protocol::{CallInfo, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
-use nu_source::{AnchorLocation, Span};
+use nu_protocol::{
+    CallInfo, ColumnPath, Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::{AnchorLocation, Span, Tagged};
 use std::path::PathBuf;
 use std::str::FromStr;
 use surf::mime;
 
 pub struct Fetch;
 
+/// How many pages `fetch --next` follows unless `--max-pages` says otherwise.
+const DEFAULT_MAX_PAGES: usize = 100;
+
 impl PerItemCommand for Fetch {
     fn name(&self) -> &str {
         "fetch"
@@ -24,6 +31,16 @@ impl PerItemCommand for Fetch {
                 "the URL to fetch the contents from",
             )
             .switch("raw", "fetch contents as text rather than a table")
+            .named(
+                "next",
+                SyntaxShape::ColumnPath,
+                "the column path holding the next page's URL, to follow paginated JSON",
+            )
+            .named(
+                "max-pages",
+                SyntaxShape::Int,
+                "the most pages to follow with --next (defaults to 100)",
+            )
     }
 
     fn usage(&self) -> &str {
@@ -59,9 +76,44 @@ fn run(
     let path_str = path_buf.display().to_string();
     let path_span = path.tag.span;
     let has_raw = call_info.args.has("raw");
+    let next = match call_info.args.get("next") {
+        Some(next) => Some(next.as_column_path()?),
+        None => None,
+    };
+    let max_pages = match call_info.args.get("max-pages") {
+        Some(Value {
+            value: UntaggedValue::Primitive(Primitive::Int(max_pages)),
+            tag,
+        }) => max_pages.to_usize().ok_or_else(|| {
+            ShellError::labeled_error("Invalid page limit", "expected a positive number", tag)
+        })?,
+        Some(other) => {
+            return Err(ShellError::labeled_error(
+                "Invalid page limit",
+                "expected a number",
+                other.tag(),
+            ))
+        }
+        None => DEFAULT_MAX_PAGES,
+    };
     let registry = registry.clone();
     let raw_args = raw_args.clone();
 
+    if let Some(next) = next {
+        let stream = async_stream! {
+            match fetch_pages(&path_str, &next, max_pages, path_span).await {
+                Ok(rows) => {
+                    for row in rows {
+                        yield ReturnSuccess::value(row);
+                    }
+                }
+                Err(err) => yield Err(err),
+            }
+        };
+
+        return Ok(stream.to_output_stream());
+    }
+
     let stream = async_stream! {
 
         let result = fetch(&path_str, path_span).await;
@@ -126,6 +178,85 @@ fn run(
     Ok(stream.to_output_stream())
 }
 
+/// Fetches the page at `location` and keeps following the link found at `next` in each
+/// JSON page, up to `max_pages` pages. The rows of every page are joined together: a page
+/// that is a table contributes its rows, and a page that is a row contributes the first
+/// column holding a table.
+async fn fetch_pages(
+    location: &str,
+    next: &Tagged<ColumnPath>,
+    max_pages: usize,
+    span: Span,
+) -> Result<Vec<Value>, ShellError> {
+    let mut rows = vec![];
+    let mut location = Some(location.to_string());
+    let mut pages = 0;
+
+    while let Some(current) = location.take() {
+        if pages == max_pages {
+            break;
+        }
+
+        pages += 1;
+
+        let (_, contents, tag) = fetch(&current, span).await?;
+
+        let page = match contents {
+            UntaggedValue::Primitive(Primitive::String(text)) => {
+                match serde_hjson::from_str::<serde_hjson::Value>(&text) {
+                    Ok(json) => convert_json_value_to_nu_value(&json, &tag, DEFAULT_MAX_DEPTH)?,
+                    Err(_) => {
+                        return Err(ShellError::labeled_error(
+                            "Could not parse page as JSON",
+                            format!("{} did not return JSON", current),
+                            span,
+                        ))
+                    }
+                }
+            }
+            _ => {
+                return Err(ShellError::labeled_error(
+                    "Could not parse page as JSON",
+                    format!("{} did not return text", current),
+                    span,
+                ))
+            }
+        };
+
+        location = match get_column_path(next, &page) {
+            Ok(link) => match link.as_string() {
+                Ok(ref link) if !link.is_empty() => Some(
+                    url::Url::parse(&current)
+                        .and_then(|base| base.join(link))
+                        .map(|url| url.to_string())
+                        .unwrap_or_else(|_| link.clone()),
+                ),
+                _ => None,
+            },
+            Err(_) => None,
+        };
+
+        match page.value {
+            UntaggedValue::Table(list) => rows.extend(list),
+            UntaggedValue::Row(ref row) => {
+                match row.entries.values().find(|cell| match cell.value {
+                    UntaggedValue::Table(_) => true,
+                    _ => false,
+                }) {
+                    Some(Value {
+                        value: UntaggedValue::Table(list),
+                        ..
+                    }) => rows.extend(list.iter().cloned()),
+                    _ => rows.push(page.clone()),
+                }
+            }
+            _ => rows.push(page),
+        }
+    }
+
+    Ok(rows)
+}
+
 pub async fn fetch(
     location: &str,
     span: Span,
@@ -139,6 +270,17 @@ pub async fn fetch(
     }
 
     let response = surf::get(location).await;
+
+    if let Ok(r) = &response {
+        if !r.status().is_success() {
+            return Err(ShellError::labeled_error(
+                "Request failed",
+                format!("server responded with {}", r.status()),
+                span,
+            ));
+        }
+    }
+
     match response {
         Ok(mut r) => match r.headers().get("content-type") {
             Some(content_type) => {
@@ -0,0 +1,100 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::{value, TaggedDictBuilder};
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+
+pub struct FillNulls;
+
+#[derive(Deserialize)]
+pub struct FillNullsArgs {
+    rest: Vec<Tagged<String>>,
+    with: Option<Tagged<String>>,
+    #[serde(rename(deserialize = "include-empty"))]
+    include_empty: bool,
+}
+
+impl WholeStreamCommand for FillNulls {
+    fn name(&self) -> &str {
+        "fill-nulls"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("fill-nulls")
+            .rest(
+                SyntaxShape::String,
+                "the columns to fill (defaults to every column)",
+            )
+            .named(
+                "with",
+                SyntaxShape::String,
+                "the text to put in place of nothing (defaults to an empty string)",
+            )
+            .switch("include-empty", "also replace empty strings")
+    }
+
+    fn usage(&self) -> &str {
+        "Replaces every empty cell with the same text."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, fill_nulls)?.run()
+    }
+}
+
+fn fill_nulls(
+    FillNullsArgs {
+        rest: columns,
+        with,
+        include_empty,
+    }: FillNullsArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let sentinel = with.map(|with| with.item).unwrap_or_default();
+
+    Ok(input
+        .values
+        .map(move |row| {
+            let filled = match &row.value {
+                UntaggedValue::Row(dict) => {
+                    let mut filled = TaggedDictBuilder::new(row.tag());
+
+                    for (column, cell) in dict.entries.iter() {
+                        let in_scope =
+                            columns.is_empty() || columns.iter().any(|c| &c.item == column);
+
+                        if in_scope && is_null(cell, include_empty) {
+                            filled.insert_value(
+                                column,
+                                value::string(&sentinel).into_value(cell.tag()),
+                            );
+                        } else {
+                            filled.insert_value(column, cell.clone());
+                        }
+                    }
+
+                    filled.into_value()
+                }
+                _ if columns.is_empty() && is_null(&row, include_empty) => {
+                    value::string(&sentinel).into_value(row.tag())
+                }
+                _ => row,
+            };
+
+            ReturnSuccess::value(filled)
+        })
+        .to_output_stream())
+}
+
+fn is_null(cell: &Value, include_empty: bool) -> bool {
+    match &cell.value {
+        UntaggedValue::Primitive(Primitive::Nothing) => true,
+        UntaggedValue::Primitive(Primitive::String(s)) => include_empty && s.is_empty(),
+        _ => false,
+    }
+}
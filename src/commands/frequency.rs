@@ -0,0 +1,105 @@
+use crate::commands::get::get_column_path;
+use crate::commands::WholeStreamCommand;
+use crate::data::{value, TaggedDictBuilder};
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::Tagged;
+use std::collections::BTreeMap;
+
+#[derive(Deserialize)]
+struct FrequencyArgs {
+    column: ColumnPath,
+    top: Option<Tagged<usize>>,
+}
+
+pub struct Frequency;
+
+impl WholeStreamCommand for Frequency {
+    fn name(&self) -> &str {
+        "frequency"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("frequency")
+            .required(
+                "column",
+                SyntaxShape::ColumnPath,
+                "the column whose values are counted",
+            )
+            .named(
+                "top",
+                SyntaxShape::Int,
+                "keep only this many of the most frequent values, counting the rest as other",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Counts how often each value of a column occurs, along with its share of the rows."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, frequency)?.run()
+    }
+}
+
+fn frequency(
+    FrequencyArgs { column, top }: FrequencyArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let stream = async_stream! {
+        let rows: Vec<Value> = input.values.collect().await;
+
+        let mut counts: BTreeMap<UntaggedValue, usize> = BTreeMap::new();
+
+        for row in &rows {
+            match get_column_path(&column, row) {
+                Ok(cell) => *counts.entry(cell.value).or_insert(0) += 1,
+                Err(err) => {
+                    yield Err(err);
+                    return;
+                }
+            }
+        }
+
+        let total = rows.len();
+
+        // The map is ordered by value and the sort is stable, so ties keep that order.
+        let mut counts: Vec<(UntaggedValue, usize)> = counts.into_iter().collect();
+        counts.sort_by(|(_, left), (_, right)| right.cmp(left));
+
+        let rest = match &top {
+            Some(top) if top.item < counts.len() => {
+                Some(counts.split_off(top.item).iter().map(|(_, count)| count).sum())
+            }
+            _ => None,
+        };
+
+        for (cell, count) in counts {
+            yield ReturnSuccess::value(frequency_row(cell, count, total, &name));
+        }
+
+        if let Some(count) = rest {
+            yield ReturnSuccess::value(frequency_row(value::string("other"), count, total, &name));
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+fn frequency_row(cell: UntaggedValue, count: usize, total: usize, tag: &Tag) -> Value {
+    let mut row = TaggedDictBuilder::new(tag);
+    row.insert_untagged("value", cell);
+    row.insert_untagged("count", value::int(count as i64));
+    row.insert_untagged(
+        "fraction",
+        UntaggedValue::Primitive(Primitive::from(count as f64 / total as f64)),
+    );
+    row.into_value()
+}
@@ -201,7 +201,9 @@ pub fn from_bson_bytes_to_value(bytes: Vec<u8>, tag: impl Into<Tag>) -> Result<V
 fn from_bson(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
     let args = args.evaluate_once(registry)?;
     let tag = args.name_tag();
-    let input = args.input;
+    let name_span = tag.span;
+    let ctrl_c = args.ctrl_c.clone();
+    let input = args.input.interruptible(&ctrl_c);
 
     let stream = async_stream! {
         let values: Vec<Value> = input.values.collect().await;
@@ -234,5 +236,5 @@ fn from_bson(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStre
         }
     };
 
-    Ok(stream.to_output_stream())
+    Ok(stream.to_output_stream().interruptible(&ctrl_c, name_span))
 }
@@ -0,0 +1,170 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::{value, TaggedDictBuilder};
+use crate::prelude::*;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, ReturnSuccess, Signature, UntaggedValue, Value};
+
+pub struct FromCookie;
+
+#[derive(Deserialize)]
+pub struct FromCookieArgs {
+    strict: bool,
+}
+
+/// The attributes a Set-Cookie header may carry, spelled the way they are stored in the row.
+const ATTRIBUTES: &[&str] = &[
+    "Path", "Domain", "Expires", "Max-Age", "Secure", "HttpOnly", "SameSite",
+];
+
+impl WholeStreamCommand for FromCookie {
+    fn name(&self) -> &str {
+        "from-cookie"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from-cookie").switch(
+            "strict",
+            "fail on malformed parts of the cookie instead of skipping them",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Parse a Cookie or Set-Cookie header as a row of names and values."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, from_cookie)?.run()
+    }
+}
+
+fn from_cookie(
+    FromCookieArgs { strict }: FromCookieArgs,
+    RunnableContext {
+        input,
+        name,
+        ctrl_c,
+        ..
+    }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let name_span = name.span;
+    let input = input.interruptible(&ctrl_c);
+
+    let stream = async_stream! {
+        let values: Vec<Value> = input.values.collect().await;
+
+        let mut concat_string = String::new();
+
+        for value in values {
+            let value_span = value.tag.span;
+
+            if let Ok(s) = value.as_string() {
+                concat_string.push_str(&s);
+            } else {
+                yield Err(ShellError::labeled_error_with_secondary(
+                    "Expected a string from pipeline",
+                    "requires string input",
+                    name_span,
+                    "value originates from here",
+                    value_span,
+                ))
+            }
+        }
+
+        match parse_cookie(concat_string.trim(), strict, &name) {
+            Ok(row) => yield ReturnSuccess::value(row),
+            Err(err) => yield Err(err),
+        }
+    };
+
+    Ok(stream.to_output_stream().interruptible(&ctrl_c, name_span))
+}
+
+/// The spelling of a cookie attribute, matched without regard to case.
+pub(crate) fn cookie_attribute(name: &str) -> Option<&'static str> {
+    ATTRIBUTES
+        .iter()
+        .find(|attribute| attribute.eq_ignore_ascii_case(name))
+        .cloned()
+}
+
+fn parse_cookie(text: &str, strict: bool, tag: &Tag) -> Result<Value, ShellError> {
+    let mut row = TaggedDictBuilder::new(tag);
+
+    for segment in strip_header_name(text).split(';') {
+        let segment = segment.trim();
+
+        if segment.is_empty() {
+            continue;
+        }
+
+        match parse_segment(segment) {
+            Some((name, value)) => row.insert_untagged(name, value),
+            None if strict => {
+                return Err(ShellError::labeled_error(
+                    "Malformed cookie",
+                    format!("could not read `{}`", segment),
+                    tag,
+                ))
+            }
+            None => {}
+        }
+    }
+
+    Ok(row.into_value())
+}
+
+fn strip_header_name(text: &str) -> &str {
+    let mut parts = text.splitn(2, ':');
+
+    match (parts.next(), parts.next()) {
+        (Some(header), Some(rest))
+            if header.trim().eq_ignore_ascii_case("cookie")
+                || header.trim().eq_ignore_ascii_case("set-cookie") =>
+        {
+            rest
+        }
+        _ => text,
+    }
+}
+
+fn parse_segment(segment: &str) -> Option<(String, UntaggedValue)> {
+    let mut parts = segment.splitn(2, '=');
+    let name = parts.next()?.trim();
+    let text = parts.next().map(|text| text.trim().trim_matches('"'));
+
+    if name.is_empty() {
+        return None;
+    }
+
+    let attribute = match cookie_attribute(name) {
+        Some(attribute) => attribute,
+        None => return Some((name.to_string(), value::string(text?))),
+    };
+
+    let value = match (attribute, text) {
+        ("Secure", None) | ("HttpOnly", None) => value::boolean(true),
+        ("Expires", Some(text)) => UntaggedValue::Primitive(Primitive::Date(parse_expires(text)?)),
+        ("Max-Age", Some(text)) => value::int(text.parse::<i64>().ok()?),
+        (_, Some(text)) => value::string(text),
+        (_, None) => return None,
+    };
+
+    Some((attribute.to_string(), value))
+}
+
+/// Expires dates are usually `Wed, 21 Oct 2015 07:28:00 GMT`, though some servers still
+/// send the older `Wed, 21-Oct-2015 07:28:00 GMT`.
+fn parse_expires(text: &str) -> Option<DateTime<Utc>> {
+    if let Ok(date) = DateTime::parse_from_rfc2822(text) {
+        return Some(date.with_timezone(&Utc));
+    }
+
+    NaiveDateTime::parse_from_str(text, "%a, %d-%b-%Y %H:%M:%S GMT")
+        .ok()
+        .map(|date| DateTime::<Utc>::from_utc(date, Utc))
+}
@@ -10,6 +10,7 @@ pub struct FromCSV;
 pub struct FromCSVArgs {
     headerless: bool,
     separator: Option<Value>,
+    auto: bool,
 }
 
 impl WholeStreamCommand for FromCSV {
@@ -25,6 +26,10 @@ impl WholeStreamCommand for FromCSV {
                 "a character to separate columns, defaults to ','",
             )
             .switch("headerless", "don't treat the first row as column names")
+            .switch(
+                "auto",
+                "detect whether columns are separated by ',', tab, ';' or '|'",
+            )
     }
 
     fn usage(&self) -> &str {
@@ -44,9 +49,18 @@ fn from_csv(
     FromCSVArgs {
         headerless,
         separator,
+        auto,
     }: FromCSVArgs,
     runnable_context: RunnableContext,
 ) -> Result<OutputStream, ShellError> {
+    if let (true, Some(separator)) = (auto, &separator) {
+        return Err(ShellError::labeled_error(
+            "Can't use --separator with --auto",
+            "--auto detects the separator itself",
+            separator.tag(),
+        ));
+    }
+
     let sep = match separator {
         Some(Value {
             value: UntaggedValue::Primitive(Primitive::String(s)),
@@ -67,8 +81,11 @@ fn from_csv(
                 vec_s[0]
             }
         }
+        _ if auto => {
+            return from_delimited_data(headerless, None, "CSV", runnable_context);
+        }
         _ => ',',
     };
 
-    from_delimited_data(headerless, sep, "CSV", runnable_context)
+    from_delimited_data(headerless, Some(sep), "CSV", runnable_context)
 }
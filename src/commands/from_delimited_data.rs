@@ -4,6 +4,12 @@ use csv::ReaderBuilder;
 use nu_errors::ShellError;
 use nu_protocol::{Primitive, ReturnSuccess, UntaggedValue, Value};
 
+/// The delimiters tried when detecting one, comma first since it wins ties.
+const CANDIDATE_DELIMITERS: &[char] = &[',', '\t', ';', '|'];
+
+/// How many lines from the start of the text are looked at to detect the delimiter.
+const SAMPLE_LINES: usize = 10;
+
 fn from_delimited_string_to_value(
     s: String,
     headerless: bool,
@@ -39,13 +45,72 @@ fn from_delimited_string_to_value(
     Ok(UntaggedValue::Table(rows).into_value(&tag))
 }
 
+/// Picks the delimiter that splits the sampled lines into the same number of fields as the
+/// first one most often. Returns whether other delimiters scored as well, in which case it
+/// falls back to comma.
+pub fn detect_delimiter(text: &str) -> (char, bool) {
+    let lines: Vec<&str> = text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .take(SAMPLE_LINES)
+        .collect();
+
+    let scores: Vec<(char, usize)> = CANDIDATE_DELIMITERS
+        .iter()
+        .map(|delimiter| (*delimiter, consistency(&lines, *delimiter)))
+        .collect();
+
+    let best = scores.iter().map(|(_, score)| *score).max().unwrap_or(0);
+    let tied: Vec<char> = scores
+        .iter()
+        .filter(|(_, score)| *score == best)
+        .map(|(delimiter, _)| *delimiter)
+        .collect();
+
+    if tied.len() == 1 {
+        (tied[0], false)
+    } else {
+        (',', true)
+    }
+}
+
+/// How many lines have as many delimiters (outside of quotes) as the first line does.
+fn consistency(lines: &[&str], delimiter: char) -> usize {
+    let counts: Vec<usize> = lines
+        .iter()
+        .map(|line| {
+            let mut quoted = false;
+            line.chars()
+                .filter(|c| {
+                    if *c == '"' {
+                        quoted = !quoted;
+                    }
+                    !quoted && *c == delimiter
+                })
+                .count()
+        })
+        .collect();
+
+    match counts.first() {
+        Some(0) | None => 0,
+        Some(first) => counts.iter().filter(|count| *count == first).count(),
+    }
+}
+
 pub fn from_delimited_data(
     headerless: bool,
-    sep: char,
+    sep: Option<char>,
     format_name: &'static str,
-    RunnableContext { input, name, .. }: RunnableContext,
+    RunnableContext {
+        input,
+        name,
+        ctrl_c,
+        ..
+    }: RunnableContext,
 ) -> Result<OutputStream, ShellError> {
+    let name_span = name.span;
     let name_tag = name;
+    let input = input.interruptible(&ctrl_c);
 
     let stream = async_stream! {
         let values: Vec<Value> = input.values.collect().await;
@@ -69,6 +134,22 @@ pub fn from_delimited_data(
             }
         }
 
+        let sep = match sep {
+            Some(sep) => sep,
+            None => {
+                let (sep, ambiguous) = detect_delimiter(&concat_string);
+
+                if ambiguous {
+                    errln!(
+                        "warning: could not tell which delimiter the {} uses, reading it with ','",
+                        format_name
+                    );
+                }
+
+                sep
+            }
+        };
+
         match from_delimited_string_to_value(concat_string, headerless, sep, name_tag.clone()) {
             Ok(x) => match x {
                 Value { value: UntaggedValue::Table(list), .. } => {
@@ -92,5 +173,5 @@ pub fn from_delimited_data(
         }
     };
 
-    Ok(stream.to_output_stream())
+    Ok(stream.to_output_stream().interruptible(&ctrl_c, name_span))
 }
@@ -0,0 +1,117 @@
+use crate::commands::get::get_column_path;
+use crate::commands::WholeStreamCommand;
+use crate::data::value;
+use crate::prelude::*;
+use bigdecimal::BigDecimal;
+use nu_errors::ShellError;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue,
+};
+use num_traits::Zero;
+
+pub struct FromFraction;
+
+#[derive(Deserialize)]
+pub struct FromFractionArgs {
+    column: ColumnPath,
+}
+
+impl WholeStreamCommand for FromFraction {
+    fn name(&self) -> &str {
+        "from-fraction"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from-fraction").required(
+            "column",
+            SyntaxShape::ColumnPath,
+            "the column holding the fractions, eg 3/4",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Reads fractions like 3/4 into decimals."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, from_fraction)?.run()
+    }
+}
+
+fn from_fraction(
+    FromFractionArgs { column }: FromFractionArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    Ok(input
+        .values
+        .map(move |row| {
+            let cell = get_column_path(&column, &row)?;
+
+            let text = match &cell.value {
+                UntaggedValue::Primitive(Primitive::String(s)) => s.clone(),
+                UntaggedValue::Primitive(Primitive::Line(s)) => s.clone(),
+                _ => {
+                    return Err(ShellError::labeled_error_with_secondary(
+                        "Expected a string",
+                        format!("found {} instead", cell.type_name()),
+                        cell.tag(),
+                        "row originates from here",
+                        row.tag(),
+                    ))
+                }
+            };
+
+            let (numerator, denominator) = match parse_fraction(&text) {
+                Some(parts) => parts,
+                None => {
+                    return Err(ShellError::labeled_error_with_secondary(
+                        "Could not read as a fraction",
+                        format!("`{}` isn't a fraction like 3/4", text),
+                        cell.tag(),
+                        "row originates from here",
+                        row.tag(),
+                    ))
+                }
+            };
+
+            if denominator.is_zero() {
+                return Err(ShellError::labeled_error_with_secondary(
+                    "Division by zero",
+                    format!("`{}` has a denominator of 0", text),
+                    cell.tag(),
+                    "row originates from here",
+                    row.tag(),
+                ));
+            }
+
+            let decimal =
+                value::decimal(BigDecimal::from(numerator) / BigDecimal::from(denominator));
+
+            match row.replace_data_at_column_path(&column, decimal.into_value(cell.tag())) {
+                Some(row) => ReturnSuccess::value(row),
+                None => Err(ShellError::labeled_error(
+                    "from-fraction could not find the column",
+                    "column name",
+                    &name,
+                )),
+            }
+        })
+        .to_output_stream())
+}
+
+/// Reads `numerator/denominator`, where a lone integer is taken as over 1.
+fn parse_fraction(text: &str) -> Option<(BigInt, BigInt)> {
+    let mut parts = text.trim().splitn(2, '/');
+
+    let numerator = parts.next()?.trim().parse::<BigInt>().ok()?;
+    let denominator = match parts.next() {
+        Some(denominator) => denominator.trim().parse::<BigInt>().ok()?,
+        None => BigInt::from(1),
+    };
+
+    Some((numerator, denominator))
+}
@@ -68,7 +68,8 @@ fn from_ini(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStrea
     let args = args.evaluate_once(registry)?;
     let tag = args.name_tag();
     let span = tag.span;
-    let input = args.input;
+    let ctrl_c = args.ctrl_c.clone();
+    let input = args.input.interruptible(&ctrl_c);
 
     let stream = async_stream! {
         let values: Vec<Value> = input.values.collect().await;
@@ -113,5 +114,5 @@ fn from_ini(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStrea
         }
     };
 
-    Ok(stream.to_output_stream())
+    Ok(stream.to_output_stream().interruptible(&ctrl_c, span))
 }
@@ -1,14 +1,26 @@
+use crate::commands::get::get_column_path;
 use crate::commands::WholeStreamCommand;
 use crate::data::{value, TaggedDictBuilder};
 use crate::prelude::*;
 use nu_errors::ShellError;
-use nu_protocol::{Primitive, ReturnSuccess, Signature, UntaggedValue, Value};
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue,
+    Value,
+};
+use nu_source::Tagged;
 
 pub struct FromJSON;
 
+/// How deeply arrays and objects may nest before a document is rejected.
+pub const DEFAULT_MAX_DEPTH: usize = 128;
+
 #[derive(Deserialize)]
 pub struct FromJSONArgs {
     objects: bool,
+    #[serde(rename(deserialize = "max-depth"))]
+    max_depth: Option<Tagged<usize>>,
+    column: Option<ColumnPath>,
+    lenient: bool,
 }
 
 impl WholeStreamCommand for FromJSON {
@@ -17,7 +29,22 @@ impl WholeStreamCommand for FromJSON {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("from-json").switch("objects", "treat each line as a separate value")
+        Signature::build("from-json")
+            .switch("objects", "treat each line as a separate value")
+            .named(
+                "max-depth",
+                SyntaxShape::Int,
+                "how deeply arrays and objects may nest (defaults to 128)",
+            )
+            .named(
+                "column",
+                SyntaxShape::ColumnPath,
+                "parse the json strings in this column of each row instead of the input text",
+            )
+            .switch(
+                "lenient",
+                "with --column, leave the cells that aren't json as they are",
+            )
     }
 
     fn usage(&self) -> &str {
@@ -33,10 +60,17 @@ impl WholeStreamCommand for FromJSON {
     }
 }
 
-fn convert_json_value_to_nu_value(v: &serde_hjson::Value, tag: impl Into<Tag>) -> Value {
+pub fn convert_json_value_to_nu_value(
+    v: &serde_hjson::Value,
+    tag: impl Into<Tag>,
+    max_depth: usize,
+) -> Result<Value, ShellError> {
     let tag = tag.into();
 
-    match v {
+    Ok(match v {
+        serde_hjson::Value::Array(_) | serde_hjson::Value::Object(_) if max_depth == 0 => {
+            return Err(nested_too_deeply(&tag))
+        }
         serde_hjson::Value::Null => UntaggedValue::Primitive(Primitive::Nothing).into_value(&tag),
         serde_hjson::Value::Bool(b) => value::boolean(*b).into_value(&tag),
         serde_hjson::Value::F64(n) => value::number(n).into_value(&tag),
@@ -47,32 +81,95 @@ fn convert_json_value_to_nu_value(v: &serde_hjson::Value, tag: impl Into<Tag>) -
         }
         serde_hjson::Value::Array(a) => UntaggedValue::Table(
             a.iter()
-                .map(|x| convert_json_value_to_nu_value(x, &tag))
-                .collect(),
+                .map(|x| convert_json_value_to_nu_value(x, &tag, max_depth - 1))
+                .collect::<Result<Vec<_>, _>>()?,
         )
         .into_value(tag),
         serde_hjson::Value::Object(o) => {
             let mut collected = TaggedDictBuilder::new(&tag);
             for (k, v) in o.iter() {
-                collected.insert_value(k.clone(), convert_json_value_to_nu_value(v, &tag));
+                collected.insert_value(
+                    k.clone(),
+                    convert_json_value_to_nu_value(v, &tag, max_depth - 1)?,
+                );
             }
 
             collected.into_value()
         }
-    }
+    })
+}
+
+pub fn nested_too_deeply(tag: &Tag) -> ShellError {
+    ShellError::labeled_error(
+        "Document is nested too deeply",
+        "nesting goes past the maximum depth (see --max-depth)",
+        tag,
+    )
 }
 
-pub fn from_json_string_to_value(s: String, tag: impl Into<Tag>) -> serde_hjson::Result<Value> {
-    let v: serde_hjson::Value = serde_hjson::from_str(&s)?;
-    Ok(convert_json_value_to_nu_value(&v, tag))
+/// Counts the brackets outside of strings, so that a document nested past `max_depth` is
+/// rejected before the parser recurses into it.
+pub fn check_depth(text: &str, max_depth: usize, tag: &Tag) -> Result<(), ShellError> {
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for c in text.chars() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+
+            continue;
+        }
+
+        match c {
+            '"' => in_string = true,
+            '[' | '{' => {
+                depth += 1;
+
+                if depth > max_depth {
+                    return Err(nested_too_deeply(tag));
+                }
+            }
+            ']' | '}' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+    }
+
+    Ok(())
 }
 
 fn from_json(
-    FromJSONArgs { objects }: FromJSONArgs,
-    RunnableContext { input, name, .. }: RunnableContext,
+    FromJSONArgs {
+        objects,
+        max_depth,
+        column,
+        lenient,
+    }: FromJSONArgs,
+    RunnableContext {
+        input,
+        name,
+        ctrl_c,
+        ..
+    }: RunnableContext,
 ) -> Result<OutputStream, ShellError> {
     let name_span = name.span;
     let name_tag = name;
+    let max_depth = max_depth
+        .map(|depth| depth.item)
+        .unwrap_or(DEFAULT_MAX_DEPTH);
+
+    if let Some(column) = column {
+        return Ok(from_json_column(
+            input, column, lenient, max_depth, name_tag,
+        ));
+    }
+
+    let input = input.interruptible(&ctrl_c);
 
     let stream = async_stream! {
         let values: Vec<Value> = input.values.collect().await;
@@ -104,9 +201,16 @@ fn from_json(
                     continue;
                 }
 
-                match from_json_string_to_value(json_str.to_string(), &name_tag) {
-                    Ok(x) =>
-                        yield ReturnSuccess::value(x),
+                if let Err(err) = check_depth(json_str, max_depth, &name_tag) {
+                    yield Err(err);
+                    continue;
+                }
+
+                match serde_hjson::from_str::<serde_hjson::Value>(json_str) {
+                    Ok(v) => match convert_json_value_to_nu_value(&v, &name_tag, max_depth) {
+                        Ok(x) => yield ReturnSuccess::value(x),
+                        Err(err) => yield Err(err),
+                    },
                     Err(_) => {
                         if let Some(ref last_tag) = latest_tag {
                             yield Err(ShellError::labeled_error_with_secondary(
@@ -120,15 +224,21 @@ fn from_json(
                 }
             }
         } else {
-            match from_json_string_to_value(concat_string, name_tag.clone()) {
-                Ok(x) =>
-                    match x {
-                        Value { value: UntaggedValue::Table(list), .. } => {
+            if let Err(err) = check_depth(&concat_string, max_depth, &name_tag) {
+                yield Err(err);
+                return;
+            }
+
+            match serde_hjson::from_str::<serde_hjson::Value>(&concat_string) {
+                Ok(v) =>
+                    match convert_json_value_to_nu_value(&v, name_tag.clone(), max_depth) {
+                        Ok(Value { value: UntaggedValue::Table(list), .. }) => {
                             for l in list {
                                 yield ReturnSuccess::value(l);
                             }
                         }
-                        x => yield ReturnSuccess::value(x),
+                        Ok(x) => yield ReturnSuccess::value(x),
+                        Err(err) => yield Err(err),
                     }
                 Err(_) => {
                     if let Some(last_tag) = latest_tag {
@@ -144,5 +254,62 @@ fn from_json(
         }
     };
 
-    Ok(stream.to_output_stream())
+    Ok(stream.to_output_stream().interruptible(&ctrl_c, name_span))
+}
+
+fn from_json_column(
+    input: InputStream,
+    column: ColumnPath,
+    lenient: bool,
+    max_depth: usize,
+    name: Tag,
+) -> OutputStream {
+    input
+        .values
+        .map(move |row| {
+            let cell = get_column_path(&column, &row)?;
+
+            let parsed = match cell.as_string() {
+                Ok(s) => {
+                    check_depth(&s, max_depth, &cell.tag())?;
+
+                    // hjson reads any word as a string, so cells are held to plain json first.
+                    if serde_json::from_str::<serde::de::IgnoredAny>(&s).is_err() {
+                        None
+                    } else {
+                        match serde_hjson::from_str::<serde_hjson::Value>(&s) {
+                            Ok(v) => {
+                                Some(convert_json_value_to_nu_value(&v, cell.tag(), max_depth)?)
+                            }
+                            Err(_) => None,
+                        }
+                    }
+                }
+                Err(_) => None,
+            };
+
+            let parsed = match parsed {
+                Some(parsed) => parsed,
+                None if lenient => return ReturnSuccess::value(row),
+                None => {
+                    return Err(ShellError::labeled_error_with_secondary(
+                        "Could not parse as JSON",
+                        format!("this {} cannot be parsed as JSON", cell.type_name()),
+                        cell.tag(),
+                        "row originates from here",
+                        row.tag(),
+                    ))
+                }
+            };
+
+            match row.replace_data_at_column_path(&column, parsed) {
+                Some(row) => ReturnSuccess::value(row),
+                None => Err(ShellError::labeled_error(
+                    "from-json could not find the column",
+                    "column name",
+                    &name,
+                )),
+            }
+        })
+        .to_output_stream()
 }
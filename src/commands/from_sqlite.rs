@@ -136,7 +136,9 @@ pub fn from_sqlite_bytes_to_value(
 fn from_sqlite(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
     let args = args.evaluate_once(registry)?;
     let tag = args.name_tag();
-    let input = args.input;
+    let name_span = tag.span;
+    let ctrl_c = args.ctrl_c.clone();
+    let input = args.input.interruptible(&ctrl_c);
 
     let stream = async_stream! {
         let values: Vec<Value> = input.values.collect().await;
@@ -176,5 +178,5 @@ fn from_sqlite(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputSt
         }
     };
 
-    Ok(stream.to_output_stream())
+    Ok(stream.to_output_stream().interruptible(&ctrl_c, name_span))
 }
@@ -252,8 +252,16 @@ fn from_ssv(
         aligned_columns,
         minimum_spaces,
     }: FromSSVArgs,
-    RunnableContext { input, name, .. }: RunnableContext,
+    RunnableContext {
+        input,
+        name,
+        ctrl_c,
+        ..
+    }: RunnableContext,
 ) -> Result<OutputStream, ShellError> {
+    let name_span = name.span;
+    let input = input.interruptible(&ctrl_c);
+
     let stream = async_stream! {
         let values: Vec<Value> = input.values.collect().await;
         let mut concat_string = String::new();
@@ -283,7 +291,9 @@ fn from_ssv(
         match from_ssv_string_to_value(&concat_string, headerless, aligned_columns, split_at, name.clone()) {
             Some(x) => match x {
                 Value { value: UntaggedValue::Table(list), ..} => {
-                    for l in list { yield ReturnSuccess::value(l) }
+                    for l in list {
+                        yield ReturnSuccess::value(l)
+                    }
                 }
                 x => yield ReturnSuccess::value(x)
             },
@@ -299,7 +309,7 @@ fn from_ssv(
         }
     };
 
-    Ok(stream.to_output_stream())
+    Ok(stream.to_output_stream().interruptible(&ctrl_c, name_span))
 }
 
 #[cfg(test)]
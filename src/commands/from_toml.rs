@@ -1,6 +1,7 @@
 use crate::commands::WholeStreamCommand;
 use crate::data::{value, TaggedDictBuilder};
 use crate::prelude::*;
+use chrono::{DateTime, Utc};
 use nu_errors::ShellError;
 use nu_protocol::{Primitive, ReturnSuccess, Signature, UntaggedValue, Value};
 
@@ -44,9 +45,11 @@ pub fn convert_toml_value_to_nu_value(v: &toml::Value, tag: impl Into<Tag>) -> V
                 .collect(),
         )
         .into_value(tag),
-        toml::Value::Datetime(dt) => {
-            UntaggedValue::Primitive(Primitive::String(dt.to_string())).into_value(tag)
-        }
+        // Local dates and times have no offset to place them in, so they stay strings.
+        toml::Value::Datetime(dt) => match DateTime::parse_from_rfc3339(&dt.to_string()) {
+            Ok(date) => value::date(date.with_timezone(&Utc)).into_value(tag),
+            Err(_) => UntaggedValue::Primitive(Primitive::String(dt.to_string())).into_value(tag),
+        },
         toml::Value::Table(t) => {
             let mut collected = TaggedDictBuilder::new(&tag);
 
@@ -71,7 +74,8 @@ pub fn from_toml(
     let args = args.evaluate_once(registry)?;
     let tag = args.name_tag();
     let name_span = tag.span;
-    let input = args.input;
+    let ctrl_c = args.ctrl_c.clone();
+    let input = args.input.interruptible(&ctrl_c);
 
     let stream = async_stream! {
         let values: Vec<Value> = input.values.collect().await;
@@ -117,5 +121,5 @@ pub fn from_toml(
         }
     };
 
-    Ok(stream.to_output_stream())
+    Ok(stream.to_output_stream().interruptible(&ctrl_c, name_span))
 }
@@ -38,5 +38,5 @@ fn from_tsv(
     FromTSVArgs { headerless }: FromTSVArgs,
     runnable_context: RunnableContext,
 ) -> Result<OutputStream, ShellError> {
-    from_delimited_data(headerless, '\t', "TSV", runnable_context)
+    from_delimited_data(headerless, Some('\t'), "TSV", runnable_context)
 }
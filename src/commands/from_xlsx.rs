@@ -43,8 +43,10 @@ fn from_xlsx(
     }: FromXLSXArgs,
     runnable_context: RunnableContext,
 ) -> Result<OutputStream, ShellError> {
-    let input = runnable_context.input;
     let tag = runnable_context.name;
+    let name_span = tag.span;
+    let ctrl_c = runnable_context.ctrl_c;
+    let input = runnable_context.input.interruptible(&ctrl_c);
 
     let stream = async_stream! {
         let values: Vec<Value> = input.values.collect().await;
@@ -102,5 +104,5 @@ fn from_xlsx(
         }
     };
 
-    Ok(stream.to_output_stream())
+    Ok(stream.to_output_stream().interruptible(&ctrl_c, name_span))
 }
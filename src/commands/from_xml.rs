@@ -84,7 +84,8 @@ fn from_xml(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStrea
     let args = args.evaluate_once(registry)?;
     let tag = args.name_tag();
     let name_span = tag.span;
-    let input = args.input;
+    let ctrl_c = args.ctrl_c.clone();
+    let input = args.input.interruptible(&ctrl_c);
 
     let stream = async_stream! {
         let values: Vec<Value> = input.values.collect().await;
@@ -131,7 +132,7 @@ fn from_xml(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStrea
         }
     };
 
-    Ok(stream.to_output_stream())
+    Ok(stream.to_output_stream().interruptible(&ctrl_c, name_span))
 }
 
 #[cfg(test)]
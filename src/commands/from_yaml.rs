@@ -1,18 +1,30 @@
+use crate::commands::from_json::{nested_too_deeply, DEFAULT_MAX_DEPTH};
 use crate::commands::WholeStreamCommand;
 use crate::data::{value, TaggedDictBuilder};
 use crate::prelude::*;
 use nu_errors::ShellError;
-use nu_protocol::{Primitive, ReturnSuccess, Signature, UntaggedValue, Value};
+use nu_protocol::{Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
 
 pub struct FromYAML;
 
+#[derive(Deserialize)]
+pub struct FromYAMLArgs {
+    #[serde(rename(deserialize = "max-depth"))]
+    max_depth: Option<Tagged<usize>>,
+}
+
 impl WholeStreamCommand for FromYAML {
     fn name(&self) -> &str {
         "from-yaml"
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("from-yaml")
+        Signature::build("from-yaml").named(
+            "max-depth",
+            SyntaxShape::Int,
+            "how deeply sequences and mappings may nest (defaults to 128)",
+        )
     }
 
     fn usage(&self) -> &str {
@@ -24,7 +36,7 @@ impl WholeStreamCommand for FromYAML {
         args: CommandArgs,
         registry: &CommandRegistry,
     ) -> Result<OutputStream, ShellError> {
-        from_yaml(args, registry)
+        args.process(registry, from_yaml)?.run()
     }
 }
 
@@ -36,7 +48,11 @@ impl WholeStreamCommand for FromYML {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("from-yml")
+        Signature::build("from-yml").named(
+            "max-depth",
+            SyntaxShape::Int,
+            "how deeply sequences and mappings may nest (defaults to 128)",
+        )
     }
 
     fn usage(&self) -> &str {
@@ -48,14 +64,21 @@ impl WholeStreamCommand for FromYML {
         args: CommandArgs,
         registry: &CommandRegistry,
     ) -> Result<OutputStream, ShellError> {
-        from_yaml(args, registry)
+        args.process(registry, from_yaml)?.run()
     }
 }
 
-fn convert_yaml_value_to_nu_value(v: &serde_yaml::Value, tag: impl Into<Tag>) -> Value {
+fn convert_yaml_value_to_nu_value(
+    v: &serde_yaml::Value,
+    tag: impl Into<Tag>,
+    max_depth: usize,
+) -> Result<Value, ShellError> {
     let tag = tag.into();
 
-    match v {
+    Ok(match v {
+        serde_yaml::Value::Sequence(_) | serde_yaml::Value::Mapping(_) if max_depth == 0 => {
+            return Err(nested_too_deeply(&tag))
+        }
         serde_yaml::Value::Bool(b) => value::boolean(*b).into_value(tag),
         serde_yaml::Value::Number(n) if n.is_i64() => {
             value::number(n.as_i64().unwrap()).into_value(tag)
@@ -66,8 +89,8 @@ fn convert_yaml_value_to_nu_value(v: &serde_yaml::Value, tag: impl Into<Tag>) ->
         serde_yaml::Value::String(s) => value::string(s).into_value(tag),
         serde_yaml::Value::Sequence(a) => UntaggedValue::Table(
             a.iter()
-                .map(|x| convert_yaml_value_to_nu_value(x, &tag))
-                .collect(),
+                .map(|x| convert_yaml_value_to_nu_value(x, &tag, max_depth - 1))
+                .collect::<Result<Vec<_>, _>>()?,
         )
         .into_value(tag),
         serde_yaml::Value::Mapping(t) => {
@@ -76,7 +99,10 @@ fn convert_yaml_value_to_nu_value(v: &serde_yaml::Value, tag: impl Into<Tag>) ->
             for (k, v) in t.iter() {
                 match k {
                     serde_yaml::Value::String(k) => {
-                        collected.insert_value(k.clone(), convert_yaml_value_to_nu_value(v, &tag));
+                        collected.insert_value(
+                            k.clone(),
+                            convert_yaml_value_to_nu_value(v, &tag, max_depth - 1)?,
+                        );
                     }
                     _ => unimplemented!("Unknown key type"),
                 }
@@ -86,19 +112,24 @@ fn convert_yaml_value_to_nu_value(v: &serde_yaml::Value, tag: impl Into<Tag>) ->
         }
         serde_yaml::Value::Null => UntaggedValue::Primitive(Primitive::Nothing).into_value(tag),
         x => unimplemented!("Unsupported yaml case: {:?}", x),
-    }
-}
-
-pub fn from_yaml_string_to_value(s: String, tag: impl Into<Tag>) -> serde_yaml::Result<Value> {
-    let v: serde_yaml::Value = serde_yaml::from_str(&s)?;
-    Ok(convert_yaml_value_to_nu_value(&v, tag))
+    })
 }
 
-fn from_yaml(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
-    let args = args.evaluate_once(registry)?;
-    let tag = args.name_tag();
+fn from_yaml(
+    FromYAMLArgs { max_depth }: FromYAMLArgs,
+    RunnableContext {
+        input,
+        name,
+        ctrl_c,
+        ..
+    }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let tag = name;
     let name_span = tag.span;
-    let input = args.input;
+    let max_depth = max_depth
+        .map(|depth| depth.item)
+        .unwrap_or(DEFAULT_MAX_DEPTH);
+    let input = input.interruptible(&ctrl_c);
 
     let stream = async_stream! {
         let values: Vec<Value> = input.values.collect().await;
@@ -124,14 +155,15 @@ fn from_yaml(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStre
             }
         }
 
-        match from_yaml_string_to_value(concat_string, tag.clone()) {
-            Ok(x) => match x {
-                Value { value: UntaggedValue::Table(list), .. } => {
+        match serde_yaml::from_str::<serde_yaml::Value>(&concat_string) {
+            Ok(v) => match convert_yaml_value_to_nu_value(&v, tag.clone(), max_depth) {
+                Ok(Value { value: UntaggedValue::Table(list), .. }) => {
                     for l in list {
                         yield ReturnSuccess::value(l);
                     }
                 }
-                x => yield ReturnSuccess::value(x),
+                Ok(x) => yield ReturnSuccess::value(x),
+                Err(err) => yield Err(err),
             },
             Err(_) => if let Some(last_tag) = latest_tag {
                 yield Err(ShellError::labeled_error_with_secondary(
@@ -145,5 +177,57 @@ fn from_yaml(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStre
         }
     };
 
-    Ok(stream.to_output_stream())
+    Ok(stream.to_output_stream().interruptible(&ctrl_c, name_span))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{from_yaml, FromYAMLArgs};
+    use crate::env::host::BasicHost;
+    use crate::prelude::*;
+    use futures::executor::block_on;
+    use futures::stream;
+    use nu_protocol::ReturnValue;
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+    #[test]
+    fn stops_once_interrupted_while_reading_the_input() {
+        let ctrl_c = Arc::new(AtomicBool::new(false));
+        let pulled = Arc::new(AtomicUsize::new(0));
+
+        let flag = ctrl_c.clone();
+        let counter = pulled.clone();
+        let lines = stream::iter(0..1_000_000).map(move |n| {
+            if counter.fetch_add(1, Ordering::SeqCst) == 2 {
+                flag.store(true, Ordering::SeqCst);
+            }
+
+            value::string(format!("- {}\n", n)).into_untagged_value()
+        });
+
+        let commands = CommandRegistry::new();
+        let context = RunnableContext {
+            input: InputStream::from_stream(lines),
+            shell_manager: ShellManager::basic(commands.clone()).unwrap(),
+            host: Arc::new(Mutex::new(Box::new(BasicHost))),
+            source: Text::from(""),
+            ctrl_c,
+            commands,
+            name: Tag::unknown(),
+        };
+
+        let output: Vec<ReturnValue> = block_on(
+            from_yaml(FromYAMLArgs { max_depth: None }, context)
+                .unwrap()
+                .collect(),
+        );
+
+        assert_eq!(pulled.load(Ordering::SeqCst), 3);
+        assert_eq!(output.len(), 1);
+
+        match &output[0] {
+            Err(err) => assert_eq!(err.to_diagnostic().message, "Interrupted"),
+            Ok(value) => panic!("expected an interrupted error, got {:?}", value),
+        }
+    }
 }
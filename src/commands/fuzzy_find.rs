@@ -0,0 +1,119 @@
+use crate::commands::str_distance::{normalized_distance, string_at};
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::Tagged;
+use num_traits::ToPrimitive;
+
+pub struct FuzzyFind;
+
+#[derive(Deserialize)]
+pub struct FuzzyFindArgs {
+    column: Tagged<String>,
+    query: Tagged<String>,
+    threshold: Option<Value>,
+}
+
+impl WholeStreamCommand for FuzzyFind {
+    fn name(&self) -> &str {
+        "fuzzy-find"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("fuzzy-find")
+            .required(
+                "column",
+                SyntaxShape::String,
+                "the column holding the strings to match",
+            )
+            .required("query", SyntaxShape::String, "the text to look for")
+            .named(
+                "threshold",
+                SyntaxShape::Number,
+                "keep only the rows scoring at least this, from 0 (anything) to 1 (the same text)",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Scores rows by how close a column is to the query and returns them closest first."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, fuzzy_find)?.run()
+    }
+}
+
+fn fuzzy_find(
+    FuzzyFindArgs {
+        column,
+        query,
+        threshold,
+    }: FuzzyFindArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let threshold = match &threshold {
+        None => 0.0,
+        Some(threshold) => match &threshold.value {
+            UntaggedValue::Primitive(Primitive::Int(i)) => i.to_f64().unwrap_or(0.0),
+            UntaggedValue::Primitive(Primitive::Decimal(d)) => d.to_f64().unwrap_or(0.0),
+            _ => {
+                return Err(ShellError::labeled_error(
+                    "Expected a number",
+                    format!("found {} instead", threshold.type_name()),
+                    threshold.tag(),
+                ))
+            }
+        },
+    };
+
+    let query = query.item.to_lowercase();
+
+    let stream = async_stream! {
+        let rows: Vec<Value> = input.values.collect().await;
+
+        if query.is_empty() {
+            for row in rows {
+                yield ReturnSuccess::value(row);
+            }
+            return;
+        }
+
+        let mut scored = vec![];
+
+        for row in rows {
+            match string_at(&row, &column) {
+                Ok(text) => {
+                    let score = 1.0 - normalized_distance(&text.to_lowercase(), &query);
+
+                    if score >= threshold {
+                        scored.push((score, row));
+                    }
+                }
+                Err(err) => {
+                    yield Err(err);
+                    return;
+                }
+            }
+        }
+
+        // The sort is stable, so rows scoring the same keep their order.
+        scored.sort_by(|(left, _), (right, _)| {
+            right
+                .partial_cmp(left)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        for (_, row) in scored {
+            yield ReturnSuccess::value(row);
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
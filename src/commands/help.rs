@@ -66,28 +66,7 @@ impl PerItemCommand for Help {
 
                         let signature = command.signature();
 
-                        let mut one_liner = String::new();
-                        one_liner.push_str(&signature.name);
-                        one_liner.push_str(" ");
-
-                        for positional in &signature.positional {
-                            match &positional.0 {
-                                PositionalType::Mandatory(name, _m) => {
-                                    one_liner.push_str(&format!("<{}> ", name));
-                                }
-                                PositionalType::Optional(name, _o) => {
-                                    one_liner.push_str(&format!("({}) ", name));
-                                }
-                            }
-                        }
-
-                        if signature.rest_positional.is_some() {
-                            one_liner.push_str(&format!(" ...args",));
-                        }
-
-                        if signature.named.len() > 0 {
-                            one_liner.push_str("{flags} ");
-                        }
+                        let one_liner = signature.one_liner();
 
                         long_desc.push_str(&format!("\nUsage:\n  > {}\n", one_liner));
 
@@ -0,0 +1,157 @@
+use crate::commands::get::get_column_path;
+use crate::commands::WholeStreamCommand;
+use crate::data::value;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue,
+    Value,
+};
+use nu_source::Tagged;
+
+const TRUE_SPELLINGS: &[&str] = &["true", "t", "yes", "y", "on", "1"];
+const FALSE_SPELLINGS: &[&str] = &["false", "f", "no", "n", "off", "0"];
+
+pub struct IntoBool;
+
+#[derive(Deserialize)]
+pub struct IntoBoolArgs {
+    column: ColumnPath,
+    #[serde(rename(deserialize = "true"))]
+    truthy: Option<Vec<Tagged<String>>>,
+    #[serde(rename(deserialize = "false"))]
+    falsy: Option<Vec<Tagged<String>>>,
+    #[serde(rename(deserialize = "on-error"))]
+    on_error: Option<Tagged<String>>,
+}
+
+impl WholeStreamCommand for IntoBool {
+    fn name(&self) -> &str {
+        "into-bool"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("into-bool")
+            .required(
+                "column",
+                SyntaxShape::ColumnPath,
+                "the column holding the spelled out booleans",
+            )
+            .named(
+                "true",
+                SyntaxShape::Any,
+                "the spellings of true, eg [yes on] (defaults to true, t, yes, y, on and 1)",
+            )
+            .named(
+                "false",
+                SyntaxShape::Any,
+                "the spellings of false, eg [no off] (defaults to false, f, no, n, off and 0)",
+            )
+            .named(
+                "on-error",
+                SyntaxShape::String,
+                "what to do with other values: error (the default) or null",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Turns spellings like yes/no or 1/0 in the given column into booleans, ignoring case."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, into_bool)?.run()
+    }
+}
+
+fn into_bool(
+    IntoBoolArgs {
+        column,
+        truthy,
+        falsy,
+        on_error,
+    }: IntoBoolArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let null_on_error = match &on_error {
+        None => false,
+        Some(on_error) => match on_error.item.as_str() {
+            "error" => false,
+            "null" => true,
+            _ => {
+                return Err(ShellError::labeled_error(
+                    "Unknown error handling",
+                    "expected error or null",
+                    on_error.tag(),
+                ))
+            }
+        },
+    };
+
+    let truthy = spellings(truthy, TRUE_SPELLINGS);
+    let falsy = spellings(falsy, FALSE_SPELLINGS);
+
+    Ok(input
+        .values
+        .map(move |row| {
+            let cell = get_column_path(&column, &row)?;
+
+            let converted = match parse_bool(&cell, &truthy, &falsy) {
+                Some(b) => value::boolean(b),
+                None if null_on_error => value::nothing(),
+                None => {
+                    return Err(ShellError::labeled_error_with_secondary(
+                        "Expected a boolean",
+                        format!("could not read this {} as true or false", cell.type_name()),
+                        cell.tag(),
+                        "row originates from here",
+                        row.tag(),
+                    ))
+                }
+            };
+
+            match row.replace_data_at_column_path(&column, converted.into_value(cell.tag())) {
+                Some(row) => ReturnSuccess::value(row),
+                None => Err(ShellError::labeled_error(
+                    "into-bool could not find the column",
+                    "column name",
+                    &name,
+                )),
+            }
+        })
+        .to_output_stream())
+}
+
+fn spellings(given: Option<Vec<Tagged<String>>>, defaults: &[&str]) -> Vec<String> {
+    match given {
+        Some(given) => given
+            .into_iter()
+            .map(|spelling| spelling.item.to_lowercase())
+            .collect(),
+        None => defaults
+            .iter()
+            .map(|spelling| spelling.to_string())
+            .collect(),
+    }
+}
+
+fn parse_bool(cell: &Value, truthy: &[String], falsy: &[String]) -> Option<bool> {
+    let text = match &cell.value {
+        UntaggedValue::Primitive(Primitive::Boolean(b)) => return Some(*b),
+        UntaggedValue::Primitive(Primitive::String(s)) => s.trim().to_lowercase(),
+        UntaggedValue::Primitive(Primitive::Line(s)) => s.trim().to_lowercase(),
+        UntaggedValue::Primitive(Primitive::Int(i)) => i.to_string(),
+        _ => return None,
+    };
+
+    if truthy.contains(&text) {
+        Some(true)
+    } else if falsy.contains(&text) {
+        Some(false)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,148 @@
+use crate::commands::get::get_column_path;
+use crate::commands::WholeStreamCommand;
+use crate::data::value;
+use crate::prelude::*;
+use chrono::{TimeZone, Utc};
+use nu_errors::ShellError;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue,
+    Value,
+};
+use nu_source::Tagged;
+use num_traits::ToPrimitive;
+
+pub struct IntoDate;
+
+#[derive(Deserialize)]
+pub struct IntoDateArgs {
+    column: ColumnPath,
+    unit: Option<Tagged<String>>,
+    #[serde(rename(deserialize = "from-string"))]
+    from_string: bool,
+}
+
+impl WholeStreamCommand for IntoDate {
+    fn name(&self) -> &str {
+        "into-date"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("into-date")
+            .required(
+                "column",
+                SyntaxShape::ColumnPath,
+                "the column holding the unix epochs",
+            )
+            .named(
+                "unit",
+                SyntaxShape::String,
+                "what the epochs count: sec (the default), ms, us or ns",
+            )
+            .switch(
+                "from-string",
+                "also read epochs written as text, eg \"1577836800\"",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Turns unix epochs in the given column into dates."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, into_date)?.run()
+    }
+}
+
+fn into_date(
+    IntoDateArgs {
+        column,
+        unit,
+        from_string,
+    }: IntoDateArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    // How many of the unit make up a second
+    let per_second: i64 = match &unit {
+        None => 1,
+        Some(unit) => match unit.item.as_str() {
+            "sec" => 1,
+            "ms" => 1_000,
+            "us" => 1_000_000,
+            "ns" => 1_000_000_000,
+            _ => {
+                return Err(ShellError::labeled_error(
+                    "Unknown unit",
+                    "expected sec, ms, us or ns",
+                    unit.tag(),
+                ))
+            }
+        },
+    };
+
+    Ok(input
+        .values
+        .map(move |row| {
+            let cell = get_column_path(&column, &row)?;
+
+            let epoch = match epoch_at(&cell, from_string) {
+                Some(epoch) => epoch,
+                None => {
+                    return Err(ShellError::labeled_error_with_secondary(
+                        "Expected an integer",
+                        format!("could not read this {} as an epoch", cell.type_name()),
+                        cell.tag(),
+                        "row originates from here",
+                        row.tag(),
+                    ))
+                }
+            };
+
+            let date = epoch.and_then(|epoch| {
+                let seconds = epoch.div_euclid(per_second);
+                let nanoseconds = epoch.rem_euclid(per_second) * (1_000_000_000 / per_second);
+
+                Utc.timestamp_opt(seconds, nanoseconds as u32).single()
+            });
+
+            let date = match date {
+                Some(date) => value::date(date),
+                None => {
+                    return Err(ShellError::labeled_error_with_secondary(
+                        "Epoch out of range",
+                        "this epoch is too far from 1970 to be a date",
+                        cell.tag(),
+                        "row originates from here",
+                        row.tag(),
+                    ))
+                }
+            };
+
+            match row.replace_data_at_column_path(&column, date.into_value(cell.tag())) {
+                Some(row) => ReturnSuccess::value(row),
+                None => Err(ShellError::labeled_error(
+                    "into-date could not find the column",
+                    "column name",
+                    &name,
+                )),
+            }
+        })
+        .to_output_stream())
+}
+
+/// The epoch in the cell, which is `Some(None)` when it is an integer too large for 64 bits.
+fn epoch_at(cell: &Value, from_string: bool) -> Option<Option<i64>> {
+    match &cell.value {
+        UntaggedValue::Primitive(Primitive::Int(i)) => Some(i.to_i64()),
+        UntaggedValue::Primitive(Primitive::String(s)) if from_string => {
+            s.trim().parse::<BigInt>().ok().map(|i| i.to_i64())
+        }
+        UntaggedValue::Primitive(Primitive::Line(s)) if from_string => {
+            s.trim().parse::<BigInt>().ok().map(|i| i.to_i64())
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,74 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::{value, TaggedDictBuilder};
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, ShellTypeName, Signature, UntaggedValue, Value};
+
+pub struct Items;
+
+#[derive(Deserialize)]
+pub struct ItemsArgs {}
+
+impl WholeStreamCommand for Items {
+    fn name(&self) -> &str {
+        "items"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("items")
+    }
+
+    fn usage(&self) -> &str {
+        "Turns a single row into a table of its keys and values."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, items)?.run()
+    }
+}
+
+fn items(
+    ItemsArgs {}: ItemsArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let stream = async_stream! {
+        let values: Vec<Value> = input.values.collect().await;
+
+        match values.as_slice() {
+            [Value {
+                value: UntaggedValue::Row(dict),
+                ..
+            }] => {
+                for (key, cell) in dict.entries.iter() {
+                    let mut item = TaggedDictBuilder::new(&name);
+                    item.insert_untagged("key", value::string(key));
+                    item.insert_value("value", cell.clone());
+                    yield ReturnSuccess::value(item.into_value());
+                }
+            }
+            [other] => {
+                yield Err(ShellError::labeled_error_with_secondary(
+                    "Expected a single row",
+                    format!("found {} instead", other.type_name()),
+                    &name,
+                    "value originates from here",
+                    other.tag(),
+                ));
+            }
+            [] => {}
+            _ => {
+                yield Err(ShellError::labeled_error(
+                    "Expected a single row",
+                    format!("found a table of {} rows instead", values.len()),
+                    &name,
+                ));
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
@@ -0,0 +1,168 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::value;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+
+pub struct JsonPointer;
+
+#[derive(Deserialize)]
+pub struct JsonPointerArgs {
+    pointer: Tagged<String>,
+    strict: bool,
+}
+
+impl WholeStreamCommand for JsonPointer {
+    fn name(&self) -> &str {
+        "json-pointer"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("json-pointer")
+            .required(
+                "pointer",
+                SyntaxShape::String,
+                "the RFC 6901 pointer to the value, eg /address/0/city",
+            )
+            .switch(
+                "strict",
+                "error when the pointer doesn't resolve instead of giving nothing",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Gets the value a JSON pointer points to in each row."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, json_pointer)?.run()
+    }
+}
+
+fn json_pointer(
+    JsonPointerArgs { pointer, strict }: JsonPointerArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let tokens = parse_pointer(&pointer)?;
+
+    Ok(input
+        .values
+        .map(move |row| match resolve(&row, &tokens) {
+            Some(found) => ReturnSuccess::value(found.clone()),
+            None if strict => Err(ShellError::labeled_error_with_secondary(
+                "Pointer does not resolve",
+                "no value at this pointer",
+                pointer.tag(),
+                "row originates from here",
+                row.tag(),
+            )),
+            None => ReturnSuccess::value(value::nothing().into_value(row.tag())),
+        })
+        .to_output_stream())
+}
+
+/// Splits a pointer into its reference tokens, decoding `~1` to `/` and `~0` to `~`.
+///
+/// The empty pointer points to the whole value.
+fn parse_pointer(pointer: &Tagged<String>) -> Result<Vec<String>, ShellError> {
+    if pointer.item.is_empty() {
+        return Ok(vec![]);
+    }
+
+    if !pointer.item.starts_with('/') {
+        return Err(ShellError::labeled_error(
+            "Invalid JSON pointer",
+            "a pointer starts with /",
+            pointer.tag(),
+        ));
+    }
+
+    pointer.item[1..]
+        .split('/')
+        .map(|token| {
+            if !has_valid_escapes(token) {
+                return Err(ShellError::labeled_error(
+                    "Invalid JSON pointer",
+                    format!("{} has a ~ that is not ~0 or ~1", token),
+                    pointer.tag(),
+                ));
+            }
+
+            // ~1 is decoded first, so ~01 stays the literal ~1.
+            Ok(token.replace("~1", "/").replace("~0", "~"))
+        })
+        .collect()
+}
+
+/// ~ may only start one of the two escapes.
+fn has_valid_escapes(token: &str) -> bool {
+    let mut chars = token.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '~' {
+            match chars.next() {
+                Some('0') | Some('1') => {}
+                _ => return false,
+            }
+        }
+    }
+
+    true
+}
+
+fn resolve<'a>(value: &'a Value, tokens: &[String]) -> Option<&'a Value> {
+    let (token, rest) = match tokens.split_first() {
+        Some(split) => split,
+        None => return Some(value),
+    };
+
+    let next = match &value.value {
+        UntaggedValue::Row(dict) => dict.entries.get(token)?,
+        UntaggedValue::Table(rows) => rows.get(array_index(token)?)?,
+        _ => return None,
+    };
+
+    resolve(next, rest)
+}
+
+/// Array indices are plain decimal numbers without leading zeros.
+fn array_index(token: &str) -> Option<usize> {
+    if token.is_empty()
+        || (token.len() > 1 && token.starts_with('0'))
+        || !token.chars().all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+
+    token.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_pointer;
+    use nu_source::TaggedItem;
+
+    fn tokens(pointer: &str) -> Vec<String> {
+        parse_pointer(&pointer.to_string().tagged_unknown()).unwrap()
+    }
+
+    #[test]
+    fn decodes_escaped_tokens() {
+        assert_eq!(tokens("/a~1b/c~0d"), vec!["a/b", "c~d"]);
+        assert_eq!(tokens("/~01"), vec!["~1"]);
+        assert_eq!(tokens(""), Vec::<String>::new());
+    }
+
+    #[test]
+    fn rejects_pointers_without_a_leading_slash_or_with_bad_escapes() {
+        assert!(parse_pointer(&"a/b".to_string().tagged_unknown()).is_err());
+        assert!(parse_pointer(&"/a~2".to_string().tagged_unknown()).is_err());
+        assert!(parse_pointer(&"/~~01".to_string().tagged_unknown()).is_err());
+        assert!(parse_pointer(&"/a~".to_string().tagged_unknown()).is_err());
+    }
+}
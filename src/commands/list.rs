@@ -0,0 +1,42 @@
+use crate::commands::ls::LsArgs;
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, SyntaxShape};
+
+pub struct List;
+
+impl WholeStreamCommand for List {
+    fn name(&self) -> &str {
+        "list"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("list")
+            .optional(
+                "path",
+                SyntaxShape::Pattern,
+                "a path to get the directory contents from",
+            )
+            .switch("full", "list all available columns for each entry")
+            .switch("all", "include hidden files (names starting with a dot)")
+            .switch("long", "add permission and owner columns (same as --full)")
+            .switch("resolve", "report the type of what symlinks point to")
+    }
+
+    fn usage(&self) -> &str {
+        "View the contents of the current or given path, leaving out hidden files."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, list)?.run()
+    }
+}
+
+fn list(args: LsArgs, context: RunnableContext) -> Result<OutputStream, ShellError> {
+    context.shell_manager.ls(args, &context)
+}
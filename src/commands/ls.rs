@@ -9,8 +9,11 @@ pub struct LS;
 
 #[derive(Deserialize)]
 pub struct LsArgs {
-    path: Option<Tagged<PathBuf>>,
-    full: bool,
+    pub path: Option<Tagged<PathBuf>>,
+    pub full: bool,
+    pub all: bool,
+    pub long: bool,
+    pub resolve: bool,
 }
 
 impl WholeStreamCommand for LS {
@@ -26,6 +29,8 @@ impl WholeStreamCommand for LS {
                 "a path to get the directory contents from",
             )
             .switch("full", "list all available columns for each entry")
+            .switch("long", "add permission and owner columns (same as --full)")
+            .switch("resolve", "report the type of what symlinks point to")
     }
 
     fn usage(&self) -> &str {
@@ -42,6 +47,9 @@ impl WholeStreamCommand for LS {
     }
 }
 
-fn ls(LsArgs { path, full }: LsArgs, context: RunnableContext) -> Result<OutputStream, ShellError> {
-    context.shell_manager.ls(path, &context, full)
+fn ls(args: LsArgs, context: RunnableContext) -> Result<OutputStream, ShellError> {
+    // ls has always listed dotfiles, it's `list` that hides them unless asked to
+    context
+        .shell_manager
+        .ls(LsArgs { all: true, ..args }, &context)
 }
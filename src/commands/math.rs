@@ -0,0 +1,175 @@
+use crate::commands::get::get_column_path;
+use crate::commands::WholeStreamCommand;
+use crate::data::value;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue,
+    Value,
+};
+use nu_source::Tagged;
+
+pub struct Math;
+
+#[derive(Deserialize)]
+pub struct MathArgs {
+    function: Tagged<String>,
+    column: ColumnPath,
+    exponent: Option<Value>,
+    #[serde(rename(deserialize = "allow-nan"))]
+    allow_nan: bool,
+}
+
+impl WholeStreamCommand for Math {
+    fn name(&self) -> &str {
+        "math"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math")
+            .required(
+                "function",
+                SyntaxShape::String,
+                "the function to apply: sqrt, log (base 10), ln, exp or pow",
+            )
+            .required(
+                "column",
+                SyntaxShape::ColumnPath,
+                "the column holding the numbers",
+            )
+            .optional("exponent", SyntaxShape::Number, "the exponent for pow")
+            .switch(
+                "allow-nan",
+                "give nothing instead of an error for numbers outside the function's domain",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Applies a math function to the numbers in a column, giving decimals."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, math)?.run()
+    }
+}
+
+enum Function {
+    Sqrt,
+    Log,
+    Ln,
+    Exp,
+    Pow(f64),
+}
+
+impl Function {
+    fn name(&self) -> &'static str {
+        match self {
+            Function::Sqrt => "sqrt",
+            Function::Log => "log",
+            Function::Ln => "ln",
+            Function::Exp => "exp",
+            Function::Pow(_) => "pow",
+        }
+    }
+
+    fn apply(&self, x: f64) -> f64 {
+        match self {
+            Function::Sqrt => x.sqrt(),
+            Function::Log => x.log10(),
+            Function::Ln => x.ln(),
+            Function::Exp => x.exp(),
+            Function::Pow(exponent) => x.powf(*exponent),
+        }
+    }
+}
+
+fn math(
+    MathArgs {
+        function,
+        column,
+        exponent,
+        allow_nan,
+    }: MathArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let exponent = match &exponent {
+        Some(exponent) => Some(value::as_f64(exponent).ok_or_else(|| {
+            ShellError::labeled_error(
+                "Expected a number",
+                format!("found {} instead", exponent.type_name()),
+                exponent.tag(),
+            )
+        })?),
+        None => None,
+    };
+
+    let function_tag = function.tag();
+
+    let function = match (function.item.as_str(), exponent) {
+        ("pow", Some(exponent)) => Function::Pow(exponent),
+        ("pow", None) => {
+            return Err(ShellError::labeled_error(
+                "Expected an exponent",
+                "pow needs the exponent after the column",
+                function_tag,
+            ))
+        }
+        (_, Some(_)) => {
+            return Err(ShellError::labeled_error(
+                "Unexpected exponent",
+                "only pow takes an exponent",
+                function_tag,
+            ))
+        }
+        ("sqrt", None) => Function::Sqrt,
+        ("log", None) => Function::Log,
+        ("ln", None) => Function::Ln,
+        ("exp", None) => Function::Exp,
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Unknown function",
+                "expected sqrt, log, ln, exp or pow",
+                function_tag,
+            ))
+        }
+    };
+
+    Ok(input
+        .values
+        .map(move |row| {
+            let cell = get_column_path(&column, &row)?;
+
+            let x = value::number_in(&cell, &row)?;
+
+            // Decimals can't hold NaN or infinity, so results outside them are domain errors.
+            let result = function.apply(x);
+
+            let result = if result.is_finite() {
+                UntaggedValue::Primitive(Primitive::from(result))
+            } else if allow_nan {
+                value::nothing()
+            } else {
+                return Err(ShellError::labeled_error_with_secondary(
+                    "Outside the function's domain",
+                    format!("{} has no result for {}", function.name(), x),
+                    cell.tag(),
+                    "row originates from here",
+                    row.tag(),
+                ));
+            };
+
+            match row.replace_data_at_column_path(&column, result.into_value(cell.tag())) {
+                Some(row) => ReturnSuccess::value(row),
+                None => Err(ShellError::labeled_error(
+                    "math could not find the column",
+                    "column name",
+                    &name,
+                )),
+            }
+        })
+        .to_output_stream())
+}
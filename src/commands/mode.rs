@@ -0,0 +1,93 @@
+use crate::commands::get::get_column_path;
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use std::collections::BTreeMap;
+
+#[derive(Deserialize)]
+struct ModeArgs {
+    column: ColumnPath,
+    all: bool,
+}
+
+pub struct Mode;
+
+impl WholeStreamCommand for Mode {
+    fn name(&self) -> &str {
+        "mode"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("mode")
+            .required(
+                "column",
+                SyntaxShape::ColumnPath,
+                "the column whose most common value is found",
+            )
+            .switch(
+                "all",
+                "return every value tied for the most common instead of the smallest",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Finds the value occurring most often in a column."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, mode)?.run()
+    }
+}
+
+fn mode(
+    ModeArgs { column, all }: ModeArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let stream = async_stream! {
+        let rows: Vec<Value> = input.values.collect().await;
+
+        if rows.is_empty() {
+            yield Err(ShellError::labeled_error(
+                "No values to find the mode of",
+                "the input is empty",
+                &name,
+            ));
+            return;
+        }
+
+        let mut counts: BTreeMap<UntaggedValue, usize> = BTreeMap::new();
+
+        for row in &rows {
+            match get_column_path(&column, row) {
+                Ok(cell) => *counts.entry(cell.value).or_insert(0) += 1,
+                Err(err) => {
+                    yield Err(err);
+                    return;
+                }
+            }
+        }
+
+        let most = counts.values().cloned().max().unwrap_or(0);
+
+        // The map is ordered by value, so without --all the smallest of the tied values wins.
+        let modes = counts
+            .into_iter()
+            .filter(|(_, count)| *count == most)
+            .map(|(cell, _)| cell);
+
+        for cell in modes {
+            yield ReturnSuccess::value(cell.into_value(&name));
+
+            if !all {
+                break;
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
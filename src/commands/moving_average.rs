@@ -0,0 +1,114 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::value;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue};
+use nu_source::Tagged;
+use std::collections::VecDeque;
+
+pub struct MovingAverage;
+
+#[derive(Deserialize)]
+pub struct MovingAverageArgs {
+    column: Tagged<String>,
+    window: Tagged<usize>,
+    into: Option<Tagged<String>>,
+    partial: bool,
+}
+
+impl WholeStreamCommand for MovingAverage {
+    fn name(&self) -> &str {
+        "moving-average"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("moving-average")
+            .required(
+                "column",
+                SyntaxShape::String,
+                "the column holding the numbers to average",
+            )
+            .required_named(
+                "window",
+                SyntaxShape::Int,
+                "how many of the latest rows to average",
+            )
+            .named(
+                "into",
+                SyntaxShape::String,
+                "the column to store the average in (defaults to <column>_average)",
+            )
+            .switch(
+                "partial",
+                "average the rows seen so far until the window fills, instead of leaving nothing",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Adds a column with the average of the given column over a window of the latest rows."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, moving_average)?.run()
+    }
+}
+
+fn moving_average(
+    MovingAverageArgs {
+        column,
+        window,
+        into,
+        partial,
+    }: MovingAverageArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    if window.item == 0 {
+        return Err(ShellError::labeled_error(
+            "Expected a window of at least one row",
+            "window must be more than 0",
+            window.tag(),
+        ));
+    }
+
+    let into = match into {
+        Some(into) => into.item,
+        None => format!("{}_average", column.item),
+    };
+
+    let size = window.item;
+    let mut latest: VecDeque<f64> = VecDeque::new();
+
+    Ok(input
+        .values
+        .map(move |row| {
+            let cell = value::cell_at(&row, &column)?;
+
+            let number = value::number_in(&cell, &row)?;
+
+            if latest.len() == size {
+                latest.pop_front();
+            }
+            latest.push_back(number);
+
+            let average = if latest.len() == size || partial {
+                let mean = latest.iter().sum::<f64>() / latest.len() as f64;
+                UntaggedValue::Primitive(Primitive::from(mean))
+            } else {
+                value::nothing()
+            };
+
+            match row.insert_data_at_path(&into, average.into_value(cell.tag())) {
+                Some(row) => ReturnSuccess::value(row),
+                None => Err(ShellError::labeled_error(
+                    "Could not add the average",
+                    "expected a row",
+                    row.tag(),
+                )),
+            }
+        })
+        .to_output_stream())
+}
@@ -0,0 +1,206 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::TaggedDictBuilder;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue};
+use nu_source::Tagged;
+use std::collections::HashMap;
+
+pub struct NormalizeColumns;
+
+#[derive(Deserialize)]
+pub struct NormalizeColumnsArgs {
+    mode: Tagged<String>,
+    keep: Option<Vec<Tagged<String>>>,
+}
+
+impl WholeStreamCommand for NormalizeColumns {
+    fn name(&self) -> &str {
+        "normalize-columns"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("normalize-columns")
+            .required(
+                "mode",
+                SyntaxShape::String,
+                "how to rewrite the column names: lower, upper, snake, kebab, title or trim",
+            )
+            .named(
+                "keep",
+                SyntaxShape::Any,
+                "with title, words to write as given, eg [ID URL]",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Renames every column by applying the same transform to its name."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, normalize_columns)?.run()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Mode {
+    Lower,
+    Upper,
+    Snake,
+    Kebab,
+    Title,
+    Trim,
+}
+
+fn normalize_columns(
+    NormalizeColumnsArgs { mode, keep }: NormalizeColumnsArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let mode = match mode.item.as_str() {
+        "lower" => Mode::Lower,
+        "upper" => Mode::Upper,
+        "snake" => Mode::Snake,
+        "kebab" => Mode::Kebab,
+        "title" => Mode::Title,
+        "trim" => Mode::Trim,
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Unknown mode",
+                "expected lower, upper, snake, kebab, title or trim",
+                mode.tag(),
+            ))
+        }
+    };
+
+    let keep: Vec<String> = keep
+        .unwrap_or_default()
+        .into_iter()
+        .map(|word| word.item)
+        .collect();
+
+    Ok(input
+        .values
+        .map(move |row| match &row.value {
+            UntaggedValue::Row(dict) => {
+                let mut renamed = TaggedDictBuilder::new(row.tag());
+                let mut originals: HashMap<String, &String> = HashMap::new();
+
+                for (column, cell) in dict.entries.iter() {
+                    let name = normalize(column, mode, &keep);
+
+                    if let Some(original) = originals.get(&name) {
+                        return Err(ShellError::labeled_error(
+                            "Column names collide",
+                            format!("{} and {} both become {}", original, column, name),
+                            row.tag(),
+                        ));
+                    }
+
+                    originals.insert(name.clone(), column);
+                    renamed.insert_value(name, cell.clone());
+                }
+
+                ReturnSuccess::value(renamed.into_value())
+            }
+            _ => ReturnSuccess::value(row.clone()),
+        })
+        .to_output_stream())
+}
+
+fn normalize(column: &str, mode: Mode, keep: &[String]) -> String {
+    match mode {
+        Mode::Lower => column.to_lowercase(),
+        Mode::Upper => column.to_uppercase(),
+        Mode::Snake => words(column).join("_"),
+        Mode::Kebab => words(column).join("-"),
+        Mode::Title => words(column)
+            .iter()
+            .map(|word| title_word(word, keep))
+            .collect::<Vec<_>>()
+            .join(" "),
+        Mode::Trim => column.trim().to_string(),
+    }
+}
+
+/// Capitalizes a lowercase word, unless it is one to keep, which is written as given (eg ID).
+fn title_word(word: &str, keep: &[String]) -> String {
+    if let Some(kept) = keep.iter().find(|kept| kept.to_lowercase() == word) {
+        return kept.clone();
+    }
+
+    let mut chars = word.chars();
+
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Splits a name into lowercase words, breaking on anything that isn't a letter or digit
+/// and where a lowercase letter or digit is followed by an uppercase one ("firstName").
+fn words(column: &str) -> Vec<String> {
+    let mut words = vec![];
+    let mut current = String::new();
+    let mut previous_is_lower = false;
+
+    for c in column.chars() {
+        if !c.is_alphanumeric() {
+            if !current.is_empty() {
+                words.push(current.clone());
+                current.clear();
+            }
+            previous_is_lower = false;
+            continue;
+        }
+
+        if c.is_uppercase() && previous_is_lower && !current.is_empty() {
+            words.push(current.clone());
+            current.clear();
+        }
+
+        previous_is_lower = c.is_lowercase() || c.is_numeric();
+        current.extend(c.to_lowercase());
+    }
+
+    if !current.is_empty() {
+        words.push(current);
+    }
+
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{normalize, Mode};
+
+    fn keep(words: &[&str]) -> Vec<String> {
+        words.iter().map(|word| word.to_string()).collect()
+    }
+
+    #[test]
+    fn snake_cases_spaces_dashes_and_camel_case() {
+        assert_eq!(normalize("First Name", Mode::Snake, &[]), "first_name");
+        assert_eq!(normalize("last-name", Mode::Snake, &[]), "last_name");
+        assert_eq!(normalize("homeAddress2", Mode::Snake, &[]), "home_address2");
+        assert_eq!(normalize("  ZIP code ", Mode::Snake, &[]), "zip_code");
+    }
+
+    #[test]
+    fn kebab_cases_like_snake_case() {
+        assert_eq!(normalize("First_Name", Mode::Kebab, &[]), "first-name");
+    }
+
+    #[test]
+    fn title_cases_words_keeping_the_given_spellings() {
+        assert_eq!(normalize("first_name", Mode::Title, &[]), "First Name");
+        assert_eq!(normalize("user-id", Mode::Title, &keep(&["ID"])), "User ID");
+        assert_eq!(
+            normalize("homeURL", Mode::Title, &keep(&["URL"])),
+            "Home URL"
+        );
+    }
+}
@@ -1,8 +1,10 @@
 use crate::commands::UnevaluatedCallInfo;
-use crate::data::value;
+use crate::data::{value, TaggedDictBuilder};
 use crate::prelude::*;
 use nu_errors::ShellError;
-use nu_protocol::{CallInfo, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_protocol::{
+    CallInfo, ReturnSuccess, ReturnValue, Signature, SyntaxShape, UntaggedValue, Value,
+};
 use nu_source::{AnchorLocation, Span};
 use std::path::{Path, PathBuf};
 
@@ -21,6 +23,14 @@ impl PerItemCommand for Open {
                 "the file path to load values from",
             )
             .switch("raw", "load content as a string insead of a table")
+            .switch(
+                "glob",
+                "open every file matching the path as a pattern, adding a source column",
+            )
+            .switch(
+                "skip-errors",
+                "with --glob, skip the files that can't be opened instead of stopping",
+            )
     }
 
     fn usage(&self) -> &str {
@@ -59,73 +69,171 @@ fn run(
     let path_str = path_buf.display().to_string();
     let path_span = path.tag.span;
     let has_raw = call_info.args.has("raw");
+    let has_glob = call_info.args.has("glob");
+    let skip_errors = call_info.args.has("skip-errors");
     let registry = registry.clone();
     let raw_args = raw_args.clone();
 
     let stream = async_stream! {
-
-        let result = fetch(&full_path, &path_str, path_span).await;
-
-        if let Err(e) = result {
-            yield Err(e);
+        if !has_glob {
+            for res in open_file(&full_path, &path_str, path_span, has_raw, &registry, raw_args).await {
+                yield res;
+            }
             return;
         }
-        let (file_extension, contents, contents_tag) = result.unwrap();
-
-        let file_extension = if has_raw {
-            None
-        } else {
-            // If the extension could not be determined via mimetype, try to use the path
-            // extension. Some file types do not declare their mimetypes (such as bson files).
-            file_extension.or(path_str.split('.').last().map(String::from))
+
+        let entries = match glob::glob(&full_path.join(&path_str).to_string_lossy()) {
+            Ok(entries) => entries,
+            Err(_) => {
+                yield Err(ShellError::labeled_error("Invalid pattern", "invalid pattern", path_span));
+                return;
+            }
         };
 
-        let tagged_contents = contents.into_value(&contents_tag);
-
-        if let Some(extension) = file_extension {
-            let command_name = format!("from-{}", extension);
-            if let Some(converter) = registry.get_command(&command_name) {
-                let new_args = RawCommandArgs {
-                    host: raw_args.host,
-                    ctrl_c: raw_args.ctrl_c,
-                    shell_manager: raw_args.shell_manager,
-                    call_info: UnevaluatedCallInfo {
-                        args: nu_parser::hir::Call {
-                            head: raw_args.call_info.args.head,
-                            positional: None,
-                            named: None,
-                            span: Span::unknown()
-                        },
-                        source: raw_args.call_info.source,
-                        name_tag: raw_args.call_info.name_tag,
-                    }
-                };
-                let mut result = converter.run(new_args.with_input(vec![tagged_contents]), &registry);
-                let result_vec: Vec<Result<ReturnSuccess, ShellError>> = result.drain_vec().await;
-                for res in result_vec {
-                    match res {
-                        Ok(ReturnSuccess::Value(Value { value: UntaggedValue::Table(list), ..})) => {
-                            for l in list {
-                                yield Ok(ReturnSuccess::Value(l));
-                            }
-                        }
-                        Ok(ReturnSuccess::Value(Value { value, .. })) => {
-                            yield Ok(ReturnSuccess::Value(Value { value, tag: contents_tag.clone() }));
-                        }
-                        x => yield x,
-                    }
+        let mut matched = false;
+
+        for entry in entries {
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(_) if skip_errors => continue,
+                Err(err) => {
+                    yield Err(ShellError::labeled_error("File could not be opened", err.to_string(), path_span));
+                    return;
+                }
+            };
+
+            if entry.is_dir() {
+                continue;
+            }
+
+            matched = true;
+
+            let source = entry.strip_prefix(&full_path).unwrap_or(&entry).display().to_string();
+            let results = open_file(&full_path, &source, path_span, has_raw, &registry, raw_args.clone()).await;
+
+            if results.iter().any(|res| res.is_err()) {
+                if skip_errors {
+                    continue;
+                }
+
+                for res in results.into_iter().filter(|res| res.is_err()) {
+                    yield res;
+                }
+                return;
+            }
+
+            for res in results {
+                match res {
+                    Ok(ReturnSuccess::Value(row)) => yield ReturnSuccess::value(with_source(row, &source)),
+                    other => yield other,
                 }
-            } else {
-                yield ReturnSuccess::value(tagged_contents);
             }
-        } else {
-            yield ReturnSuccess::value(tagged_contents);
+        }
+
+        if !matched {
+            yield Err(ShellError::labeled_error("No files matched", "no matches", path_span));
         }
     };
 
     Ok(stream.to_output_stream())
 }
 
+/// Loads the file and runs it through its `from-` converter, giving the converted rows.
+async fn open_file(
+    cwd: &PathBuf,
+    path_str: &str,
+    path_span: Span,
+    has_raw: bool,
+    registry: &CommandRegistry,
+    raw_args: RawCommandArgs,
+) -> Vec<ReturnValue> {
+    let result = fetch(cwd, path_str, path_span).await;
+
+    let (file_extension, contents, contents_tag) = match result {
+        Ok(result) => result,
+        Err(e) => return vec![Err(e)],
+    };
+
+    let file_extension = if has_raw {
+        None
+    } else {
+        // If the extension could not be determined via mimetype, try to use the path
+        // extension. Some file types do not declare their mimetypes (such as bson files).
+        file_extension.or(path_str.split('.').last().map(String::from))
+    };
+
+    let tagged_contents = contents.into_value(&contents_tag);
+
+    let converter = match file_extension {
+        Some(extension) => registry.get_command(&format!("from-{}", extension)),
+        None => None,
+    };
+
+    let converter = match converter {
+        Some(converter) => converter,
+        None => return vec![ReturnSuccess::value(tagged_contents)],
+    };
+
+    let new_args = RawCommandArgs {
+        host: raw_args.host,
+        ctrl_c: raw_args.ctrl_c,
+        shell_manager: raw_args.shell_manager,
+        call_info: UnevaluatedCallInfo {
+            args: nu_parser::hir::Call {
+                head: raw_args.call_info.args.head,
+                positional: None,
+                named: None,
+                span: Span::unknown(),
+            },
+            source: raw_args.call_info.source,
+            name_tag: raw_args.call_info.name_tag,
+        },
+    };
+
+    let mut result = converter.run(new_args.with_input(vec![tagged_contents]), registry);
+    let result_vec: Vec<Result<ReturnSuccess, ShellError>> = result.drain_vec().await;
+    let mut converted = vec![];
+
+    for res in result_vec {
+        match res {
+            Ok(ReturnSuccess::Value(Value {
+                value: UntaggedValue::Table(list),
+                ..
+            })) => {
+                for l in list {
+                    converted.push(Ok(ReturnSuccess::Value(l)));
+                }
+            }
+            Ok(ReturnSuccess::Value(Value { value, .. })) => {
+                converted.push(Ok(ReturnSuccess::Value(Value {
+                    value,
+                    tag: contents_tag.clone(),
+                })));
+            }
+            x => converted.push(x),
+        }
+    }
+
+    converted
+}
+
+/// Rows get a `source` column naming the file they came from. Other values are put in a row
+/// next to it.
+fn with_source(value: Value, source: &str) -> Value {
+    let source_value = value::string(source).into_value(&value.tag);
+
+    if let UntaggedValue::Row(_) = value.value {
+        if let Some(row) = value.insert_data_at_path("source", source_value.clone()) {
+            return row;
+        }
+    }
+
+    let mut row = TaggedDictBuilder::new(&value.tag);
+    row.insert_value("value", value.clone());
+    row.insert_value("source", source_value);
+    row.into_value()
+}
+
 pub async fn fetch(
     cwd: &PathBuf,
     location: &str,
@@ -148,99 +256,19 @@ pub async fn fetch(
                 )),
                 Err(_) => {
                     //Non utf8 data.
-                    match (bytes.get(0), bytes.get(1)) {
-                        (Some(x), Some(y)) if *x == 0xff && *y == 0xfe => {
-                            // Possibly UTF-16 little endian
-                            let utf16 = read_le_u16(&bytes[2..]);
-
-                            if let Some(utf16) = utf16 {
-                                match std::string::String::from_utf16(&utf16) {
-                                    Ok(s) => Ok((
-                                        cwd.extension()
-                                            .map(|name| name.to_string_lossy().to_string()),
-                                        value::string(s),
-                                        Tag {
-                                            span,
-                                            anchor: Some(AnchorLocation::File(
-                                                cwd.to_string_lossy().to_string(),
-                                            )),
-                                        },
-                                    )),
-                                    Err(_) => Ok((
-                                        None,
-                                        value::binary(bytes),
-                                        Tag {
-                                            span,
-                                            anchor: Some(AnchorLocation::File(
-                                                cwd.to_string_lossy().to_string(),
-                                            )),
-                                        },
-                                    )),
-                                }
-                            } else {
-                                Ok((
-                                    None,
-                                    value::binary(bytes),
-                                    Tag {
-                                        span,
-                                        anchor: Some(AnchorLocation::File(
-                                            cwd.to_string_lossy().to_string(),
-                                        )),
-                                    },
-                                ))
-                            }
-                        }
-                        (Some(x), Some(y)) if *x == 0xfe && *y == 0xff => {
-                            // Possibly UTF-16 big endian
-                            let utf16 = read_be_u16(&bytes[2..]);
-
-                            if let Some(utf16) = utf16 {
-                                match std::string::String::from_utf16(&utf16) {
-                                    Ok(s) => Ok((
-                                        cwd.extension()
-                                            .map(|name| name.to_string_lossy().to_string()),
-                                        value::string(s),
-                                        Tag {
-                                            span,
-                                            anchor: Some(AnchorLocation::File(
-                                                cwd.to_string_lossy().to_string(),
-                                            )),
-                                        },
-                                    )),
-                                    Err(_) => Ok((
-                                        None,
-                                        value::binary(bytes),
-                                        Tag {
-                                            span,
-                                            anchor: Some(AnchorLocation::File(
-                                                cwd.to_string_lossy().to_string(),
-                                            )),
-                                        },
-                                    )),
-                                }
-                            } else {
-                                Ok((
-                                    None,
-                                    value::binary(bytes),
-                                    Tag {
-                                        span,
-                                        anchor: Some(AnchorLocation::File(
-                                            cwd.to_string_lossy().to_string(),
-                                        )),
-                                    },
-                                ))
-                            }
-                        }
-                        _ => Ok((
-                            None,
-                            value::binary(bytes),
-                            Tag {
-                                span,
-                                anchor: Some(AnchorLocation::File(
-                                    cwd.to_string_lossy().to_string(),
-                                )),
-                            },
+                    let tag = Tag {
+                        span,
+                        anchor: Some(AnchorLocation::File(cwd.to_string_lossy().to_string())),
+                    };
+
+                    match decode_with_bom(&bytes) {
+                        Some(s) => Ok((
+                            cwd.extension()
+                                .map(|name| name.to_string_lossy().to_string()),
+                            value::string(s),
+                            tag,
                         )),
+                        None => Ok((None, value::binary(bytes), tag)),
                     }
                 }
             },
@@ -261,6 +289,57 @@ pub async fn fetch(
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ByteOrderMark {
+    Utf8,
+    Utf16LittleEndian,
+    Utf16BigEndian,
+}
+
+impl ByteOrderMark {
+    pub fn encoding_name(self) -> &'static str {
+        match self {
+            ByteOrderMark::Utf8 => "utf-8",
+            ByteOrderMark::Utf16LittleEndian => "utf-16le",
+            ByteOrderMark::Utf16BigEndian => "utf-16be",
+        }
+    }
+
+    pub fn byte_len(self) -> usize {
+        match self {
+            ByteOrderMark::Utf8 => 3,
+            ByteOrderMark::Utf16LittleEndian | ByteOrderMark::Utf16BigEndian => 2,
+        }
+    }
+}
+
+/// Detects the encoding of the bytes from their leading byte order mark, if any.
+pub fn detect_bom(bytes: &[u8]) -> Option<ByteOrderMark> {
+    if bytes.starts_with(&[0xef, 0xbb, 0xbf]) {
+        Some(ByteOrderMark::Utf8)
+    } else if bytes.starts_with(&[0xff, 0xfe]) {
+        Some(ByteOrderMark::Utf16LittleEndian)
+    } else if bytes.starts_with(&[0xfe, 0xff]) {
+        Some(ByteOrderMark::Utf16BigEndian)
+    } else {
+        None
+    }
+}
+
+/// Decodes UTF-16 bytes that start with a byte order mark.
+pub fn decode_with_bom(bytes: &[u8]) -> Option<String> {
+    let bom = detect_bom(bytes)?;
+    let contents = &bytes[bom.byte_len()..];
+
+    let utf16 = match bom {
+        ByteOrderMark::Utf16LittleEndian => read_le_u16(contents)?,
+        ByteOrderMark::Utf16BigEndian => read_be_u16(contents)?,
+        ByteOrderMark::Utf8 => return None,
+    };
+
+    std::string::String::from_utf16(&utf16).ok()
+}
+
 fn read_le_u16(input: &[u8]) -> Option<Vec<u16>> {
     if input.len() % 2 != 0 || input.len() < 2 {
         None
@@ -0,0 +1,190 @@
+use crate::commands::percentile::linear;
+use crate::commands::WholeStreamCommand;
+use crate::data::value;
+use crate::prelude::*;
+use bigdecimal::BigDecimal;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, ShellTypeName, Signature, SyntaxShape, Value};
+use nu_source::Tagged;
+use num_traits::{FromPrimitive, ToPrimitive};
+
+pub struct Outliers;
+
+#[derive(Deserialize)]
+pub struct OutliersArgs {
+    column: Tagged<String>,
+    iqr: Option<Value>,
+    zscore: Option<Value>,
+    only: bool,
+}
+
+impl WholeStreamCommand for Outliers {
+    fn name(&self) -> &str {
+        "outliers"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("outliers")
+            .required(
+                "column",
+                SyntaxShape::String,
+                "the column holding the numbers",
+            )
+            .named(
+                "iqr",
+                SyntaxShape::Number,
+                "flag values this many interquartile ranges outside the quartiles (the default, with 1.5)",
+            )
+            .named(
+                "zscore",
+                SyntaxShape::Number,
+                "flag values this many standard deviations from the mean instead",
+            )
+            .switch(
+                "only",
+                "keep only the outlying rows instead of adding an is_outlier column",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Flags the rows whose number in the given column lies far from the rest."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, outliers)?.run()
+    }
+}
+
+enum Method {
+    Iqr(f64),
+    ZScore(f64),
+}
+
+impl Method {
+    /// The lowest and highest numbers that are not outliers.
+    fn bounds(&self, numbers: &[f64]) -> (f64, f64) {
+        match self {
+            Method::Iqr(factor) => {
+                let mut sorted: Vec<BigDecimal> = numbers
+                    .iter()
+                    .filter_map(|n| BigDecimal::from_f64(*n))
+                    .collect();
+                sorted.sort();
+
+                let q1 = linear(&sorted, &BigDecimal::from(25))
+                    .to_f64()
+                    .unwrap_or(0.0);
+                let q3 = linear(&sorted, &BigDecimal::from(75))
+                    .to_f64()
+                    .unwrap_or(0.0);
+                let spread = factor * (q3 - q1);
+
+                (q1 - spread, q3 + spread)
+            }
+            Method::ZScore(deviations) => {
+                let count = numbers.len() as f64;
+                let mean = numbers.iter().sum::<f64>() / count;
+                let variance = numbers.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / count;
+                let spread = deviations * variance.sqrt();
+
+                (mean - spread, mean + spread)
+            }
+        }
+    }
+}
+
+fn outliers(
+    OutliersArgs {
+        column,
+        iqr,
+        zscore,
+        only,
+    }: OutliersArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let method = match (&iqr, &zscore) {
+        (Some(_), Some(zscore)) => {
+            return Err(ShellError::labeled_error(
+                "Choose one method",
+                "--iqr and --zscore can't be used together",
+                zscore.tag(),
+            ))
+        }
+        (Some(iqr), None) => Method::Iqr(positive_number(iqr)?),
+        (None, Some(zscore)) => Method::ZScore(positive_number(zscore)?),
+        (None, None) => Method::Iqr(1.5),
+    };
+
+    let stream = async_stream! {
+        let rows: Vec<Value> = input.values.collect().await;
+        let mut numbers = Vec::with_capacity(rows.len());
+
+        for row in &rows {
+            match value::number_at(row, &column) {
+                Ok(n) => numbers.push(n),
+                Err(err) => {
+                    yield Err(err);
+                    return;
+                }
+            }
+        }
+
+        // Quartiles of fewer than four values don't say much about the spread.
+        if let Method::Iqr(_) = method {
+            if numbers.len() < 4 {
+                yield Err(ShellError::labeled_error(
+                    "Not enough values for the IQR method",
+                    format!("needs at least 4 values, found {}", numbers.len()),
+                    &name,
+                ));
+                return;
+            }
+        }
+
+        if numbers.is_empty() {
+            return;
+        }
+
+        let (low, high) = method.bounds(&numbers);
+
+        for (row, number) in rows.into_iter().zip(numbers) {
+            let is_outlier = number < low || number > high;
+
+            if only {
+                if is_outlier {
+                    yield ReturnSuccess::value(row);
+                }
+                continue;
+            }
+
+            let flag = value::boolean(is_outlier).into_value(row.tag());
+
+            match row.insert_data_at_path("is_outlier", flag) {
+                Some(flagged) => yield ReturnSuccess::value(flagged),
+                None => yield ReturnSuccess::value(row),
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+fn positive_number(given: &Value) -> Result<f64, ShellError> {
+    match value::as_f64(given) {
+        Some(n) if n > 0.0 => Ok(n),
+        Some(_) => Err(ShellError::labeled_error(
+            "Expected a positive number",
+            "the threshold must be above 0",
+            given.tag(),
+        )),
+        None => Err(ShellError::labeled_error(
+            "Expected a number",
+            format!("found {} instead", given.type_name()),
+            given.tag(),
+        )),
+    }
+}
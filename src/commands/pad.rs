@@ -0,0 +1,178 @@
+use crate::commands::get::get_column_path;
+use crate::commands::WholeStreamCommand;
+use crate::data::value;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue,
+    Value,
+};
+use nu_source::Tagged;
+
+pub struct Pad;
+
+#[derive(Deserialize)]
+pub struct PadArgs {
+    column: ColumnPath,
+    width: Tagged<usize>,
+    #[serde(rename(deserialize = "char"))]
+    fill: Option<Tagged<String>>,
+    align: Option<Tagged<String>>,
+    truncate: bool,
+}
+
+#[derive(Clone, Copy)]
+enum Alignment {
+    Left,
+    Right,
+    Center,
+}
+
+impl WholeStreamCommand for Pad {
+    fn name(&self) -> &str {
+        "pad"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("pad")
+            .required(
+                "column",
+                SyntaxShape::ColumnPath,
+                "the column holding the strings to pad",
+            )
+            .required("width", SyntaxShape::Int, "the width to pad the strings to")
+            .named(
+                "char",
+                SyntaxShape::String,
+                "the character to pad with (defaults to a space)",
+            )
+            .named(
+                "align",
+                SyntaxShape::String,
+                "where to place the string: left, right or center (defaults to left)",
+            )
+            .switch("truncate", "cut strings longer than the width")
+    }
+
+    fn usage(&self) -> &str {
+        "Pads the strings in a column to a fixed width."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, pad)?.run()
+    }
+}
+
+fn pad(
+    PadArgs {
+        column,
+        width,
+        fill,
+        align,
+        truncate,
+    }: PadArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let fill = match fill {
+        Some(fill) => {
+            let mut chars = fill.item.chars();
+
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => c,
+                _ => {
+                    return Err(ShellError::labeled_error(
+                        "Expected a single character",
+                        "pad with a single character",
+                        fill.tag(),
+                    ))
+                }
+            }
+        }
+        None => ' ',
+    };
+
+    let alignment = match align {
+        Some(align) => match align.item.as_str() {
+            "left" => Alignment::Left,
+            "right" => Alignment::Right,
+            "center" => Alignment::Center,
+            _ => {
+                return Err(ShellError::labeled_error(
+                    "Unknown alignment",
+                    "expected left, right or center",
+                    align.tag(),
+                ))
+            }
+        },
+        None => Alignment::Left,
+    };
+
+    let width = width.item;
+
+    Ok(input
+        .values
+        .map(move |row| {
+            let cell = get_column_path(&column, &row)?;
+            let padded = pad_value(&cell, width, fill, alignment, truncate)?;
+
+            match row.replace_data_at_column_path(&column, padded) {
+                Some(row) => ReturnSuccess::value(row),
+                None => Err(ShellError::labeled_error(
+                    "pad could not find the column",
+                    "column name",
+                    &name,
+                )),
+            }
+        })
+        .to_output_stream())
+}
+
+fn pad_value(
+    cell: &Value,
+    width: usize,
+    fill: char,
+    alignment: Alignment,
+    truncate: bool,
+) -> Result<Value, ShellError> {
+    let s = match &cell.value {
+        UntaggedValue::Primitive(Primitive::String(s)) => s,
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Expected a string",
+                format!("found {} instead", cell.type_name()),
+                cell.tag(),
+            ))
+        }
+    };
+
+    Ok(value::string(pad_string(s, width, fill, alignment, truncate)).into_value(cell.tag()))
+}
+
+fn pad_string(s: &str, width: usize, fill: char, alignment: Alignment, truncate: bool) -> String {
+    let len = s.chars().count();
+
+    if len >= width {
+        return if truncate {
+            s.chars().take(width).collect()
+        } else {
+            s.to_string()
+        };
+    }
+
+    let missing = width - len;
+    let (left, right) = match alignment {
+        Alignment::Left => (0, missing),
+        Alignment::Right => (missing, 0),
+        Alignment::Center => (missing / 2, missing - missing / 2),
+    };
+
+    let mut padded = String::new();
+    padded.extend(std::iter::repeat(fill).take(left));
+    padded.push_str(s);
+    padded.extend(std::iter::repeat(fill).take(right));
+    padded
+}
@@ -0,0 +1,103 @@
+use crate::commands::get::get_column_path;
+use crate::commands::WholeStreamCommand;
+use crate::data::value;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue,
+    Value,
+};
+use num_traits::ToPrimitive;
+
+pub struct ParseUnit;
+
+#[derive(Deserialize)]
+pub struct ParseUnitArgs {
+    column: ColumnPath,
+    lenient: bool,
+}
+
+impl WholeStreamCommand for ParseUnit {
+    fn name(&self) -> &str {
+        "parse-unit"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("parse-unit")
+            .required(
+                "column",
+                SyntaxShape::ColumnPath,
+                "the column holding the sizes or durations",
+            )
+            .switch(
+                "lenient",
+                "leave strings that are not sizes or durations as they are",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Turns strings like 5kb or 10s in the given column into sizes and durations."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, parse_unit)?.run()
+    }
+}
+
+fn parse_unit(
+    ParseUnitArgs { column, lenient }: ParseUnitArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    Ok(input
+        .values
+        .map(move |row| {
+            let cell = get_column_path(&column, &row)?;
+
+            let parsed = match parse_value(&cell) {
+                Some(parsed) => parsed,
+                None if lenient => return ReturnSuccess::value(row),
+                None => {
+                    return Err(ShellError::labeled_error_with_secondary(
+                        "Expected a size or duration",
+                        format!("could not parse {} as a unit", cell.type_name()),
+                        cell.tag(),
+                        "row originates from here",
+                        row.tag(),
+                    ))
+                }
+            };
+
+            match row.replace_data_at_column_path(&column, parsed) {
+                Some(row) => ReturnSuccess::value(row),
+                None => Err(ShellError::labeled_error(
+                    "parse-unit could not find the column",
+                    "column name",
+                    &name,
+                )),
+            }
+        })
+        .to_output_stream())
+}
+
+fn parse_value(cell: &Value) -> Option<Value> {
+    let s = match &cell.value {
+        UntaggedValue::Primitive(Primitive::String(s)) => s,
+        UntaggedValue::Primitive(Primitive::Line(s)) => s,
+        _ => return None,
+    };
+
+    let (number, unit) = nu_parser::parse_unit(s.trim())?;
+
+    // Sizes are computed as plain numbers, so turn them into byte counts here.
+    let parsed = match unit.compute(&number) {
+        UntaggedValue::Primitive(Primitive::Int(i)) => value::bytes(i.to_u64()?),
+        UntaggedValue::Primitive(Primitive::Decimal(d)) => value::bytes(d.to_u64()?),
+        other => other,
+    };
+
+    Some(parsed.into_value(cell.tag()))
+}
@@ -0,0 +1,85 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::{value, TaggedDictBuilder};
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    Evaluate, Primitive, ReturnSuccess, Scope, Signature, SyntaxShape, UntaggedValue, Value,
+};
+
+pub struct Partition;
+
+#[derive(Deserialize)]
+pub struct PartitionArgs {
+    condition: Evaluate,
+}
+
+impl WholeStreamCommand for Partition {
+    fn name(&self) -> &str {
+        "partition"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("partition").required(
+            "condition",
+            SyntaxShape::Block,
+            "the condition that decides which table a row goes into",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Splits the table into the rows that match the condition and the rows that don't."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, partition)?.run()
+    }
+}
+
+pub fn partition(
+    PartitionArgs { condition }: PartitionArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let stream = async_stream! {
+        let values: Vec<Value> = input.values.collect().await;
+
+        let mut matched = vec![];
+        let mut unmatched = vec![];
+
+        for value in values {
+            match condition.invoke(&Scope::new(value.clone())) {
+                Ok(Value {
+                    value: UntaggedValue::Primitive(Primitive::Boolean(true)),
+                    ..
+                }) => matched.push(value),
+                Ok(Value {
+                    value: UntaggedValue::Primitive(Primitive::Boolean(false)),
+                    ..
+                }) => unmatched.push(value),
+                Ok(_) => {
+                    yield Err(ShellError::labeled_error(
+                        "Expected a condition",
+                        "condition must evaluate to true or false",
+                        &name,
+                    ));
+                    return;
+                }
+                Err(err) => {
+                    yield Err(err);
+                    return;
+                }
+            }
+        }
+
+        let mut row = TaggedDictBuilder::new(&name);
+        row.insert_untagged("matched", value::table(&matched));
+        row.insert_untagged("unmatched", value::table(&unmatched));
+
+        yield ReturnSuccess::value(row.into_value());
+    };
+
+    Ok(stream.to_output_stream())
+}
@@ -0,0 +1,79 @@
+use crate::commands::path_parse::operate_on_path;
+use crate::commands::WholeStreamCommand;
+use crate::data::value;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, ReturnSuccess, Signature, SyntaxShape};
+use std::path::PathBuf;
+
+#[derive(Deserialize)]
+struct PathCanonicalizeArgs {
+    column: Option<ColumnPath>,
+    #[serde(rename(deserialize = "allow-missing"))]
+    allow_missing: bool,
+}
+
+pub struct PathCanonicalize;
+
+impl WholeStreamCommand for PathCanonicalize {
+    fn name(&self) -> &str {
+        "path-canonicalize"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("path-canonicalize")
+            .optional(
+                "column",
+                SyntaxShape::ColumnPath,
+                "the column holding the paths (defaults to the input itself)",
+            )
+            .switch(
+                "allow-missing",
+                "leave paths that don't exist as they are instead of failing",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Resolves paths against the current directory, following symlinks."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, path_canonicalize)?.run()
+    }
+}
+
+fn path_canonicalize(
+    PathCanonicalizeArgs {
+        column,
+        allow_missing,
+    }: PathCanonicalizeArgs,
+    RunnableContext {
+        input,
+        name,
+        shell_manager,
+        ..
+    }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let cwd = PathBuf::from(shell_manager.path());
+
+    Ok(input
+        .values
+        .map(move |row| {
+            ReturnSuccess::value(operate_on_path(row, &column, &name, |path, tag| {
+                match dunce::canonicalize(cwd.join(path)) {
+                    Ok(canonical) => Ok(value::path(canonical)),
+                    Err(_) if allow_missing => Ok(value::path(path)),
+                    Err(err) => Err(ShellError::labeled_error(
+                        "Could not canonicalize the path",
+                        err.to_string(),
+                        tag,
+                    )),
+                }
+            })?)
+        })
+        .to_output_stream())
+}
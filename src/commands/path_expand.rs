@@ -0,0 +1,177 @@
+use crate::commands::path_parse::operate_on_path;
+use crate::commands::WholeStreamCommand;
+use crate::data::value;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, ReturnSuccess, Signature, SyntaxShape};
+use std::path::PathBuf;
+
+#[derive(Deserialize)]
+struct PathExpandArgs {
+    column: Option<ColumnPath>,
+    strict: bool,
+}
+
+pub struct PathExpand;
+
+impl WholeStreamCommand for PathExpand {
+    fn name(&self) -> &str {
+        "path-expand"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("path-expand")
+            .optional(
+                "column",
+                SyntaxShape::ColumnPath,
+                "the column holding the paths (defaults to the input itself)",
+            )
+            .switch(
+                "strict",
+                "fail on variables that aren't set instead of leaving them empty",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Expands ~ and $VAR/%VAR% references in paths, making them absolute."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, path_expand)?.run()
+    }
+}
+
+fn path_expand(
+    PathExpandArgs { column, strict }: PathExpandArgs,
+    RunnableContext {
+        input,
+        name,
+        shell_manager,
+        ..
+    }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let cwd = PathBuf::from(shell_manager.path());
+
+    Ok(input
+        .values
+        .map(move |row| {
+            ReturnSuccess::value(operate_on_path(row, &column, &name, |path, tag| {
+                let expanded = expand(&path.to_string_lossy(), strict, |var| {
+                    std::env::var(var).ok()
+                })
+                .map_err(|var| {
+                    ShellError::labeled_error(
+                        "Variable not set",
+                        format!("${} is not set", var),
+                        tag,
+                    )
+                })?;
+
+                let mut expanded = PathBuf::from(expanded);
+
+                if let Ok(rest) = expanded.strip_prefix("~") {
+                    if let Some(home) = dirs::home_dir() {
+                        expanded = home.join(rest);
+                    }
+                }
+
+                Ok(value::path(cwd.join(expanded)))
+            })?)
+        })
+        .to_output_stream())
+}
+
+/// Replaces `$VAR`, `${VAR}` and `%VAR%` with the variable's value. Variables that aren't
+/// set become empty, or make the whole expansion fail with their name when `strict`.
+fn expand(
+    text: &str,
+    strict: bool,
+    lookup: impl Fn(&str) -> Option<String>,
+) -> Result<String, String> {
+    let mut expanded = String::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find(|c| c == '$' || c == '%') {
+        expanded.push_str(&rest[..start]);
+
+        let marker = &rest[start..=start];
+        let after = &rest[start + 1..];
+
+        let (var, consumed) = if marker == "%" {
+            match after.find('%') {
+                Some(end) if is_var_name(&after[..end]) => (&after[..end], end + 1),
+                _ => ("", 0),
+            }
+        } else if after.starts_with('{') {
+            match after.find('}') {
+                Some(end) if is_var_name(&after[1..end]) => (&after[1..end], end + 1),
+                _ => ("", 0),
+            }
+        } else {
+            let end = after
+                .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .unwrap_or(after.len());
+            (&after[..end], end)
+        };
+
+        if var.is_empty() {
+            expanded.push_str(marker);
+        } else {
+            match lookup(var) {
+                Some(value) => expanded.push_str(&value),
+                None if strict => return Err(var.to_string()),
+                None => {}
+            }
+        }
+
+        rest = &after[consumed..];
+    }
+
+    expanded.push_str(rest);
+
+    Ok(expanded)
+}
+
+fn is_var_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::expand;
+
+    fn lookup(var: &str) -> Option<String> {
+        match var {
+            "HOME" => Some("/home/viking".to_string()),
+            "APPDATA" => Some("C:/Users/viking/AppData".to_string()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn expands_dollar_brace_and_percent_references() {
+        assert_eq!(
+            expand("$HOME/a:${HOME}/b:%APPDATA%/c", false, lookup).unwrap(),
+            "/home/viking/a:/home/viking/b:C:/Users/viking/AppData/c"
+        );
+    }
+
+    #[test]
+    fn leaves_lone_markers_alone() {
+        assert_eq!(expand("100% done", false, lookup).unwrap(), "100% done");
+        assert_eq!(expand("cost$/x", false, lookup).unwrap(), "cost$/x");
+    }
+
+    #[test]
+    fn missing_variables_are_empty_unless_strict() {
+        assert_eq!(expand("$MISSING/bin", false, lookup).unwrap(), "/bin");
+        assert_eq!(
+            expand("$MISSING/bin", true, lookup),
+            Err("MISSING".to_string())
+        );
+    }
+}
@@ -0,0 +1,104 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::value;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, Value};
+use std::path::{Component, Path, PathBuf};
+
+#[derive(Deserialize)]
+struct PathJoinArgs {
+    rest: Vec<Value>,
+}
+
+pub struct PathJoin;
+
+impl WholeStreamCommand for PathJoin {
+    fn name(&self) -> &str {
+        "path-join"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("path-join").required_rest(
+            SyntaxShape::Any,
+            "the segments to join; an absolute segment starts the path over",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Joins the segments into a single path, resolving . and .. where it can."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, path_join)?.run()
+    }
+}
+
+fn path_join(
+    PathJoinArgs { rest }: PathJoinArgs,
+    RunnableContext { name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let mut joined = PathBuf::new();
+
+    // Segments are pushed like `PathBuf::push`, so an absolute segment in the middle
+    // replaces everything before it.
+    for segment in &rest {
+        joined.push(segment.as_path()?);
+    }
+
+    Ok(OutputStream::one(ReturnSuccess::value(
+        value::path(normalize(&joined)).into_value(&name),
+    )))
+}
+
+/// Drops `.` and lets `..` remove the segment before it. A `..` with nothing before it to
+/// remove is kept, except right after the root, where it has nowhere to go. A path that
+/// resolves to nothing becomes `.`.
+fn normalize(path: &Path) -> PathBuf {
+    let mut components: Vec<Component> = vec![];
+
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => match components.last() {
+                Some(Component::Normal(_)) => {
+                    components.pop();
+                }
+                Some(Component::RootDir) | Some(Component::Prefix(_)) => {}
+                _ => components.push(component),
+            },
+            _ => components.push(component),
+        }
+    }
+
+    if components.is_empty() {
+        return PathBuf::from(".");
+    }
+
+    components.iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::normalize;
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn resolves_current_and_parent_segments() {
+        assert_eq!(
+            normalize(Path::new("nu/./src/../tests")),
+            PathBuf::from("nu").join("tests")
+        );
+    }
+
+    #[test]
+    fn keeps_leading_parent_segments() {
+        assert_eq!(
+            normalize(Path::new("../../nu")),
+            PathBuf::from("..").join("..").join("nu")
+        );
+    }
+}
@@ -0,0 +1,150 @@
+use crate::commands::get::get_column_path;
+use crate::commands::WholeStreamCommand;
+use crate::data::{value, TaggedDictBuilder};
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    ColumnPath, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::Tagged;
+use std::ffi::OsStr;
+use std::path::Path;
+
+#[derive(Deserialize)]
+struct PathParseArgs {
+    column: Option<ColumnPath>,
+    component: Option<Tagged<String>>,
+}
+
+pub struct PathParse;
+
+impl WholeStreamCommand for PathParse {
+    fn name(&self) -> &str {
+        "path-parse"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("path-parse")
+            .optional(
+                "column",
+                SyntaxShape::ColumnPath,
+                "the column holding the paths (defaults to the input itself)",
+            )
+            .named(
+                "component",
+                SyntaxShape::String,
+                "return only this component: parent, stem, extension or filename",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Splits paths into their parent, stem, extension and filename."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, path_parse)?.run()
+    }
+}
+
+fn path_parse(
+    PathParseArgs { column, component }: PathParseArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    if let Some(component) = &component {
+        match component.item.as_str() {
+            "parent" | "stem" | "extension" | "filename" => {}
+            _ => {
+                return Err(ShellError::labeled_error(
+                    "Unknown path component",
+                    "expected parent, stem, extension or filename",
+                    component.tag(),
+                ))
+            }
+        }
+    }
+
+    let component = component.map(|component| component.item);
+
+    Ok(input
+        .values
+        .map(move |row| {
+            ReturnSuccess::value(operate_on_path(
+                row,
+                &column,
+                &name,
+                |path, tag| match &component {
+                    Some(component) => Ok(path_component(path, component)),
+                    None => {
+                        let mut parsed = TaggedDictBuilder::new(tag);
+
+                        for component in &["parent", "stem", "extension", "filename"] {
+                            parsed.insert_untagged(*component, path_component(path, component));
+                        }
+
+                        Ok(parsed.into_untagged_value())
+                    }
+                },
+            )?)
+        })
+        .to_output_stream())
+}
+
+fn path_component(path: &Path, component: &str) -> UntaggedValue {
+    let text = |part: Option<&OsStr>| match part {
+        Some(part) => value::string(part.to_string_lossy()),
+        None => value::nothing(),
+    };
+
+    match component {
+        "parent" => match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => value::path(parent),
+            _ => value::nothing(),
+        },
+        "stem" => text(path.file_stem()),
+        "extension" => text(path.extension()),
+        _ => text(path.file_name()),
+    }
+}
+
+/// Runs `action` on the path found in the given column of the row, replacing the cell
+/// with the result. Without a column, the input value itself is taken as the path.
+/// Both paths and strings are accepted.
+pub fn operate_on_path(
+    row: Value,
+    column: &Option<ColumnPath>,
+    name: &Tag,
+    action: impl Fn(&Path, &Tag) -> Result<UntaggedValue, ShellError>,
+) -> Result<Value, ShellError> {
+    match column {
+        Some(column) => {
+            let cell = get_column_path(column, &row)?;
+            let updated = action(&expect_path(&cell, name)?, &cell.tag)?.into_value(&cell.tag);
+
+            match row.replace_data_at_column_path(column, updated) {
+                Some(row) => Ok(row),
+                None => Err(ShellError::labeled_error(
+                    "Could not update the path",
+                    "expected a row",
+                    row.tag(),
+                )),
+            }
+        }
+        None => Ok(action(&expect_path(&row, name)?, &row.tag)?.into_value(&row.tag)),
+    }
+}
+
+pub fn expect_path(value: &Value, name: &Tag) -> Result<std::path::PathBuf, ShellError> {
+    value.as_path().map_err(|_| {
+        ShellError::labeled_error_with_secondary(
+            "Expected a path",
+            format!("found {} instead", value.type_name()),
+            name,
+            "value originates from here",
+            value.tag(),
+        )
+    })
+}
@@ -0,0 +1,65 @@
+use crate::commands::path_parse::operate_on_path;
+use crate::commands::WholeStreamCommand;
+use crate::data::value;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, ReturnSuccess, Signature, SyntaxShape};
+use nu_source::Tagged;
+
+#[derive(Deserialize)]
+struct PathSetExtensionArgs {
+    extension: Tagged<String>,
+    column: Option<ColumnPath>,
+}
+
+pub struct PathSetExtension;
+
+impl WholeStreamCommand for PathSetExtension {
+    fn name(&self) -> &str {
+        "path-set-extension"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("path-set-extension")
+            .required(
+                "extension",
+                SyntaxShape::String,
+                "the new extension, with or without the dot (\"\" removes it)",
+            )
+            .optional(
+                "column",
+                SyntaxShape::ColumnPath,
+                "the column holding the paths (defaults to the input itself)",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Replaces, adds or removes the extension of paths."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, path_set_extension)?.run()
+    }
+}
+
+fn path_set_extension(
+    PathSetExtensionArgs { extension, column }: PathSetExtensionArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let extension = extension.item.trim_start_matches('.').to_string();
+
+    Ok(input
+        .values
+        .map(move |row| {
+            ReturnSuccess::value(operate_on_path(row, &column, &name, |path, _| {
+                let mut path = path.to_path_buf();
+                path.set_extension(&extension);
+                Ok(value::path(path))
+            })?)
+        })
+        .to_output_stream())
+}
@@ -0,0 +1,140 @@
+use crate::commands::get::get_column_path;
+use crate::commands::path_parse::expect_path;
+use crate::commands::WholeStreamCommand;
+use crate::data::files::FileType;
+use crate::data::{value, TaggedDictBuilder};
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, ReturnSuccess, Signature, SyntaxShape, Value};
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+#[derive(Deserialize)]
+struct PathTypeArgs {
+    column: Option<ColumnPath>,
+    #[serde(rename(deserialize = "only-existing"))]
+    only_existing: bool,
+}
+
+pub struct PathType;
+
+impl WholeStreamCommand for PathType {
+    fn name(&self) -> &str {
+        "path-type"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("path-type")
+            .optional(
+                "column",
+                SyntaxShape::ColumnPath,
+                "the column holding the paths (defaults to the input itself)",
+            )
+            .switch("only-existing", "drop the rows whose path doesn't exist")
+    }
+
+    fn usage(&self) -> &str {
+        "Adds whether each path exists and whether it is a file, directory or symlink."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, path_type)?.run()
+    }
+}
+
+fn path_type(
+    PathTypeArgs {
+        column,
+        only_existing,
+    }: PathTypeArgs,
+    RunnableContext {
+        input,
+        name,
+        shell_manager,
+        ..
+    }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let cwd = PathBuf::from(shell_manager.path());
+
+    let stream = input
+        .values
+        .map(move |row| {
+            let mut result = VecDeque::new();
+
+            match annotate(&row, &column, &cwd, &name) {
+                Ok((_, false)) if only_existing => {}
+                Ok((annotated, _)) => result.push_back(ReturnSuccess::value(annotated)),
+                Err(err) => result.push_back(Err(err)),
+            }
+
+            result
+        })
+        .flatten();
+
+    Ok(stream.to_output_stream())
+}
+
+/// Returns the annotated row along with whether its path exists.
+fn annotate(
+    row: &Value,
+    column: &Option<ColumnPath>,
+    cwd: &Path,
+    name: &Tag,
+) -> Result<(Value, bool), ShellError> {
+    let cell = match column {
+        Some(column) => get_column_path(column, row)?,
+        None => row.clone(),
+    };
+
+    let kind = file_type(&cwd.join(expect_path(&cell, name)?)).map_err(|err| {
+        ShellError::labeled_error(
+            "Could not read the path's metadata",
+            err.to_string(),
+            cell.tag(),
+        )
+    })?;
+
+    let exists = kind.is_some();
+    let kind = match kind {
+        Some(kind) => value::string(format!("{:?}", kind)),
+        None => value::nothing(),
+    };
+
+    let annotated = match column {
+        Some(_) => row
+            .insert_data_at_path("exists", value::boolean(exists).into_value(row.tag()))
+            .and_then(|row| row.insert_data_at_path("type", kind.into_value(row.tag())))
+            .ok_or_else(|| {
+                ShellError::labeled_error(
+                    "Could not annotate the path",
+                    "expected a row",
+                    row.tag(),
+                )
+            })?,
+        None => {
+            let mut annotated = TaggedDictBuilder::new(row.tag());
+            annotated.insert_value("path", row.clone());
+            annotated.insert_untagged("exists", value::boolean(exists));
+            annotated.insert_untagged("type", kind);
+            annotated.into_value()
+        }
+    };
+
+    Ok((annotated, exists))
+}
+
+/// Symlinks are reported as such rather than followed. A path that doesn't exist gives
+/// `None`; any other failure (like missing permissions) is passed on.
+fn file_type(path: &Path) -> Result<Option<FileType>, std::io::Error> {
+    match std::fs::symlink_metadata(path) {
+        Ok(metadata) if metadata.file_type().is_symlink() => Ok(Some(FileType::Symlink)),
+        Ok(metadata) if metadata.is_dir() => Ok(Some(FileType::Directory)),
+        Ok(_) => Ok(Some(FileType::File)),
+        Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err),
+    }
+}
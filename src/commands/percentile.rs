@@ -0,0 +1,182 @@
+use crate::commands::get::get_column_path;
+use crate::commands::WholeStreamCommand;
+use crate::data::{value, TaggedDictBuilder};
+use crate::prelude::*;
+use bigdecimal::BigDecimal;
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, Value};
+use nu_source::Tagged;
+use num_traits::ToPrimitive;
+
+pub struct Percentile;
+
+#[derive(Deserialize)]
+pub struct PercentileArgs {
+    column: ColumnPath,
+    rest: Vec<Value>,
+    method: Option<Tagged<String>>,
+}
+
+impl WholeStreamCommand for Percentile {
+    fn name(&self) -> &str {
+        "percentile"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("percentile")
+            .required(
+                "column",
+                SyntaxShape::ColumnPath,
+                "the column holding the numbers",
+            )
+            .required_rest(
+                SyntaxShape::Number,
+                "the percentiles to compute, from 0 to 100",
+            )
+            .named(
+                "method",
+                SyntaxShape::String,
+                "linear (the default) to interpolate between ranks, or nearest to pick the nearest rank",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Computes percentiles of a numeric column, returning them as a row."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, percentile)?.run()
+    }
+}
+
+enum Method {
+    Linear,
+    Nearest,
+}
+
+fn percentile(
+    PercentileArgs {
+        column,
+        rest,
+        method,
+    }: PercentileArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let method = match &method {
+        None => Method::Linear,
+        Some(method) => match method.item.as_str() {
+            "linear" => Method::Linear,
+            "nearest" => Method::Nearest,
+            _ => {
+                return Err(ShellError::labeled_error(
+                    "Unknown method",
+                    "expected linear or nearest",
+                    method.tag(),
+                ))
+            }
+        },
+    };
+
+    let percentiles = rest
+        .iter()
+        .map(|requested| match value::as_decimal(requested) {
+            Some(p) if p >= BigDecimal::from(0) && p <= BigDecimal::from(100) => Ok(p),
+            Some(_) => Err(ShellError::labeled_error(
+                "Percentile out of range",
+                "expected a number from 0 to 100",
+                requested.tag(),
+            )),
+            None => Err(ShellError::labeled_error(
+                "Expected a number",
+                format!("found {} instead", requested.type_name()),
+                requested.tag(),
+            )),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let stream = async_stream! {
+        let rows: Vec<Value> = input.values.collect().await;
+        let mut numbers = Vec::with_capacity(rows.len());
+
+        for row in &rows {
+            let cell = match get_column_path(&column, row) {
+                Ok(cell) => cell,
+                Err(err) => {
+                    yield Err(err);
+                    return;
+                }
+            };
+
+            match value::as_decimal(&cell) {
+                Some(n) => numbers.push(n),
+                None => {
+                    yield Err(ShellError::labeled_error_with_secondary(
+                        "Expected a number",
+                        format!("found {} instead", cell.type_name()),
+                        cell.tag(),
+                        "row originates from here",
+                        row.tag(),
+                    ));
+                    return;
+                }
+            }
+        }
+
+        if numbers.is_empty() {
+            yield Err(ShellError::labeled_error(
+                "No numbers to compute percentiles of",
+                "the input is empty",
+                &name,
+            ));
+            return;
+        }
+
+        numbers.sort();
+
+        let mut computed = TaggedDictBuilder::new(&name);
+
+        for p in &percentiles {
+            let result = match method {
+                Method::Linear => linear(&numbers, p),
+                Method::Nearest => nearest(&numbers, p),
+            };
+
+            computed.insert_untagged(format!("p{}", p), value::decimal(result));
+        }
+
+        yield ReturnSuccess::value(computed.into_value());
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+/// Interpolates between the two values whose ranks surround the percentile. This is done
+/// in decimals rather than floats so that exact inputs give exact percentiles.
+pub(crate) fn linear(sorted: &[BigDecimal], p: &BigDecimal) -> BigDecimal {
+    let rank = p * BigDecimal::from((sorted.len() - 1) as u64) / BigDecimal::from(100);
+    let below = rank.with_scale(0);
+    let fraction = &rank - &below;
+    let below = below.to_usize().unwrap_or(0);
+
+    if fraction == BigDecimal::from(0) {
+        return sorted[below].clone();
+    }
+
+    &sorted[below] + (&sorted[below + 1] - &sorted[below]) * fraction
+}
+
+/// The smallest value with at least p percent of the values at or below it.
+fn nearest(sorted: &[BigDecimal], p: &BigDecimal) -> BigDecimal {
+    let rank = p * BigDecimal::from(sorted.len() as u64) / BigDecimal::from(100);
+    let mut index = rank.with_scale(0);
+
+    if index < rank {
+        index += BigDecimal::from(1);
+    }
+
+    sorted[index.to_usize().unwrap_or(1).max(1) - 1].clone()
+}
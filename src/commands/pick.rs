@@ -19,7 +19,8 @@ impl WholeStreamCommand for Pick {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("pick").rest(SyntaxShape::Any, "the columns to select from the table")
+        Signature::build("pick")
+            .required_rest(SyntaxShape::Any, "the columns to select from the table")
     }
 
     fn usage(&self) -> &str {
@@ -37,16 +38,8 @@ impl WholeStreamCommand for Pick {
 
 fn pick(
     PickArgs { rest: fields }: PickArgs,
-    RunnableContext { input, name, .. }: RunnableContext,
+    RunnableContext { input, .. }: RunnableContext,
 ) -> Result<OutputStream, ShellError> {
-    if fields.len() == 0 {
-        return Err(ShellError::labeled_error(
-            "Pick requires columns to pick",
-            "needs parameter",
-            name,
-        ));
-    }
-
     let fields: Vec<_> = fields.iter().map(|f| f.item.clone()).collect();
 
     let objects = input
@@ -0,0 +1,175 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::{value, TaggedDictBuilder};
+use crate::prelude::*;
+use indexmap::IndexMap;
+use nu_errors::ShellError;
+use nu_parser::Operator;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, Value};
+use nu_source::Tagged;
+
+pub struct PivotWide;
+
+#[derive(Deserialize)]
+pub struct PivotWideArgs {
+    index: Tagged<String>,
+    columns: Tagged<String>,
+    values: Tagged<String>,
+    agg: Option<Tagged<String>>,
+}
+
+impl WholeStreamCommand for PivotWide {
+    fn name(&self) -> &str {
+        "pivot-wide"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("pivot-wide")
+            .required_named(
+                "index",
+                SyntaxShape::String,
+                "the column whose values become the rows",
+            )
+            .required_named(
+                "columns",
+                SyntaxShape::String,
+                "the column whose values become the new column names",
+            )
+            .required_named(
+                "values",
+                SyntaxShape::String,
+                "the column holding the values to place in the new columns",
+            )
+            .named(
+                "agg",
+                SyntaxShape::String,
+                "how to combine repeated entries (only sum is supported)",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Reshapes a long table into a wide one with a row per index and a column per name."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, pivot_wide)?.run()
+    }
+}
+
+struct WideRow {
+    index: Value,
+    cells: IndexMap<String, Value>,
+}
+
+fn pivot_wide(
+    PivotWideArgs {
+        index,
+        columns,
+        values,
+        agg,
+    }: PivotWideArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let sum = match agg {
+        Some(agg) if agg.item == "sum" => true,
+        Some(agg) => {
+            return Err(ShellError::labeled_error(
+                "Unknown aggregation",
+                "only sum is supported",
+                agg.tag(),
+            ))
+        }
+        None => false,
+    };
+
+    let stream = async_stream! {
+        let rows: Vec<Value> = input.values.collect().await;
+
+        match reshape(&rows, &index, &columns, &values, sum) {
+            Ok((names, wide)) => {
+                for row in wide.values() {
+                    let mut out = TaggedDictBuilder::new(&name);
+                    out.insert_value(index.item.clone(), row.index.clone());
+
+                    for column in &names {
+                        match row.cells.get(column) {
+                            Some(cell) => out.insert_value(column.clone(), cell.clone()),
+                            None => out.insert_untagged(column.clone(), value::nothing()),
+                        }
+                    }
+
+                    yield ReturnSuccess::value(out.into_value());
+                }
+            }
+            Err(err) => yield Err(err),
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+fn reshape(
+    rows: &[Value],
+    index: &Tagged<String>,
+    columns: &Tagged<String>,
+    values: &Tagged<String>,
+    sum: bool,
+) -> Result<(Vec<String>, IndexMap<String, WideRow>), ShellError> {
+    let mut names: Vec<String> = vec![];
+    let mut wide: IndexMap<String, WideRow> = IndexMap::new();
+
+    for row in rows {
+        let index_value = value::cell_at(row, index)?;
+        let column = text(&value::cell_at(row, columns)?);
+        let cell_value = value::cell_at(row, values)?;
+
+        if !names.contains(&column) {
+            names.push(column.clone());
+        }
+
+        let entry = wide.entry(text(&index_value)).or_insert_with(|| WideRow {
+            index: index_value.clone(),
+            cells: IndexMap::new(),
+        });
+
+        let combined = match entry.cells.get(&column) {
+            None => cell_value,
+            Some(existing) if sum => {
+                match value::compute_values(&Operator::Plus, existing, &cell_value) {
+                    Ok(total) => total.into_value(cell_value.tag()),
+                    Err((left, right)) => {
+                        return Err(ShellError::labeled_error(
+                            "Could not sum the repeated entries",
+                            format!("cannot add {} and {}", left, right),
+                            cell_value.tag(),
+                        ))
+                    }
+                }
+            }
+            Some(_) => {
+                return Err(ShellError::labeled_error_with_secondary(
+                    "Repeated entry",
+                    format!(
+                        "{} already has a value for {} (use --agg sum to combine them)",
+                        text(&index_value),
+                        column
+                    ),
+                    cell_value.tag(),
+                    "row originates from here",
+                    row.tag(),
+                ))
+            }
+        };
+
+        entry.cells.insert(column, combined);
+    }
+
+    Ok((names, wide))
+}
+
+pub(crate) fn text(cell: &Value) -> String {
+    value::format_leaf(&cell.value).plain_string(std::usize::MAX)
+}
@@ -0,0 +1,107 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape};
+use nu_source::Tagged;
+use std::io::Write;
+use std::sync::atomic::Ordering;
+use std::time::{Duration, Instant};
+
+const BAR_WIDTH: usize = 30;
+const REPORT_INTERVAL: Duration = Duration::from_millis(100);
+
+pub struct Progress;
+
+#[derive(Deserialize)]
+pub struct ProgressArgs {
+    total: Option<Tagged<usize>>,
+}
+
+impl WholeStreamCommand for Progress {
+    fn name(&self) -> &str {
+        "progress"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("progress").named(
+            "total",
+            SyntaxShape::Int,
+            "how many values to expect, showing a bar and percentage",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Passes values through unchanged, counting them on stderr as they go by."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, progress)?.run()
+    }
+}
+
+fn progress(
+    ProgressArgs { total }: ProgressArgs,
+    RunnableContext {
+        input,
+        host,
+        ctrl_c,
+        ..
+    }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let total = total.map(|total| total.item);
+
+    let stream = async_stream! {
+        let mut values = input.values;
+        let mut count = 0;
+        let mut reported_at: Option<Instant> = None;
+
+        while let Some(value) = values.next().await {
+            if ctrl_c.load(Ordering::SeqCst) {
+                break;
+            }
+
+            count += 1;
+
+            // Redrawing on every value would slow down fast streams, so updates are spaced out.
+            if reported_at.map_or(true, |at| at.elapsed() >= REPORT_INTERVAL) {
+                report(&host, count, total, false);
+                reported_at = Some(Instant::now());
+            }
+
+            yield ReturnSuccess::value(value);
+        }
+
+        report(&host, count, total, true);
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+fn report(host: &Arc<Mutex<Box<dyn Host>>>, count: usize, total: Option<usize>, done: bool) {
+    let line = match total {
+        Some(total) if total > 0 => {
+            let fraction = (count as f64 / total as f64).min(1.0);
+            let filled = (fraction * BAR_WIDTH as f64) as usize;
+
+            format!(
+                "[{}{}] {}/{} ({:.0}%)",
+                "#".repeat(filled),
+                ".".repeat(BAR_WIDTH - filled),
+                count,
+                total,
+                fraction * 100.0
+            )
+        }
+        _ => format!("{} values", count),
+    };
+
+    if let Ok(host) = host.lock() {
+        let mut err = host.err_termcolor();
+        let _ = write!(err, "\r{}{}", line, if done { "\n" } else { "" });
+        let _ = err.flush();
+    }
+}
@@ -0,0 +1,254 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::{value, TaggedDictBuilder};
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+
+pub struct Query;
+
+#[derive(Deserialize)]
+pub struct QueryArgs {
+    path: Tagged<String>,
+    #[serde(rename(deserialize = "values-only"))]
+    values_only: bool,
+}
+
+impl WholeStreamCommand for Query {
+    fn name(&self) -> &str {
+        "query"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("query")
+            .required(
+                "path",
+                SyntaxShape::String,
+                "the path to match, eg \"$..name\" or \"authors.*\"",
+            )
+            .switch(
+                "values-only",
+                "return the matching values without their paths",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Finds every value matching a JSONPath-like query (with .. and * support)."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, query)?.run()
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Selector {
+    Key(String),
+    Index(usize),
+    Wildcard,
+}
+
+#[derive(Debug, PartialEq)]
+enum Step {
+    Child(Selector),
+    Descendant(Selector),
+}
+
+fn query(
+    QueryArgs { path, values_only }: QueryArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let steps = parse_query(&path.item).map_err(|reason| {
+        ShellError::labeled_error("Could not parse the query", reason, path.tag())
+    })?;
+
+    let stream = async_stream! {
+        let input: Vec<Value> = input.values.collect().await;
+
+        let root = if input.len() == 1 {
+            input[0].clone()
+        } else {
+            value::table(&input).into_value(&name)
+        };
+
+        for (path, found) in select(&root, &steps) {
+            if values_only {
+                yield ReturnSuccess::value(found.clone());
+            } else {
+                let mut row = TaggedDictBuilder::new(&name);
+                row.insert_untagged("path", value::string(path));
+                row.insert_value("value", found.clone());
+                yield ReturnSuccess::value(row.into_value());
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+fn parse_query(input: &str) -> Result<Vec<Step>, String> {
+    let mut steps = vec![];
+    let mut rest = input.trim().trim_start_matches('$');
+
+    while !rest.is_empty() {
+        let descendant = rest.starts_with("..");
+
+        let separator = if descendant {
+            2
+        } else if rest.starts_with('.') {
+            1
+        } else if steps.is_empty() || rest.starts_with('[') {
+            0
+        } else {
+            return Err(format!("expected . or [ before {}", rest));
+        };
+
+        rest = &rest[separator..];
+
+        let (selector, remaining) = if rest.starts_with('[') {
+            match rest.find(']') {
+                Some(end) => (parse_selector(&rest[1..end])?, &rest[end + 1..]),
+                None => return Err("missing closing ]".to_string()),
+            }
+        } else {
+            let end = rest.find(|c| c == '.' || c == '[').unwrap_or(rest.len());
+            (parse_selector(&rest[..end])?, &rest[end..])
+        };
+
+        steps.push(if descendant {
+            Step::Descendant(selector)
+        } else {
+            Step::Child(selector)
+        });
+
+        rest = remaining;
+    }
+
+    Ok(steps)
+}
+
+fn parse_selector(segment: &str) -> Result<Selector, String> {
+    let segment = segment.trim();
+
+    if segment.is_empty() {
+        return Err("empty path segment".to_string());
+    }
+
+    if segment == "*" {
+        return Ok(Selector::Wildcard);
+    }
+
+    if let Ok(index) = segment.parse::<usize>() {
+        return Ok(Selector::Index(index));
+    }
+
+    Ok(Selector::Key(
+        segment.trim_matches(|c| c == '\'' || c == '"').to_string(),
+    ))
+}
+
+fn select<'a>(root: &'a Value, steps: &[Step]) -> Vec<(String, &'a Value)> {
+    let mut current = vec![("$".to_string(), root)];
+
+    for step in steps {
+        let mut next = vec![];
+
+        for (path, value) in current {
+            match step {
+                Step::Child(selector) => matching_children(&path, value, selector, &mut next),
+                Step::Descendant(selector) => {
+                    for (path, value) in self_and_descendants(path, value) {
+                        matching_children(&path, value, selector, &mut next);
+                    }
+                }
+            }
+        }
+
+        current = next;
+    }
+
+    current
+}
+
+fn children(value: &Value) -> Vec<(String, &Value)> {
+    match &value.value {
+        UntaggedValue::Row(row) => row
+            .entries
+            .iter()
+            .map(|(key, value)| (key.clone(), value))
+            .collect(),
+        UntaggedValue::Table(rows) => rows
+            .iter()
+            .enumerate()
+            .map(|(idx, value)| (idx.to_string(), value))
+            .collect(),
+        _ => vec![],
+    }
+}
+
+fn matching_children<'a>(
+    path: &str,
+    value: &'a Value,
+    selector: &Selector,
+    found: &mut Vec<(String, &'a Value)>,
+) {
+    for (key, child) in children(value) {
+        let matches = match selector {
+            Selector::Wildcard => true,
+            Selector::Key(name) => *name == key,
+            Selector::Index(idx) => idx.to_string() == key,
+        };
+
+        if matches {
+            found.push((format!("{}.{}", path, key), child));
+        }
+    }
+}
+
+fn self_and_descendants(path: String, value: &Value) -> Vec<(String, &Value)> {
+    let mut all = vec![];
+
+    for (key, child) in children(value) {
+        all.extend(self_and_descendants(format!("{}.{}", path, key), child));
+    }
+
+    all.insert(0, (path, value));
+    all
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_query, Selector, Step};
+
+    #[test]
+    fn parses_children_wildcards_and_descendants() {
+        assert_eq!(
+            parse_query("$.authors[*]..name").unwrap(),
+            vec![
+                Step::Child(Selector::Key("authors".to_string())),
+                Step::Child(Selector::Wildcard),
+                Step::Descendant(Selector::Key("name".to_string())),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_indexes_without_a_leading_dollar() {
+        assert_eq!(
+            parse_query("authors.0").unwrap(),
+            vec![
+                Step::Child(Selector::Key("authors".to_string())),
+                Step::Child(Selector::Index(0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_empty_segments() {
+        assert!(parse_query("authors..").is_err());
+    }
+}
@@ -0,0 +1,77 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use language_reporting::Label;
+use nu_errors::ShellError;
+use nu_protocol::{Evaluate, ReturnSuccess, Scope, Signature, SyntaxShape, Value};
+
+pub struct Reduce;
+
+#[derive(Deserialize)]
+pub struct ReduceArgs {
+    initial: Value,
+    block: Evaluate,
+}
+
+impl WholeStreamCommand for Reduce {
+    fn name(&self) -> &str {
+        "reduce"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("reduce")
+            .required(
+                "initial",
+                SyntaxShape::Any,
+                "the value to start the accumulator with",
+            )
+            .required(
+                "block",
+                SyntaxShape::Block,
+                "the block giving the next accumulator (the accumulator is $acc and the row is $it)",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Folds the rows into a single value by running the block on the accumulator and each row."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, reduce)?.run()
+    }
+}
+
+fn reduce(
+    ReduceArgs { initial, block }: ReduceArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let stream = async_stream! {
+        let mut values = input.values;
+        let mut acc = initial;
+
+        while let Some(row) = values.next().await {
+            let span = row.tag.span;
+
+            let mut scope = Scope::new(row);
+            scope.vars.insert("acc".to_string(), acc);
+
+            match block.invoke(&scope) {
+                Ok(next) => acc = next,
+                Err(err) => {
+                    let diagnostic = err.to_diagnostic().with_label(
+                        Label::new_secondary(span).with_message("while reducing this row"),
+                    );
+                    yield Err(ShellError::diagnostic(diagnostic));
+                    return;
+                }
+            }
+        }
+
+        yield ReturnSuccess::value(acc);
+    };
+
+    Ok(stream.to_output_stream())
+}
@@ -18,7 +18,8 @@ impl WholeStreamCommand for Reject {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("reject").rest(SyntaxShape::Member, "the names of columns to remove")
+        Signature::build("reject")
+            .required_rest(SyntaxShape::Member, "the names of columns to remove")
     }
 
     fn usage(&self) -> &str {
@@ -36,16 +37,8 @@ impl WholeStreamCommand for Reject {
 
 fn reject(
     RejectArgs { rest: fields }: RejectArgs,
-    RunnableContext { input, name, .. }: RunnableContext,
+    RunnableContext { input, .. }: RunnableContext,
 ) -> Result<OutputStream, ShellError> {
-    if fields.len() == 0 {
-        return Err(ShellError::labeled_error(
-            "Reject requires fields",
-            "needs parameter",
-            name,
-        ));
-    }
-
     let fields: Vec<_> = fields.iter().map(|f| f.item.clone()).collect();
 
     let stream = input
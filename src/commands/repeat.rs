@@ -0,0 +1,68 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, Value};
+use nu_source::Tagged;
+use std::sync::atomic::Ordering;
+
+#[derive(Deserialize)]
+struct RepeatArgs {
+    count: Tagged<i64>,
+}
+
+pub struct Repeat;
+
+impl WholeStreamCommand for Repeat {
+    fn name(&self) -> &str {
+        "repeat"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("repeat").required(
+            "count",
+            SyntaxShape::Int,
+            "how many times to emit the input",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Emits the input, as a whole, the given number of times."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, repeat)?.run()
+    }
+}
+
+fn repeat(
+    RepeatArgs { count }: RepeatArgs,
+    RunnableContext { input, ctrl_c, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    if count.item < 0 {
+        return Err(ShellError::labeled_error(
+            "Count can't be negative",
+            "negative count",
+            count.tag(),
+        ));
+    }
+
+    let stream = async_stream! {
+        let values: Vec<Value> = input.into_vec().await;
+
+        for _ in 0..count.item {
+            if ctrl_c.load(Ordering::SeqCst) {
+                break;
+            }
+
+            for value in values.iter() {
+                yield ReturnSuccess::value(value.clone());
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
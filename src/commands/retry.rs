@@ -0,0 +1,241 @@
+use crate::commands::classified::block::{block_source, run_to_end};
+use crate::commands::WholeStreamCommand;
+use crate::data::value::duration_seconds;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, Value};
+use nu_source::Tagged;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+#[derive(Deserialize)]
+struct RetryArgs {
+    times: Option<Tagged<usize>>,
+    delay: Option<Value>,
+}
+
+pub struct Retry;
+
+impl WholeStreamCommand for Retry {
+    fn name(&self) -> &str {
+        "retry"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("retry")
+            .required(
+                "block",
+                SyntaxShape::Block,
+                "the pipeline to run for each value, with the value as its input (eg { open $it })",
+            )
+            .named(
+                "times",
+                SyntaxShape::Int,
+                "how many attempts to make in total (defaults to 3)",
+            )
+            .named(
+                "delay",
+                SyntaxShape::Any,
+                "how long to wait between attempts (like 2s; defaults to no wait)",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Runs the block's pipeline on each value, running it again while it fails."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        Ok(args.process_raw(registry, retry)?.run())
+    }
+}
+
+fn retry(
+    RetryArgs { times, delay }: RetryArgs,
+    RunnableContext {
+        input,
+        shell_manager,
+        host,
+        ctrl_c,
+        commands,
+        ..
+    }: RunnableContext,
+    raw_args: RawCommandArgs,
+) -> Result<OutputStream, ShellError> {
+    let source = block_source(&raw_args.call_info)?;
+    let times = times.map(|times| times.item).unwrap_or(3).max(1);
+    let delay = match &delay {
+        Some(delay) => Duration::from_secs(duration_seconds(delay)?),
+        None => Duration::from_secs(0),
+    };
+
+    let mut values = input.values;
+
+    let stream = async_stream! {
+        while let Some(row) = values.next().await {
+            let result = attempt(times, delay, &ctrl_c, || {
+                // Each attempt gets a fresh context so errors of the last one don't linger,
+                // sharing ctrl_c so that the block stops along with the rest of the pipeline.
+                let mut context = Context::nested(
+                    commands.clone(),
+                    host.clone(),
+                    shell_manager.clone(),
+                );
+                context.ctrl_c = ctrl_c.clone();
+
+                let source = source.clone();
+                let row = row.clone();
+
+                async move { run_to_end(&source, &mut context, row).await }
+            })
+            .await;
+
+            match result {
+                Ok(output) => {
+                    for value in output {
+                        yield ReturnSuccess::value(value);
+                    }
+                }
+                Err(err) => {
+                    yield Err(err);
+                    return;
+                }
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+/// Calls `action` until it succeeds or has been tried `times` times, waiting for `delay`
+/// in between. The last error is passed on, noting how many attempts were made. Pressing
+/// ctrl-c while waiting gives up early.
+async fn attempt<T, F>(
+    times: usize,
+    delay: Duration,
+    ctrl_c: &AtomicBool,
+    mut action: impl FnMut() -> F,
+) -> Result<T, ShellError>
+where
+    F: Future<Output = Result<T, ShellError>>,
+{
+    let mut attempts = 0;
+
+    loop {
+        attempts += 1;
+
+        match action().await {
+            Ok(value) => return Ok(value),
+            Err(err) if attempts >= times || !pause(delay, ctrl_c).await => {
+                let mut diagnostic = err.to_diagnostic();
+                diagnostic.message = format!(
+                    "{} (gave up after {} attempts)",
+                    diagnostic.message, attempts
+                );
+                return Err(ShellError::diagnostic(diagnostic));
+            }
+            Err(_) => {}
+        }
+    }
+}
+
+/// Waits for `delay` without holding up the thread, checking for ctrl-c along the way.
+/// Gives back false if it was pressed.
+async fn pause(delay: Duration, ctrl_c: &AtomicBool) -> bool {
+    let until = Instant::now() + delay;
+
+    loop {
+        if ctrl_c.load(Ordering::SeqCst) {
+            return false;
+        }
+
+        let remaining = until.saturating_duration_since(Instant::now());
+
+        if remaining == Duration::from_secs(0) {
+            return true;
+        }
+
+        futures_timer::Delay::new(remaining.min(Duration::from_millis(100))).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::attempt;
+    use futures::executor::block_on;
+    use futures::future::ready;
+    use nu_errors::ShellError;
+    use std::sync::atomic::AtomicBool;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn stops_at_the_first_success() {
+        let mut calls = 0;
+
+        let result = block_on(attempt(
+            3,
+            Duration::from_secs(0),
+            &AtomicBool::new(false),
+            || {
+                calls += 1;
+                if calls < 2 {
+                    ready(Err(ShellError::untagged_runtime_error("flaky")))
+                } else {
+                    ready(Ok(calls))
+                }
+            },
+        ));
+
+        assert_eq!(result.unwrap(), 2);
+        assert_eq!(calls, 2);
+    }
+
+    #[test]
+    fn notes_the_attempts_when_giving_up() {
+        let mut calls = 0;
+
+        let result: Result<(), ShellError> = block_on(attempt(
+            3,
+            Duration::from_secs(0),
+            &AtomicBool::new(false),
+            || {
+                calls += 1;
+                ready(Err(ShellError::untagged_runtime_error("broken")))
+            },
+        ));
+
+        assert_eq!(calls, 3);
+        assert!(result
+            .unwrap_err()
+            .to_diagnostic()
+            .message
+            .contains("gave up after 3 attempts"));
+    }
+
+    #[test]
+    fn gives_up_instead_of_waiting_once_ctrl_c_is_pressed() {
+        let mut calls = 0;
+        let started = Instant::now();
+
+        let result: Result<(), ShellError> = block_on(attempt(
+            3,
+            Duration::from_secs(60),
+            &AtomicBool::new(true),
+            || {
+                calls += 1;
+                ready(Err(ShellError::untagged_runtime_error("broken")))
+            },
+        ));
+
+        assert_eq!(calls, 1);
+        assert!(started.elapsed() < Duration::from_secs(10));
+        assert!(result
+            .unwrap_err()
+            .to_diagnostic()
+            .message
+            .contains("gave up after 1 attempts"));
+    }
+}
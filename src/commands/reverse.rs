@@ -1,11 +1,24 @@
+use crate::commands::get::get_column_path;
 use crate::commands::WholeStreamCommand;
 use crate::context::CommandRegistry;
+use crate::data::value;
 use crate::prelude::*;
 use nu_errors::ShellError;
-use nu_protocol::Signature;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue,
+    Value,
+};
+use unicode_segmentation::UnicodeSegmentation;
 
 pub struct Reverse;
 
+#[derive(Deserialize)]
+pub struct ReverseArgs {
+    column: Option<ColumnPath>,
+    rows: bool,
+    string: bool,
+}
+
 impl WholeStreamCommand for Reverse {
     fn name(&self) -> &str {
         "reverse"
@@ -13,10 +26,17 @@ impl WholeStreamCommand for Reverse {
 
     fn signature(&self) -> Signature {
         Signature::build("reverse")
+            .optional(
+                "column",
+                SyntaxShape::ColumnPath,
+                "the column holding the strings to reverse",
+            )
+            .switch("rows", "reverse the order of the rows (the default)")
+            .switch("string", "reverse the characters of each string instead")
     }
 
     fn usage(&self) -> &str {
-        "Reverses the table."
+        "Reverses the table, or the strings in the given column."
     }
 
     fn run(
@@ -24,20 +44,90 @@ impl WholeStreamCommand for Reverse {
         args: CommandArgs,
         registry: &CommandRegistry,
     ) -> Result<OutputStream, ShellError> {
-        reverse(args, registry)
+        args.process(registry, reverse)?.run()
     }
 }
 
-fn reverse(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
-    let args = args.evaluate_once(registry)?;
-    let (input, _args) = args.parts();
+fn reverse(
+    ReverseArgs {
+        column,
+        rows,
+        string,
+    }: ReverseArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    if rows && (string || column.is_some()) {
+        return Err(ShellError::labeled_error(
+            "Conflicting reverse flags",
+            "--rows cannot be combined with --string or a column",
+            name,
+        ));
+    }
 
-    let output = input.values.collect::<Vec<_>>();
+    if !string && column.is_none() {
+        let output = input.values.collect::<Vec<_>>();
 
-    let output = output.map(move |mut vec| {
-        vec.reverse();
-        vec.into_iter().collect::<VecDeque<_>>()
-    });
+        let output = output.map(move |mut vec| {
+            vec.reverse();
+            vec.into_iter().collect::<VecDeque<_>>()
+        });
+
+        return Ok(output.flatten_stream().from_input_stream());
+    }
 
-    Ok(output.flatten_stream().from_input_stream())
+    Ok(input
+        .values
+        .map(move |row| match &column {
+            Some(column) => {
+                let cell = get_column_path(column, &row)?;
+                let reversed = reverse_value(&cell)?;
+
+                match row.replace_data_at_column_path(column, reversed) {
+                    Some(row) => ReturnSuccess::value(row),
+                    None => Err(ShellError::labeled_error(
+                        "reverse could not find the column",
+                        "column name",
+                        &name,
+                    )),
+                }
+            }
+            None => ReturnSuccess::value(reverse_value(&row)?),
+        })
+        .to_output_stream())
+}
+
+fn reverse_value(cell: &Value) -> Result<Value, ShellError> {
+    match &cell.value {
+        UntaggedValue::Primitive(Primitive::String(s)) => {
+            Ok(value::string(reverse_graphemes(s)).into_value(cell.tag()))
+        }
+        UntaggedValue::Primitive(Primitive::Line(s)) => {
+            Ok(value::line(reverse_graphemes(s)).into_value(cell.tag()))
+        }
+        _ => Err(ShellError::labeled_error(
+            "Expected a string",
+            format!("found {} instead", cell.type_name()),
+            cell.tag(),
+        )),
+    }
+}
+
+fn reverse_graphemes(s: &str) -> String {
+    s.graphemes(true).rev().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::reverse_graphemes;
+
+    #[test]
+    fn reverses_ascii() {
+        assert_eq!(reverse_graphemes("nushell"), "llehsun");
+    }
+
+    #[test]
+    fn keeps_combining_characters_with_their_base() {
+        assert_eq!(reverse_graphemes("a\u{0303}b"), "ba\u{0303}");
+        assert_eq!(reverse_graphemes("日本語"), "語本日");
+    }
 }
@@ -0,0 +1,170 @@
+use crate::commands::get::get_column_path;
+use crate::commands::WholeStreamCommand;
+use crate::data::value;
+use crate::prelude::*;
+use bigdecimal::BigDecimal;
+use nu_errors::ShellError;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue,
+};
+use nu_source::Tagged;
+use num_traits::{One, Signed, Zero};
+
+pub struct Round;
+
+#[derive(Deserialize)]
+pub struct RoundArgs {
+    column: ColumnPath,
+    to: Option<Tagged<i64>>,
+    mode: Option<Tagged<String>>,
+}
+
+impl WholeStreamCommand for Round {
+    fn name(&self) -> &str {
+        "round"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("round")
+            .required(
+                "column",
+                SyntaxShape::ColumnPath,
+                "the column holding the numbers to round",
+            )
+            .named(
+                "to",
+                SyntaxShape::Int,
+                "how many decimal places to keep (defaults to 0), where -1 rounds to tens and so on",
+            )
+            .named(
+                "mode",
+                SyntaxShape::String,
+                "round (the default, halves away from zero), floor, ceil or trunc",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Rounds the numbers in a column to a number of decimal places."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, round)?.run()
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Mode {
+    Round,
+    Floor,
+    Ceil,
+    Trunc,
+}
+
+fn round(
+    RoundArgs { column, to, mode }: RoundArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let places = to.map(|to| to.item).unwrap_or(0);
+
+    let mode = match &mode {
+        None => Mode::Round,
+        Some(mode) => match mode.item.as_str() {
+            "round" => Mode::Round,
+            "floor" => Mode::Floor,
+            "ceil" => Mode::Ceil,
+            "trunc" => Mode::Trunc,
+            _ => {
+                return Err(ShellError::labeled_error(
+                    "Unknown mode",
+                    "expected round, floor, ceil or trunc",
+                    mode.tag(),
+                ))
+            }
+        },
+    };
+
+    Ok(input
+        .values
+        .map(move |row| {
+            let cell = get_column_path(&column, &row)?;
+
+            let rounded = match &cell.value {
+                UntaggedValue::Primitive(Primitive::Int(i)) if places >= 0 => value::int(i.clone()),
+                UntaggedValue::Primitive(Primitive::Int(i)) => {
+                    value::int(round_digits(i, 0, places, mode))
+                }
+                UntaggedValue::Primitive(Primitive::Decimal(d)) => {
+                    let (digits, scale) = d.as_bigint_and_exponent();
+                    let rounded = round_digits(&digits, scale, places, mode);
+
+                    value::decimal(BigDecimal::new(rounded, places.max(0)))
+                }
+                _ => {
+                    return Err(ShellError::labeled_error_with_secondary(
+                        "Expected a number",
+                        format!("found {} instead", cell.type_name()),
+                        cell.tag(),
+                        "row originates from here",
+                        row.tag(),
+                    ))
+                }
+            };
+
+            match row.replace_data_at_column_path(&column, rounded.into_value(cell.tag())) {
+                Some(row) => ReturnSuccess::value(row),
+                None => Err(ShellError::labeled_error(
+                    "round could not find the column",
+                    "column name",
+                    &name,
+                )),
+            }
+        })
+        .to_output_stream())
+}
+
+/// Rounds `digits * 10^-scale` to the given places, returning the digits of the result with a
+/// scale of `places`, or of 0 when rounding to tens and beyond.
+fn round_digits(digits: &BigInt, scale: i64, places: i64, mode: Mode) -> BigInt {
+    let ten = BigInt::from(10);
+
+    if scale <= places {
+        return if places >= 0 {
+            digits * num_traits::pow(ten, (places - scale) as usize)
+        } else {
+            digits * num_traits::pow(ten, (-scale).max(0) as usize)
+        };
+    }
+
+    let factor = num_traits::pow(ten.clone(), (scale - places) as usize);
+
+    // Division truncates towards zero, leaving a remainder with the sign of the digits.
+    let mut quotient = digits / &factor;
+    let remainder = digits % &factor;
+
+    if !remainder.is_zero() {
+        let away_from_zero = match mode {
+            Mode::Trunc => false,
+            Mode::Floor => digits.is_negative(),
+            Mode::Ceil => digits.is_positive(),
+            Mode::Round => remainder.abs() * 2 >= factor,
+        };
+
+        if away_from_zero {
+            quotient = if digits.is_negative() {
+                quotient - BigInt::one()
+            } else {
+                quotient + BigInt::one()
+            };
+        }
+    }
+
+    if places < 0 {
+        quotient * num_traits::pow(ten, (-places) as usize)
+    } else {
+        quotient
+    }
+}
@@ -0,0 +1,100 @@
+use crate::commands::diff::{changed_columns, other_table};
+use crate::commands::WholeStreamCommand;
+use crate::data::{value, TaggedDictBuilder};
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Evaluate, ReturnSuccess, Signature, SyntaxShape, Value};
+
+pub struct RowDiff;
+
+#[derive(Deserialize)]
+pub struct RowDiffArgs {
+    other: Option<Evaluate>,
+}
+
+impl WholeStreamCommand for RowDiff {
+    fn name(&self) -> &str {
+        "row-diff"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("row-diff").optional(
+            "other",
+            SyntaxShape::Block,
+            "the block giving the row to compare against (without it, the input's two rows are compared)",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Lists the columns whose values differ between two rows."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, row_diff)?.run()
+    }
+}
+
+fn row_diff(
+    RowDiffArgs { other }: RowDiffArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let stream = async_stream! {
+        let rows: Vec<Value> = input.values.collect().await;
+
+        let (left, right) = match pair(rows, &other, &name) {
+            Ok(pair) => pair,
+            Err(err) => {
+                yield Err(err);
+                return;
+            }
+        };
+
+        for (column, old, new) in changed_columns(&left, &right) {
+            let mut change = TaggedDictBuilder::new(&name);
+            change.insert_untagged("column", value::string(column));
+            change.insert_value("left", old);
+            change.insert_value("right", new);
+
+            yield ReturnSuccess::value(change.into_value());
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+/// The two rows to compare: the input row and the block's, or the input's two rows.
+fn pair(
+    mut rows: Vec<Value>,
+    other: &Option<Evaluate>,
+    name: &Tag,
+) -> Result<(Value, Value), ShellError> {
+    match other {
+        Some(other) => {
+            let mut others = other_table(other, &rows, name)?;
+
+            match (rows.len(), others.len()) {
+                (1, 1) => Ok((rows.remove(0), others.remove(0))),
+                (left, right) => Err(ShellError::labeled_error(
+                    "Expected a single row on each side",
+                    format!("found {} input rows and {} from the block", left, right),
+                    name,
+                )),
+            }
+        }
+        None => match rows.len() {
+            2 => {
+                let right = rows.remove(1);
+                Ok((rows.remove(0), right))
+            }
+            count => Err(ShellError::labeled_error(
+                "Expected two rows",
+                format!("found {} input rows", count),
+                name,
+            )),
+        },
+    }
+}
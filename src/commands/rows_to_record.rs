@@ -0,0 +1,110 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::{value, TaggedDictBuilder};
+use crate::prelude::*;
+use indexmap::IndexMap;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, Value};
+use nu_source::Tagged;
+
+pub struct RowsToRecord;
+
+#[derive(Deserialize)]
+pub struct RowsToRecordArgs {
+    key: Tagged<String>,
+    value: Tagged<String>,
+    collect: bool,
+}
+
+impl WholeStreamCommand for RowsToRecord {
+    fn name(&self) -> &str {
+        "rows-to-record"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("rows-to-record")
+            .required_named(
+                "key",
+                SyntaxShape::String,
+                "the column holding the names of the new columns",
+            )
+            .required_named(
+                "value",
+                SyntaxShape::String,
+                "the column holding the values of the new columns",
+            )
+            .switch(
+                "collect",
+                "gather the values of repeated keys into a table instead of failing",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Turns a table of key/value rows into a single row."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, rows_to_record)?.run()
+    }
+}
+
+fn rows_to_record(
+    RowsToRecordArgs {
+        key,
+        value: value_column,
+        collect,
+    }: RowsToRecordArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let stream = async_stream! {
+        let rows: Vec<Value> = input.values.collect().await;
+
+        let mut entries: IndexMap<String, Vec<Value>> = IndexMap::new();
+
+        for row in &rows {
+            let key_cell = value::cell_at(row, &key);
+            let value_cell = value::cell_at(row, &value_column);
+
+            let (key_cell, value_cell) = match (key_cell, value_cell) {
+                (Ok(key_cell), Ok(value_cell)) => (key_cell, value_cell),
+                (Err(err), _) | (_, Err(err)) => {
+                    yield Err(err);
+                    return;
+                }
+            };
+
+            let column = value::format_leaf(&key_cell.value).plain_string(std::usize::MAX);
+            let values = entries.entry(column.clone()).or_insert_with(Vec::new);
+
+            if !values.is_empty() && !collect {
+                yield Err(ShellError::labeled_error_with_secondary(
+                    "Repeated key",
+                    format!("{} appears more than once (use --collect to gather them)", column),
+                    key_cell.tag(),
+                    "row originates from here",
+                    row.tag(),
+                ));
+                return;
+            }
+
+            values.push(value_cell);
+        }
+
+        let mut record = TaggedDictBuilder::new(&name);
+
+        for (column, mut values) in entries {
+            if values.len() == 1 {
+                record.insert_value(column, values.remove(0));
+            } else {
+                record.insert_untagged(column, value::table(&values));
+            }
+        }
+
+        yield ReturnSuccess::value(record.into_value());
+    };
+
+    Ok(stream.to_output_stream())
+}
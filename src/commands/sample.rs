@@ -0,0 +1,81 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, Value};
+use nu_source::Tagged;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+#[derive(Deserialize)]
+struct SampleArgs {
+    count: Tagged<usize>,
+    seed: Option<Tagged<u64>>,
+}
+
+pub struct Sample;
+
+impl WholeStreamCommand for Sample {
+    fn name(&self) -> &str {
+        "sample"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("sample")
+            .required("count", SyntaxShape::Int, "how many rows to pick")
+            .named(
+                "seed",
+                SyntaxShape::Int,
+                "seed the random choice so the same rows are picked every time",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Picks the given number of distinct rows at random."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, sample)?.run()
+    }
+}
+
+fn sample(
+    SampleArgs { count, seed }: SampleArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let mut rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed.item),
+        None => StdRng::from_entropy(),
+    };
+
+    let stream = async_stream! {
+        let mut values = input.values;
+        let mut reservoir: Vec<Value> = vec![];
+        let mut seen = 0;
+
+        // Reservoir sampling: the n-th row replaces a kept one with a chance of count/n, which
+        // leaves every row equally likely to be kept without holding on to the whole input.
+        while let Some(value) = values.next().await {
+            seen += 1;
+
+            if reservoir.len() < count.item {
+                reservoir.push(value);
+            } else {
+                let index = rng.gen_range(0, seen);
+
+                if index < count.item {
+                    reservoir[index] = value;
+                }
+            }
+        }
+
+        for value in reservoir {
+            yield ReturnSuccess::value(value);
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
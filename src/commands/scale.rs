@@ -0,0 +1,133 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::value;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+
+pub struct Scale;
+
+#[derive(Deserialize)]
+pub struct ScaleArgs {
+    rest: Vec<Tagged<String>>,
+    method: Option<Tagged<String>>,
+}
+
+impl WholeStreamCommand for Scale {
+    fn name(&self) -> &str {
+        "scale"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("scale")
+            .required_rest(SyntaxShape::String, "the numeric columns to rescale")
+            .named(
+                "method",
+                SyntaxShape::String,
+                "minmax (the default) to rescale from 0 to 1, or zscore for a mean of 0 and a standard deviation of 1",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Rescales numeric columns from 0 to 1, or to their z-scores."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, scale)?.run()
+    }
+}
+
+enum Method {
+    MinMax,
+    ZScore,
+}
+
+impl Method {
+    /// The offset and divisor turning a number into its scaled value.
+    fn parameters(&self, numbers: &[f64]) -> (f64, f64) {
+        match self {
+            Method::MinMax => {
+                let min = numbers.iter().cloned().fold(std::f64::INFINITY, f64::min);
+                let max = numbers
+                    .iter()
+                    .cloned()
+                    .fold(std::f64::NEG_INFINITY, f64::max);
+
+                (min, max - min)
+            }
+            Method::ZScore => {
+                let count = numbers.len() as f64;
+                let mean = numbers.iter().sum::<f64>() / count;
+                let variance = numbers.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / count;
+
+                (mean, variance.sqrt())
+            }
+        }
+    }
+}
+
+fn scale(
+    ScaleArgs { rest, method }: ScaleArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let method = match &method {
+        None => Method::MinMax,
+        Some(method) => match method.item.as_str() {
+            "minmax" => Method::MinMax,
+            "zscore" => Method::ZScore,
+            _ => {
+                return Err(ShellError::labeled_error(
+                    "Unknown method",
+                    "expected minmax or zscore",
+                    method.tag(),
+                ))
+            }
+        },
+    };
+
+    let stream = async_stream! {
+        let mut rows: Vec<Value> = input.values.collect().await;
+
+        for column in &rest {
+            let mut numbers = Vec::with_capacity(rows.len());
+
+            for row in &rows {
+                match value::number_at(row, column) {
+                    Ok(n) => numbers.push(n),
+                    Err(err) => {
+                        yield Err(err);
+                        return;
+                    }
+                }
+            }
+
+            let (offset, divisor) = method.parameters(&numbers);
+
+            for (row, number) in rows.iter_mut().zip(numbers) {
+                // A constant column has nothing to spread out, so every value scales to 0.
+                let scaled = if divisor == 0.0 {
+                    0.0
+                } else {
+                    (number - offset) / divisor
+                };
+
+                let scaled =
+                    UntaggedValue::Primitive(Primitive::from(scaled)).into_value(row.tag());
+
+                if let Some(scaled_row) = row.insert_data_at_path(&column.item, scaled) {
+                    *row = scaled_row;
+                }
+            }
+        }
+
+        for row in rows {
+            yield ReturnSuccess::value(row);
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
@@ -0,0 +1,114 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::base::shape::TypeShape;
+use crate::data::{value, TaggedDictBuilder};
+use crate::prelude::*;
+use indexmap::IndexMap;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, UntaggedValue, Value};
+use nu_source::PrettyDebug;
+
+pub struct Schema;
+
+#[derive(Deserialize)]
+pub struct SchemaArgs {}
+
+impl WholeStreamCommand for Schema {
+    fn name(&self) -> &str {
+        "schema"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("schema")
+    }
+
+    fn usage(&self) -> &str {
+        "Describes each column of the table with its name and type, joining the types seen in it."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, schema)?.run()
+    }
+}
+
+fn schema(
+    SchemaArgs {}: SchemaArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let stream = async_stream! {
+        let rows: Vec<Value> = input.values.collect().await;
+
+        for (column, ty) in column_types(&rows) {
+            let mut described = TaggedDictBuilder::new(&name);
+            described.insert_untagged("name", value::string(column));
+            described.insert_untagged("type", value::string(ty));
+
+            yield ReturnSuccess::value(described.into_value());
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+/// The type of each column in the order the columns are first seen, joining the types of its
+/// cells (eg `integer | string`). Rows missing a column count as nothing for it.
+pub(crate) fn column_types(rows: &[Value]) -> IndexMap<String, String> {
+    let mut columns: IndexMap<String, Vec<TypeShape>> = IndexMap::new();
+
+    for (index, row) in rows.iter().enumerate() {
+        let cells: Vec<(String, &UntaggedValue)> = match &row.value {
+            UntaggedValue::Row(dict) => dict
+                .entries
+                .iter()
+                .map(|(column, cell)| (column.clone(), &cell.value))
+                .collect(),
+            other => vec![("<value>".to_string(), other)],
+        };
+
+        // A column first seen after some rows was missing from them.
+        for (column, cell) in cells {
+            let shapes = columns.entry(column).or_insert_with(|| {
+                if index > 0 {
+                    vec![TypeShape::Nothing]
+                } else {
+                    vec![]
+                }
+            });
+
+            add_shape(shapes, TypeShape::from_value(cell));
+        }
+
+        for (column, shapes) in columns.iter_mut() {
+            let present = match &row.value {
+                UntaggedValue::Row(dict) => dict.entries.contains_key(column),
+                _ => column == "<value>",
+            };
+
+            if !present {
+                add_shape(shapes, TypeShape::Nothing);
+            }
+        }
+    }
+
+    columns
+        .into_iter()
+        .map(|(column, shapes)| {
+            let ty = shapes
+                .iter()
+                .map(|shape| shape.plain_string(100_000))
+                .collect::<Vec<_>>()
+                .join(" | ");
+
+            (column, ty)
+        })
+        .collect()
+}
+
+fn add_shape(shapes: &mut Vec<TypeShape>, shape: TypeShape) {
+    if !shapes.contains(&shape) {
+        shapes.push(shape);
+    }
+}
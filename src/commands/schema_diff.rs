@@ -0,0 +1,99 @@
+use crate::commands::diff::other_table;
+use crate::commands::schema::column_types;
+use crate::commands::WholeStreamCommand;
+use crate::data::{value, TaggedDictBuilder};
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Evaluate, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+
+pub struct SchemaDiff;
+
+#[derive(Deserialize)]
+pub struct SchemaDiffArgs {
+    other: Evaluate,
+}
+
+impl WholeStreamCommand for SchemaDiff {
+    fn name(&self) -> &str {
+        "schema-diff"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("schema-diff").required(
+            "other",
+            SyntaxShape::Block,
+            "the block giving the table to compare against (the input table is $it)",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Shows the columns added, removed and retyped between the input table and another table."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, schema_diff)?.run()
+    }
+}
+
+fn schema_diff(
+    SchemaDiffArgs { other }: SchemaDiffArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let stream = async_stream! {
+        let left: Vec<Value> = input.values.collect().await;
+
+        let right = match other_table(&other, &left, &name) {
+            Ok(right) => right,
+            Err(err) => {
+                yield Err(err);
+                return;
+            }
+        };
+
+        let old = column_types(&left);
+        let new = column_types(&right);
+
+        for (column, old_type) in old.iter() {
+            let kind = match new.get(column) {
+                None => "removed",
+                Some(new_type) if new_type != old_type => "retyped",
+                Some(_) => continue,
+            };
+
+            yield ReturnSuccess::value(
+                change(column, kind, Some(old_type), new.get(column), &name),
+            );
+        }
+
+        for (column, new_type) in new.iter() {
+            if !old.contains_key(column) {
+                yield ReturnSuccess::value(change(column, "added", None, Some(new_type), &name));
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+fn change(
+    column: &str,
+    kind: &str,
+    old: Option<&String>,
+    new: Option<&String>,
+    tag: &Tag,
+) -> Value {
+    let mut change = TaggedDictBuilder::new(tag);
+    change.insert_untagged("column", value::string(column));
+    change.insert_untagged("change", value::string(kind));
+    change.insert_untagged("old", or_nothing(old));
+    change.insert_untagged("new", or_nothing(new));
+    change.into_value()
+}
+
+fn or_nothing(ty: Option<&String>) -> UntaggedValue {
+    ty.map(value::string).unwrap_or_else(value::nothing)
+}
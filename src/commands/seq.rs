@@ -0,0 +1,196 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::value;
+use crate::prelude::*;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, NaiveDate, Utc};
+use nu_errors::ShellError;
+use nu_protocol::{
+    Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use num_bigint::BigInt;
+use num_traits::Zero;
+
+#[derive(Deserialize)]
+struct SeqArgs {
+    start: Value,
+    end: Value,
+    step: Option<Value>,
+    dates: bool,
+}
+
+pub struct Seq;
+
+impl WholeStreamCommand for Seq {
+    fn name(&self) -> &str {
+        "seq"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("seq")
+            .required("start", SyntaxShape::Any, "the first value")
+            .required(
+                "end",
+                SyntaxShape::Any,
+                "the last value (included if reached)",
+            )
+            .optional(
+                "step",
+                SyntaxShape::Any,
+                "the difference between values (defaults to 1, or 1d for dates)",
+            )
+            .switch(
+                "dates",
+                "count dates (like 2019-10-01) instead of numbers, stepping by a duration",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Generates the numbers or dates from start to end."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, seq)?.run()
+    }
+}
+
+fn seq(
+    SeqArgs {
+        start,
+        end,
+        step,
+        dates,
+    }: SeqArgs,
+    RunnableContext { name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    if dates {
+        let step_seconds = match &step {
+            Some(step) => value::duration_seconds(step)?,
+            None => 24 * 60 * 60,
+        };
+
+        if step_seconds == 0 {
+            return Err(zero_step(step.as_ref(), &name));
+        }
+
+        let step = chrono::Duration::seconds(step_seconds as i64);
+        let values = steps(as_date(&start)?, as_date(&end)?, true, move |date| {
+            *date + step
+        })
+        .map(move |date| {
+            ReturnSuccess::value(UntaggedValue::Primitive(Primitive::Date(date)).into_value(&name))
+        });
+
+        return Ok(futures::stream::iter(values).to_output_stream());
+    }
+
+    let default_step = value::int(1).into_value(&name);
+    let step_value = step.as_ref().unwrap_or(&default_step);
+
+    match (&start.value, &end.value, &step_value.value) {
+        (
+            UntaggedValue::Primitive(Primitive::Int(start)),
+            UntaggedValue::Primitive(Primitive::Int(end)),
+            UntaggedValue::Primitive(Primitive::Int(step_by)),
+        ) => {
+            if step_by.is_zero() {
+                return Err(zero_step(step.as_ref(), &name));
+            }
+
+            let step_by = step_by.clone();
+            let values = steps(
+                start.clone(),
+                end.clone(),
+                step_by > BigInt::zero(),
+                move |n| n + &step_by,
+            )
+            .map(move |n| ReturnSuccess::value(value::int(n).into_value(&name)));
+
+            Ok(futures::stream::iter(values).to_output_stream())
+        }
+        _ => {
+            let step_by = as_decimal(step_value)?;
+
+            if step_by.is_zero() {
+                return Err(zero_step(step.as_ref(), &name));
+            }
+
+            let ascending = step_by > BigDecimal::zero();
+            let values = steps(
+                as_decimal(&start)?,
+                as_decimal(&end)?,
+                ascending,
+                move |n| n + &step_by,
+            )
+            .map(move |n| ReturnSuccess::value(value::decimal(n).into_value(&name)));
+
+            Ok(futures::stream::iter(values).to_output_stream())
+        }
+    }
+}
+
+/// Counts from `start` until passing `end`, which is empty when `start` is already past it.
+fn steps<T: Clone + PartialOrd>(
+    start: T,
+    end: T,
+    ascending: bool,
+    next: impl Fn(&T) -> T,
+) -> impl Iterator<Item = T> {
+    std::iter::successors(Some(start), move |current| Some(next(current))).take_while(
+        move |current| {
+            if ascending {
+                *current <= end
+            } else {
+                *current >= end
+            }
+        },
+    )
+}
+
+fn as_decimal(number: &Value) -> Result<BigDecimal, ShellError> {
+    match &number.value {
+        UntaggedValue::Primitive(Primitive::Int(n)) => Ok(BigDecimal::from(n.clone())),
+        UntaggedValue::Primitive(Primitive::Decimal(n)) => Ok(n.clone()),
+        _ => Err(ShellError::labeled_error(
+            "Expected a number",
+            format!("found {} instead", number.type_name()),
+            number.tag(),
+        )),
+    }
+}
+
+/// Dates are taken as RFC 3339 timestamps, or plain YYYY-MM-DD days starting at midnight UTC.
+fn as_date(date: &Value) -> Result<DateTime<Utc>, ShellError> {
+    let parsed = match &date.value {
+        UntaggedValue::Primitive(Primitive::Date(date)) => Some(*date),
+        UntaggedValue::Primitive(Primitive::String(text)) => DateTime::parse_from_rfc3339(text)
+            .map(|date| date.with_timezone(&Utc))
+            .ok()
+            .or_else(|| {
+                NaiveDate::parse_from_str(text, "%Y-%m-%d")
+                    .ok()
+                    .map(|day| DateTime::from_utc(day.and_hms(0, 0, 0), Utc))
+            }),
+        _ => None,
+    };
+
+    parsed.ok_or_else(|| {
+        ShellError::labeled_error(
+            "Expected a date",
+            "expected a date like 2019-10-01",
+            date.tag(),
+        )
+    })
+}
+
+fn zero_step(step: Option<&Value>, name: &Tag) -> ShellError {
+    let tag = match step {
+        Some(step) => step.tag(),
+        None => name.clone(),
+    };
+
+    ShellError::labeled_error("Step can't be zero", "step of zero", tag)
+}
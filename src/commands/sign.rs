@@ -0,0 +1,68 @@
+use crate::commands::abs::map_numbers;
+use crate::commands::WholeStreamCommand;
+use crate::data::value;
+use crate::prelude::*;
+use bigdecimal::BigDecimal;
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, ReturnSuccess, Signature, SyntaxShape};
+use nu_source::Tagged;
+use num_traits::Zero;
+use std::cmp::Ordering;
+
+pub struct Sign;
+
+#[derive(Deserialize)]
+pub struct SignArgs {
+    rest: Vec<Tagged<String>>,
+}
+
+impl WholeStreamCommand for Sign {
+    fn name(&self) -> &str {
+        "sign"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("sign").rest(
+            SyntaxShape::String,
+            "the columns holding the numbers (defaults to the values themselves)",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Replaces numbers with their sign: -1, 0 or 1."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, sign)?.run()
+    }
+}
+
+fn sign(
+    SignArgs { rest }: SignArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    Ok(input
+        .values
+        .map(move |value| {
+            let value = map_numbers(value, &rest, |number| {
+                let ordering = match number {
+                    Primitive::Int(i) => i.cmp(&BigInt::zero()),
+                    Primitive::Decimal(d) => d.cmp(&BigDecimal::zero()),
+                    _ => unreachable!("map_numbers only gives numbers"),
+                };
+
+                match ordering {
+                    Ordering::Less => value::int(-1),
+                    Ordering::Equal => value::int(0),
+                    Ordering::Greater => value::int(1),
+                }
+            })?;
+
+            ReturnSuccess::value(value)
+        })
+        .to_output_stream())
+}
@@ -0,0 +1,135 @@
+use crate::commands::open::detect_bom;
+use crate::commands::WholeStreamCommand;
+use crate::data::{value, TaggedDictBuilder};
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, Value};
+use nu_source::Tagged;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+const SNIFF_LENGTH: u64 = 512;
+
+pub struct Sniff;
+
+#[derive(Deserialize)]
+pub struct SniffArgs {
+    path: Tagged<PathBuf>,
+}
+
+impl WholeStreamCommand for Sniff {
+    fn name(&self) -> &str {
+        "sniff"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("sniff").required("path", SyntaxShape::Path, "the file to inspect")
+    }
+
+    fn usage(&self) -> &str {
+        "Detect the mime type and encoding of a file from its leading bytes."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, sniff)?.run()
+    }
+}
+
+fn sniff(
+    SniffArgs { path }: SniffArgs,
+    RunnableContext {
+        shell_manager,
+        name,
+        ..
+    }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let mut full_path = PathBuf::from(shell_manager.path());
+    full_path.push(&path.item);
+
+    let mut bytes = vec![];
+
+    std::fs::File::open(&full_path)
+        .and_then(|file| file.take(SNIFF_LENGTH).read_to_end(&mut bytes))
+        .map_err(|_| {
+            ShellError::labeled_error("File could not be opened", "file not found", path.tag())
+        })?;
+
+    Ok(OutputStream::one(ReturnSuccess::value(describe(
+        &bytes, &full_path, name,
+    ))))
+}
+
+fn describe(bytes: &[u8], path: &Path, tag: impl Into<Tag>) -> Value {
+    let bom = detect_bom(bytes);
+    let binary = bom.is_none() && looks_binary(bytes);
+
+    let extension = path
+        .extension()
+        .map(|ext| ext.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+
+    let mut row = TaggedDictBuilder::new(tag);
+
+    row.insert_untagged("mime", value::string(sniff_mime(bytes, &extension, binary)));
+
+    match bom {
+        Some(bom) => row.insert_untagged("encoding", value::string(bom.encoding_name())),
+        None if !binary => row.insert_untagged("encoding", value::string("utf-8")),
+        None => row.insert_untagged("encoding", value::nothing()),
+    }
+
+    row.insert_untagged("binary", value::boolean(binary));
+
+    row.into_value()
+}
+
+fn looks_binary(bytes: &[u8]) -> bool {
+    if bytes.contains(&0) {
+        return true;
+    }
+
+    match std::str::from_utf8(bytes) {
+        Ok(_) => false,
+        // Only the leading bytes are read, so a character may be cut off at the end.
+        Err(e) => e.error_len().is_some(),
+    }
+}
+
+fn sniff_mime(bytes: &[u8], extension: &str, binary: bool) -> &'static str {
+    let signatures: &[(&[u8], &str)] = &[
+        (b"\x89PNG\r\n\x1a\n", "image/png"),
+        (b"\xff\xd8\xff", "image/jpeg"),
+        (b"GIF87a", "image/gif"),
+        (b"GIF89a", "image/gif"),
+        (b"%PDF-", "application/pdf"),
+        (b"PK\x03\x04", "application/zip"),
+        (b"\x1f\x8b", "application/gzip"),
+        (b"SQLite format 3\0", "application/x-sqlite3"),
+    ];
+
+    for &(signature, mime) in signatures {
+        if bytes.starts_with(signature) {
+            return mime;
+        }
+    }
+
+    if binary {
+        return "application/octet-stream";
+    }
+
+    match extension {
+        "json" => "application/json",
+        "toml" => "application/toml",
+        "yaml" | "yml" => "application/x-yaml",
+        "xml" => "application/xml",
+        "csv" => "text/csv",
+        "tsv" => "text/tab-separated-values",
+        "html" | "htm" => "text/html",
+        "md" => "text/markdown",
+        _ => "text/plain",
+    }
+}
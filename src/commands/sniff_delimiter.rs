@@ -0,0 +1,75 @@
+use crate::commands::from_delimited_data::detect_delimiter;
+use crate::commands::WholeStreamCommand;
+use crate::data::{value, TaggedDictBuilder};
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, Value};
+
+pub struct SniffDelimiter;
+
+#[derive(Deserialize)]
+pub struct SniffDelimiterArgs {}
+
+impl WholeStreamCommand for SniffDelimiter {
+    fn name(&self) -> &str {
+        "sniff-delimiter"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("sniff-delimiter")
+    }
+
+    fn usage(&self) -> &str {
+        "Detects whether the columns of csv-like text are separated by ',', tab, ';' or '|'."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, sniff_delimiter)?.run()
+    }
+}
+
+fn sniff_delimiter(
+    SniffDelimiterArgs {}: SniffDelimiterArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let stream = async_stream! {
+        let values: Vec<Value> = input.values.collect().await;
+        let mut concat_string = String::new();
+
+        for value in values {
+            match value.as_string() {
+                Ok(s) => concat_string.push_str(&s),
+                Err(_) => {
+                    yield Err(ShellError::labeled_error_with_secondary(
+                        "Expected a string from pipeline",
+                        "requires string input",
+                        &name,
+                        "value originates from here",
+                        value.tag(),
+                    ));
+                    return;
+                }
+            }
+        }
+
+        let (delimiter, ambiguous) = detect_delimiter(&concat_string);
+
+        // Written the way from-csv --separator takes it.
+        let delimiter = match delimiter {
+            '\t' => r"\t".to_string(),
+            other => other.to_string(),
+        };
+
+        let mut row = TaggedDictBuilder::new(&name);
+        row.insert_untagged("delimiter", value::string(delimiter));
+        row.insert_untagged("ambiguous", value::boolean(ambiguous));
+
+        yield ReturnSuccess::value(row.into_value());
+    };
+
+    Ok(stream.to_output_stream())
+}
@@ -0,0 +1,75 @@
+use crate::commands::cumulative_min::compare;
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Evaluate, ReturnSuccess, Scope, Signature, SyntaxShape, Value};
+
+pub struct SortByKey;
+
+#[derive(Deserialize)]
+pub struct SortByKeyArgs {
+    key: Evaluate,
+    reverse: bool,
+}
+
+impl WholeStreamCommand for SortByKey {
+    fn name(&self) -> &str {
+        "sort-by-key"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("sort-by-key")
+            .required(
+                "key",
+                SyntaxShape::Block,
+                "the block computing the key to sort each row by (the row is $it)",
+            )
+            .switch("reverse", "sort from the largest key to the smallest")
+    }
+
+    fn usage(&self) -> &str {
+        "Sorts the rows by a key computed for each of them, keeping rows with equal keys in order."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, sort_by_key)?.run()
+    }
+}
+
+fn sort_by_key(
+    SortByKeyArgs { key, reverse }: SortByKeyArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let stream = async_stream! {
+        let rows: Vec<Value> = input.values.collect().await;
+        let mut keyed = Vec::with_capacity(rows.len());
+
+        for row in rows {
+            match key.invoke(&Scope::new(row.clone())) {
+                Ok(computed) => keyed.push((computed.value, row)),
+                Err(err) => {
+                    yield Err(err);
+                    return;
+                }
+            }
+        }
+
+        // sort_by is stable, so rows with equal keys keep their order either way. Keys that
+        // can't be compared with each other, like a string and a number, sort by their types.
+        if reverse {
+            keyed.sort_by(|(left, _), (right, _)| compare(right, left));
+        } else {
+            keyed.sort_by(|(left, _), (right, _)| compare(left, right));
+        }
+
+        for (_, row) in keyed {
+            yield ReturnSuccess::value(row);
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
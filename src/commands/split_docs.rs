@@ -0,0 +1,135 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::value;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, Value};
+use nu_source::Tagged;
+
+pub struct SplitDocs;
+
+#[derive(Deserialize)]
+pub struct SplitDocsArgs {
+    separator: Option<Tagged<String>>,
+}
+
+impl WholeStreamCommand for SplitDocs {
+    fn name(&self) -> &str {
+        "split-docs"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("split-docs").named(
+            "separator",
+            SyntaxShape::String,
+            "the line that separates documents (defaults to a blank line or ---)",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Splits text holding several documents into one string per document."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, split_docs)?.run()
+    }
+}
+
+fn split_docs(
+    SplitDocsArgs { separator }: SplitDocsArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let separator = separator.map(|separator| separator.item);
+
+    let stream = async_stream! {
+        let values: Vec<Value> = input.values.collect().await;
+
+        let mut concat_string = String::new();
+        let mut latest_tag: Option<Tag> = None;
+
+        for value in values {
+            latest_tag = Some(value.tag.clone());
+
+            if let Ok(s) = value.as_string() {
+                concat_string.push_str(&s);
+            } else {
+                yield Err(ShellError::labeled_error_with_secondary(
+                    "Expected a string from pipeline",
+                    "requires string input",
+                    &name,
+                    "value originates from here",
+                    value.tag(),
+                ));
+                return;
+            }
+        }
+
+        let tag = latest_tag.unwrap_or_else(|| name.clone());
+
+        for doc in split(&concat_string, separator.as_ref().map(|s| s.as_str())) {
+            yield ReturnSuccess::value(value::string(doc).into_value(&tag));
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+/// Splits the text on separator lines, dropping documents that are empty or only whitespace.
+/// Without a separator, blank lines and `---` lines both end a document.
+fn split(text: &str, separator: Option<&str>) -> Vec<String> {
+    let mut docs = vec![];
+    let mut current: Vec<&str> = vec![];
+
+    for line in text.lines() {
+        let trimmed = line.trim();
+
+        let is_separator = match separator {
+            Some(separator) => trimmed == separator,
+            None => trimmed.is_empty() || trimmed == "---",
+        };
+
+        if is_separator {
+            push_doc(&mut docs, &mut current);
+        } else {
+            current.push(line);
+        }
+    }
+
+    push_doc(&mut docs, &mut current);
+
+    docs
+}
+
+fn push_doc(docs: &mut Vec<String>, current: &mut Vec<&str>) {
+    let doc = current.join("\n");
+
+    if !doc.trim().is_empty() {
+        docs.push(doc);
+    }
+
+    current.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::split;
+
+    #[test]
+    fn splits_on_blank_lines_and_dashes_by_default() {
+        assert_eq!(
+            split("a: 1\n\nb: 2\n---\nc: 3\n\n\n", None),
+            vec!["a: 1", "b: 2", "c: 3"]
+        );
+    }
+
+    #[test]
+    fn keeps_blank_lines_inside_documents_with_a_separator() {
+        assert_eq!(
+            split("a: 1\n\nb: 2\n%%\nc: 3\n%%\n", Some("%%")),
+            vec!["a: 1\n\nb: 2", "c: 3"]
+        );
+    }
+}
@@ -0,0 +1,96 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::{value, TaggedDictBuilder};
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, Value};
+use nu_source::Tagged;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+pub struct SplitInto;
+
+#[derive(Deserialize)]
+pub struct SplitIntoArgs {
+    parts: Tagged<usize>,
+    by: Option<Tagged<String>>,
+}
+
+impl WholeStreamCommand for SplitInto {
+    fn name(&self) -> &str {
+        "split-into"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("split-into")
+            .required("parts", SyntaxShape::Int, "how many tables to split into")
+            .named(
+                "by",
+                SyntaxShape::String,
+                "the column whose value decides the table, so rows with the same value stay together",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Deals the rows out into a number of tables, returned as the columns part_0, part_1 and so on."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, split_into)?.run()
+    }
+}
+
+fn split_into(
+    SplitIntoArgs { parts, by }: SplitIntoArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    if parts.item == 0 {
+        return Err(ShellError::labeled_error(
+            "Expected at least one part",
+            "parts must be more than 0",
+            parts.tag(),
+        ));
+    }
+
+    let count = parts.item;
+
+    let stream = async_stream! {
+        let values: Vec<Value> = input.values.collect().await;
+        let mut tables: Vec<Vec<Value>> = vec![vec![]; count];
+
+        for (index, row) in values.into_iter().enumerate() {
+            let part = match &by {
+                None => index % count,
+                Some(column) => match value::cell_at(&row, column) {
+                    Ok(cell) => (key_hash(&cell) % count as u64) as usize,
+                    Err(err) => {
+                        yield Err(err);
+                        return;
+                    }
+                },
+            };
+
+            tables[part].push(row);
+        }
+
+        let mut row = TaggedDictBuilder::new(&name);
+
+        for (part, table) in tables.iter().enumerate() {
+            row.insert_untagged(format!("part_{}", part), value::table(table));
+        }
+
+        yield ReturnSuccess::value(row.into_value());
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+/// Values don't implement Hash, so the key is hashed by its debug form.
+fn key_hash(cell: &Value) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    format!("{:?}", cell.value).hash(&mut hasher);
+    hasher.finish()
+}
@@ -0,0 +1,91 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::value;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape};
+use nu_source::Tagged;
+use std::path::PathBuf;
+
+#[derive(Deserialize)]
+struct SplitPathsArgs {
+    separator: Option<Tagged<String>>,
+    #[serde(rename(deserialize = "keep-empty"))]
+    keep_empty: bool,
+}
+
+pub struct SplitPaths;
+
+impl WholeStreamCommand for SplitPaths {
+    fn name(&self) -> &str {
+        "split-paths"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("split-paths")
+            .named(
+                "separator",
+                SyntaxShape::String,
+                "the character between paths (defaults to the platform's, eg : or ;)",
+            )
+            .switch("keep-empty", "keep empty segments instead of dropping them")
+    }
+
+    fn usage(&self) -> &str {
+        "Split a PATH-like string into a path per row."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, split_paths)?.run()
+    }
+}
+
+fn split_paths(
+    SplitPathsArgs {
+        separator,
+        keep_empty,
+    }: SplitPathsArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let separator = separator.map(|separator| separator.item);
+
+    let stream = input
+        .values
+        .map(move |v| {
+            let mut result = VecDeque::new();
+
+            match v.as_string() {
+                Ok(s) => {
+                    let paths: Vec<PathBuf> = match &separator {
+                        Some(separator) => s.split(separator.as_str()).map(PathBuf::from).collect(),
+                        None => std::env::split_paths(&s).collect(),
+                    };
+
+                    for path in paths {
+                        if keep_empty || !path.as_os_str().is_empty() {
+                            result.push_back(ReturnSuccess::value(
+                                value::path(path).into_value(&v.tag),
+                            ));
+                        }
+                    }
+                }
+                Err(_) => {
+                    result.push_back(Err(ShellError::labeled_error_with_secondary(
+                        "Expected a string from pipeline",
+                        "requires string input",
+                        &name,
+                        "value originates from here",
+                        v.tag(),
+                    )));
+                }
+            }
+
+            result
+        })
+        .flatten();
+
+    Ok(stream.to_output_stream())
+}
@@ -0,0 +1,195 @@
+use crate::commands::get::get_column_path;
+use crate::commands::WholeStreamCommand;
+use crate::data::value;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue,
+};
+use nu_source::Tagged;
+
+pub struct StrCase;
+
+#[derive(Deserialize)]
+pub struct StrCaseArgs {
+    column: ColumnPath,
+    mode: Tagged<String>,
+}
+
+impl WholeStreamCommand for StrCase {
+    fn name(&self) -> &str {
+        "str-case"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str-case")
+            .required(
+                "column",
+                SyntaxShape::ColumnPath,
+                "the column holding the strings",
+            )
+            .required(
+                "mode",
+                SyntaxShape::String,
+                "the case to write them in: upper, lower, title, snake, camel or kebab",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Rewrites the strings in a column in another case, eg snake_case or camelCase."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, str_case)?.run()
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Case {
+    Upper,
+    Lower,
+    Title,
+    Snake,
+    Camel,
+    Kebab,
+}
+
+fn str_case(
+    StrCaseArgs { column, mode }: StrCaseArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let case = match mode.item.as_str() {
+        "upper" => Case::Upper,
+        "lower" => Case::Lower,
+        "title" => Case::Title,
+        "snake" => Case::Snake,
+        "camel" => Case::Camel,
+        "kebab" => Case::Kebab,
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Unknown case",
+                "expected upper, lower, title, snake, camel or kebab",
+                mode.tag(),
+            ))
+        }
+    };
+
+    Ok(input
+        .values
+        .map(move |row| {
+            let cell = get_column_path(&column, &row)?;
+
+            let text = match &cell.value {
+                UntaggedValue::Primitive(Primitive::String(s)) => s,
+                UntaggedValue::Primitive(Primitive::Line(s)) => s,
+                _ => {
+                    return Err(ShellError::labeled_error_with_secondary(
+                        "Expected a string",
+                        format!("found {} instead", cell.type_name()),
+                        cell.tag(),
+                        "row originates from here",
+                        row.tag(),
+                    ))
+                }
+            };
+
+            let converted = value::string(convert(text, case));
+
+            match row.replace_data_at_column_path(&column, converted.into_value(cell.tag())) {
+                Some(row) => ReturnSuccess::value(row),
+                None => Err(ShellError::labeled_error(
+                    "str-case could not find the column",
+                    "column name",
+                    &name,
+                )),
+            }
+        })
+        .to_output_stream())
+}
+
+fn convert(text: &str, case: Case) -> String {
+    match case {
+        Case::Upper => text.to_uppercase(),
+        Case::Lower => text.to_lowercase(),
+        Case::Title => words(text)
+            .iter()
+            .map(|word| capitalized(word))
+            .collect::<Vec<_>>()
+            .join(" "),
+        Case::Snake => lowercase_words(text).join("_"),
+        Case::Kebab => lowercase_words(text).join("-"),
+        Case::Camel => words(text)
+            .iter()
+            .enumerate()
+            .map(|(index, word)| match index {
+                0 => word.to_lowercase(),
+                _ => capitalized(word),
+            })
+            .collect(),
+    }
+}
+
+fn lowercase_words(text: &str) -> Vec<String> {
+    words(text).iter().map(|word| word.to_lowercase()).collect()
+}
+
+fn capitalized(word: &str) -> String {
+    let mut chars = word.chars();
+
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
+
+/// Splits on whitespace, `_` and `-`, and where the case changes inside a word, so that
+/// `parseHTTPRequest` gives parse, HTTP and Request.
+fn words(text: &str) -> Vec<String> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut words = vec![];
+    let mut current = String::new();
+
+    for (index, &c) in chars.iter().enumerate() {
+        if c.is_whitespace() || c == '_' || c == '-' {
+            if !current.is_empty() {
+                words.push(std::mem::replace(&mut current, String::new()));
+            }
+            continue;
+        }
+
+        let previous = if index > 0 {
+            chars.get(index - 1)
+        } else {
+            None
+        };
+        let next = chars.get(index + 1);
+
+        let starts_word = c.is_uppercase()
+            && match previous {
+                Some(previous) if previous.is_lowercase() || previous.is_numeric() => true,
+                Some(previous) if previous.is_uppercase() => {
+                    next.map_or(false, |n| n.is_lowercase())
+                }
+                _ => false,
+            };
+
+        if starts_word && !current.is_empty() {
+            words.push(std::mem::replace(&mut current, String::new()));
+        }
+
+        current.push(c);
+    }
+
+    if !current.is_empty() {
+        words.push(current);
+    }
+
+    words
+}
@@ -0,0 +1,108 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::value;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::Tagged;
+
+pub struct StrCollect;
+
+#[derive(Deserialize)]
+pub struct StrCollectArgs {
+    column: Option<Tagged<String>>,
+    separator: Option<Tagged<String>>,
+    strict: bool,
+}
+
+impl WholeStreamCommand for StrCollect {
+    fn name(&self) -> &str {
+        "str-collect"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str-collect")
+            .optional(
+                "column",
+                SyntaxShape::String,
+                "the column holding the strings to join",
+            )
+            .named(
+                "separator",
+                SyntaxShape::String,
+                "the text to place between each string",
+            )
+            .switch("strict", "error on values that are not strings")
+    }
+
+    fn usage(&self) -> &str {
+        "Joins the strings in the pipeline (or in the given column) into a single string."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, str_collect)?.run()
+    }
+}
+
+fn str_collect(
+    StrCollectArgs {
+        column,
+        separator,
+        strict,
+    }: StrCollectArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let stream = async_stream! {
+        let values: Vec<Value> = input.values.collect().await;
+
+        let separator = match &separator {
+            Some(separator) => separator.item.clone(),
+            None => String::new(),
+        };
+
+        let mut strings = vec![];
+
+        for value in values {
+            let cell = match &column {
+                Some(column) => match value::cell_at(&value, column) {
+                    Ok(cell) => cell,
+                    Err(err) => {
+                        yield Err(err);
+                        return;
+                    }
+                },
+                None => value,
+            };
+
+            match to_string(&cell, strict) {
+                Ok(s) => strings.push(s),
+                Err(err) => {
+                    yield Err(err);
+                    return;
+                }
+            }
+        }
+
+        yield ReturnSuccess::value(value::string(strings.join(&separator)).into_value(&name));
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+fn to_string(cell: &Value, strict: bool) -> Result<String, ShellError> {
+    match &cell.value {
+        UntaggedValue::Primitive(Primitive::String(s)) => Ok(s.clone()),
+        UntaggedValue::Primitive(Primitive::Line(s)) => Ok(s.clone()),
+        _ if strict => Err(ShellError::labeled_error(
+            "Expected a string",
+            format!("found {} instead", cell.type_name()),
+            cell.tag(),
+        )),
+        other => Ok(value::format_leaf(other).plain_string(std::usize::MAX)),
+    }
+}
@@ -0,0 +1,134 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::value;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::Tagged;
+
+pub struct StrDistance;
+
+#[derive(Deserialize)]
+pub struct StrDistanceArgs {
+    column: Tagged<String>,
+    other: Tagged<String>,
+    literal: bool,
+    into: Option<Tagged<String>>,
+    normalized: bool,
+}
+
+impl WholeStreamCommand for StrDistance {
+    fn name(&self) -> &str {
+        "str-distance"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str-distance")
+            .required(
+                "column",
+                SyntaxShape::String,
+                "the column holding the strings to compare",
+            )
+            .required(
+                "other",
+                SyntaxShape::String,
+                "the column holding the strings to compare with (or the text itself with --literal)",
+            )
+            .switch(
+                "literal",
+                "compare with the other text itself instead of a column",
+            )
+            .named(
+                "into",
+                SyntaxShape::String,
+                "the column to store the distance in (defaults to distance)",
+            )
+            .switch(
+                "normalized",
+                "divide by the length of the longer string, giving a number from 0 to 1",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Adds a column with the levenshtein edit distance between two strings."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, str_distance)?.run()
+    }
+}
+
+fn str_distance(
+    StrDistanceArgs {
+        column,
+        other,
+        literal,
+        into,
+        normalized,
+    }: StrDistanceArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let into = match into {
+        Some(into) => into.item,
+        None => "distance".to_string(),
+    };
+
+    Ok(input
+        .values
+        .map(move |row| {
+            let left = string_at(&row, &column)?;
+
+            let right = if literal {
+                other.item.clone()
+            } else {
+                string_at(&row, &other)?
+            };
+
+            let distance = if normalized {
+                UntaggedValue::Primitive(Primitive::from(normalized_distance(&left, &right)))
+            } else {
+                value::int(natural::distance::levenshtein_distance(&left, &right) as i64)
+            };
+
+            match row.insert_data_at_path(&into, distance.into_value(row.tag())) {
+                Some(row) => ReturnSuccess::value(row),
+                None => Err(ShellError::labeled_error(
+                    "Could not add the distance",
+                    "expected a row",
+                    row.tag(),
+                )),
+            }
+        })
+        .to_output_stream())
+}
+
+/// The edit distance divided by the length of the longer string, from 0 (the same) to 1.
+pub(crate) fn normalized_distance(left: &str, right: &str) -> f64 {
+    let longer = left.chars().count().max(right.chars().count());
+
+    match longer {
+        0 => 0.0,
+        _ => natural::distance::levenshtein_distance(left, right) as f64 / longer as f64,
+    }
+}
+
+pub(crate) fn string_at(row: &Value, column: &Tagged<String>) -> Result<String, ShellError> {
+    let cell = value::cell_at(row, column)?;
+
+    match &cell.value {
+        UntaggedValue::Primitive(Primitive::String(s)) => Ok(s.clone()),
+        UntaggedValue::Primitive(Primitive::Line(s)) => Ok(s.clone()),
+        _ => Err(ShellError::labeled_error_with_secondary(
+            "Expected a string",
+            format!("found {} instead", cell.type_name()),
+            cell.tag(),
+            "row originates from here",
+            row.tag(),
+        )),
+    }
+}
@@ -0,0 +1,33 @@
+use crate::commands::str_starts_with::{affix_signature, filter_by_affix, StrAffixArgs};
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::Signature;
+
+pub struct StrEndsWith;
+
+impl WholeStreamCommand for StrEndsWith {
+    fn name(&self) -> &str {
+        "str-ends-with"
+    }
+
+    fn signature(&self) -> Signature {
+        affix_signature("str-ends-with", "the text the strings should end with")
+    }
+
+    fn usage(&self) -> &str {
+        "Keeps the rows whose string in the given column ends with the text."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, str_ends_with)?.run()
+    }
+}
+
+fn str_ends_with(args: StrAffixArgs, context: RunnableContext) -> Result<OutputStream, ShellError> {
+    filter_by_affix(args, context, |text, suffix| text.ends_with(suffix))
+}
@@ -0,0 +1,126 @@
+use crate::commands::get::get_column_path;
+use crate::commands::WholeStreamCommand;
+use crate::data::value;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue,
+};
+use nu_source::Tagged;
+use unicode_segmentation::UnicodeSegmentation;
+
+pub struct StrIndexOf;
+
+#[derive(Deserialize)]
+pub struct StrIndexOfArgs {
+    column: ColumnPath,
+    needle: Tagged<String>,
+    into: Option<Tagged<String>>,
+    end: bool,
+    insensitive: bool,
+}
+
+impl WholeStreamCommand for StrIndexOf {
+    fn name(&self) -> &str {
+        "str-index-of"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str-index-of")
+            .required(
+                "column",
+                SyntaxShape::ColumnPath,
+                "the column holding the strings to search",
+            )
+            .required("needle", SyntaxShape::String, "the text to look for")
+            .named(
+                "into",
+                SyntaxShape::String,
+                "the column to store the index in (defaults to index)",
+            )
+            .switch("end", "find the last occurrence instead of the first")
+            .switch("insensitive", "ignore case when matching")
+    }
+
+    fn usage(&self) -> &str {
+        "Adds a column with the character index where the text first occurs, or -1 when it doesn't."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, str_index_of)?.run()
+    }
+}
+
+fn str_index_of(
+    StrIndexOfArgs {
+        column,
+        needle,
+        into,
+        end,
+        insensitive,
+    }: StrIndexOfArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let into = match into {
+        Some(into) => into.item,
+        None => "index".to_string(),
+    };
+
+    let needle = if insensitive {
+        needle.item.to_lowercase()
+    } else {
+        needle.item
+    };
+
+    Ok(input
+        .values
+        .map(move |row| {
+            let cell = get_column_path(&column, &row)?;
+
+            let text = match &cell.value {
+                UntaggedValue::Primitive(Primitive::String(s)) => s,
+                UntaggedValue::Primitive(Primitive::Line(s)) => s,
+                _ => {
+                    return Err(ShellError::labeled_error_with_secondary(
+                        "Expected a string",
+                        format!("found {} instead", cell.type_name()),
+                        cell.tag(),
+                        "row originates from here",
+                        row.tag(),
+                    ))
+                }
+            };
+
+            let text = if insensitive {
+                text.to_lowercase()
+            } else {
+                text.clone()
+            };
+
+            let found = if end {
+                text.rfind(needle.as_str())
+            } else {
+                text.find(needle.as_str())
+            };
+
+            // The byte offset is turned into a count of the characters before it.
+            let index = match found {
+                Some(offset) => text[..offset].graphemes(true).count() as i64,
+                None => -1,
+            };
+
+            match row.insert_data_at_path(&into, value::int(index).into_value(cell.tag())) {
+                Some(row) => ReturnSuccess::value(row),
+                None => Err(ShellError::labeled_error(
+                    "Could not add the index",
+                    "expected a row",
+                    row.tag(),
+                )),
+            }
+        })
+        .to_output_stream())
+}
@@ -0,0 +1,107 @@
+use crate::commands::get::get_column_path;
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue};
+use nu_source::Tagged;
+
+pub struct StrStartsWith;
+
+#[derive(Deserialize)]
+pub struct StrAffixArgs {
+    column: ColumnPath,
+    text: Tagged<String>,
+    insensitive: bool,
+    invert: bool,
+}
+
+impl WholeStreamCommand for StrStartsWith {
+    fn name(&self) -> &str {
+        "str-starts-with"
+    }
+
+    fn signature(&self) -> Signature {
+        affix_signature("str-starts-with", "the text the strings should start with")
+    }
+
+    fn usage(&self) -> &str {
+        "Keeps the rows whose string in the given column starts with the text."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, str_starts_with)?.run()
+    }
+}
+
+fn str_starts_with(
+    args: StrAffixArgs,
+    context: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    filter_by_affix(args, context, |text, prefix| text.starts_with(prefix))
+}
+
+pub(crate) fn affix_signature(name: &str, text: &str) -> Signature {
+    Signature::build(name)
+        .required(
+            "column",
+            SyntaxShape::ColumnPath,
+            "the column holding the strings",
+        )
+        .required("text", SyntaxShape::String, text)
+        .switch("insensitive", "ignore case when matching")
+        .switch("invert", "keep the rows that don't match instead")
+}
+
+/// Keeps the rows whose column matches. Cells that aren't strings never match.
+pub(crate) fn filter_by_affix(
+    StrAffixArgs {
+        column,
+        text,
+        insensitive,
+        invert,
+    }: StrAffixArgs,
+    RunnableContext { input, .. }: RunnableContext,
+    matches: fn(&str, &str) -> bool,
+) -> Result<OutputStream, ShellError> {
+    let text = if insensitive {
+        text.item.to_lowercase()
+    } else {
+        text.item
+    };
+
+    let stream = async_stream! {
+        let mut rows = input.values;
+
+        while let Some(row) = rows.next().await {
+            let cell = match get_column_path(&column, &row) {
+                Ok(cell) => cell,
+                Err(err) => {
+                    yield Err(err);
+                    return;
+                }
+            };
+
+            let matched = match &cell.value {
+                UntaggedValue::Primitive(Primitive::String(s))
+                | UntaggedValue::Primitive(Primitive::Line(s)) => {
+                    if insensitive {
+                        matches(&s.to_lowercase(), &text)
+                    } else {
+                        matches(s, &text)
+                    }
+                }
+                _ => false,
+            };
+
+            if matched != invert {
+                yield ReturnSuccess::value(row);
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
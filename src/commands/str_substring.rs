@@ -0,0 +1,117 @@
+use crate::commands::get::get_column_path;
+use crate::commands::WholeStreamCommand;
+use crate::data::value;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue,
+};
+use nu_source::Tagged;
+use unicode_segmentation::UnicodeSegmentation;
+
+pub struct StrSubstring;
+
+#[derive(Deserialize)]
+pub struct StrSubstringArgs {
+    column: ColumnPath,
+    start: Tagged<i64>,
+    end: Option<Tagged<i64>>,
+}
+
+impl WholeStreamCommand for StrSubstring {
+    fn name(&self) -> &str {
+        "str-substring"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str-substring")
+            .required(
+                "column",
+                SyntaxShape::ColumnPath,
+                "the column holding the strings to slice",
+            )
+            .required(
+                "start",
+                SyntaxShape::Int,
+                "the index of the first character to keep (negative counts from the end)",
+            )
+            .optional(
+                "end",
+                SyntaxShape::Int,
+                "the index to stop before (negative counts from the end, defaults to the end)",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Replaces the strings in a column with the characters between two indices."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, str_substring)?.run()
+    }
+}
+
+fn str_substring(
+    StrSubstringArgs { column, start, end }: StrSubstringArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let start = start.item;
+    let end = end.map(|end| end.item);
+
+    Ok(input
+        .values
+        .map(move |row| {
+            let cell = get_column_path(&column, &row)?;
+
+            let text = match &cell.value {
+                UntaggedValue::Primitive(Primitive::String(s)) => s,
+                UntaggedValue::Primitive(Primitive::Line(s)) => s,
+                _ => {
+                    return Err(ShellError::labeled_error_with_secondary(
+                        "Expected a string",
+                        format!("found {} instead", cell.type_name()),
+                        cell.tag(),
+                        "row originates from here",
+                        row.tag(),
+                    ))
+                }
+            };
+
+            let sliced = value::string(substring(text, start, end));
+
+            match row.replace_data_at_column_path(&column, sliced.into_value(cell.tag())) {
+                Some(row) => ReturnSuccess::value(row),
+                None => Err(ShellError::labeled_error(
+                    "str-substring could not find the column",
+                    "column name",
+                    &name,
+                )),
+            }
+        })
+        .to_output_stream())
+}
+
+/// Slices by grapheme, so characters made of several code points are never split. Indices
+/// past either end are moved to it.
+fn substring(text: &str, start: i64, end: Option<i64>) -> String {
+    let graphemes: Vec<&str> = text.graphemes(true).collect();
+    let len = graphemes.len() as i64;
+
+    let resolve = |index: i64| {
+        let index = if index < 0 { len + index } else { index };
+        index.max(0).min(len) as usize
+    };
+
+    let start = resolve(start);
+    let end = resolve(end.unwrap_or(len));
+
+    if start >= end {
+        String::new()
+    } else {
+        graphemes[start..end].concat()
+    }
+}
@@ -0,0 +1,138 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::{value, TaggedDictBuilder};
+use crate::prelude::*;
+use indexmap::IndexMap;
+use nu_errors::ShellError;
+use nu_parser::Operator;
+use nu_protocol::{Primitive, ReturnSuccess, Signature, UntaggedValue, Value};
+use std::collections::HashSet;
+
+pub struct Summary;
+
+impl WholeStreamCommand for Summary {
+    fn name(&self) -> &str {
+        "summary"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("summary")
+    }
+
+    fn usage(&self) -> &str {
+        "Summarizes each column of the table with basic statistics."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        summary(args, registry)
+    }
+}
+
+fn summary(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let args = args.evaluate_once(registry)?;
+    let tag = args.name_tag();
+    let input = args.input;
+
+    let stream = async_stream! {
+        let rows: Vec<Value> = input.values.collect().await;
+
+        let mut columns: IndexMap<String, Vec<Value>> = IndexMap::new();
+
+        for row in &rows {
+            if let UntaggedValue::Row(dict) = &row.value {
+                for (column, cell) in dict.entries.iter() {
+                    let cells = columns.entry(column.clone()).or_insert_with(Vec::new);
+
+                    if !cell.value.is_none() {
+                        cells.push(cell.clone());
+                    }
+                }
+            }
+        }
+
+        for (column, cells) in columns {
+            match describe(&column, &cells, &tag) {
+                Ok(described) => yield ReturnSuccess::value(described),
+                Err(err) => {
+                    yield Err(err);
+                    return;
+                }
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+fn is_number(value: &Value) -> bool {
+    match &value.value {
+        UntaggedValue::Primitive(Primitive::Int(_))
+        | UntaggedValue::Primitive(Primitive::Decimal(_)) => true,
+        _ => false,
+    }
+}
+
+/// Numeric columns (every non-empty cell a number) get count, min, max, mean and the
+/// population stddev. Any other column gets count and the number of distinct cells.
+fn describe(column: &str, cells: &[Value], tag: &Tag) -> Result<Value, ShellError> {
+    let mut described = TaggedDictBuilder::new(tag);
+
+    described.insert_untagged("column", value::string(column));
+    described.insert_untagged("count", value::int(cells.len()));
+
+    if cells.is_empty() || !cells.iter().all(is_number) {
+        let distinct: HashSet<String> = cells
+            .iter()
+            .map(|cell| value::format_leaf(&cell.value).plain_string(std::usize::MAX))
+            .collect();
+
+        described.insert_untagged("distinct", value::int(distinct.len()));
+
+        return Ok(described.into_value());
+    }
+
+    let mut min = &cells[0];
+    let mut max = &cells[0];
+    let mut total = cells[0].value.clone();
+
+    for cell in &cells[1..] {
+        if value::compare_values(&Operator::LessThan, &cell.value, &min.value).unwrap_or(false) {
+            min = cell;
+        }
+
+        if value::compare_values(&Operator::GreaterThan, &cell.value, &max.value).unwrap_or(false) {
+            max = cell;
+        }
+
+        total = value::compute_values(&Operator::Plus, &total, &cell.value).map_err(
+            |(left, right)| {
+                ShellError::labeled_error(
+                    "Could not add the values",
+                    format!("cannot add {} and {}", left, right),
+                    cell.tag(),
+                )
+            },
+        )?;
+    }
+
+    let count = cells.len() as f64;
+    let mean = value::as_f64(&total).unwrap_or(std::f64::NAN) / count;
+    let variance = cells
+        .iter()
+        .map(|cell| (value::as_f64(cell).unwrap_or(std::f64::NAN) - mean).powi(2))
+        .sum::<f64>()
+        / count;
+
+    described.insert_value("min", min.clone());
+    described.insert_value("max", max.clone());
+    described.insert_untagged("mean", UntaggedValue::Primitive(Primitive::from(mean)));
+    described.insert_untagged(
+        "stddev",
+        UntaggedValue::Primitive(Primitive::from(variance.sqrt())),
+    );
+
+    Ok(described.into_value())
+}
@@ -0,0 +1,303 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::value;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape};
+use nu_source::Tagged;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::PathBuf;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+pub struct Tail;
+
+#[derive(Deserialize)]
+pub struct TailArgs {
+    path: Tagged<PathBuf>,
+    rows: Option<Tagged<u64>>,
+    follow: bool,
+}
+
+impl WholeStreamCommand for Tail {
+    fn name(&self) -> &str {
+        "tail"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("tail")
+            .required("path", SyntaxShape::Path, "the file to read lines from")
+            .optional(
+                "rows",
+                SyntaxShape::Int,
+                "starting from the back, the number of lines to return",
+            )
+            .switch("follow", "keep reading lines as they are appended")
+    }
+
+    fn usage(&self) -> &str {
+        "Show the last lines of a file, optionally following new lines as they are appended."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, tail)?.run()
+    }
+}
+
+fn tail(
+    TailArgs { path, rows, follow }: TailArgs,
+    RunnableContext {
+        shell_manager,
+        ctrl_c,
+        name,
+        ..
+    }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let mut full_path = PathBuf::from(shell_manager.path());
+    full_path.push(&path.item);
+
+    let rows_desired = if let Some(quantity) = rows {
+        *quantity as usize
+    } else {
+        10
+    };
+
+    let contents = std::fs::read(&full_path).map_err(|_| {
+        ShellError::labeled_error("File could not be opened", "file not found", path.tag())
+    })?;
+
+    let tag = Tag {
+        span: name.span,
+        anchor: Some(AnchorLocation::File(
+            full_path.to_string_lossy().to_string(),
+        )),
+    };
+
+    let mut follower = Follower::new(&full_path, contents.len() as u64);
+
+    let stream = async_stream! {
+        let text = String::from_utf8_lossy(&contents);
+        let lines: Vec<&str> = text.lines().collect();
+        let start = lines.len().saturating_sub(rows_desired);
+
+        for line in lines[start..].iter() {
+            yield ReturnSuccess::value(value::line(*line).into_value(&tag));
+        }
+
+        if !follow {
+            return;
+        }
+
+        loop {
+            if ctrl_c.load(Ordering::SeqCst) {
+                break;
+            }
+
+            match follower.poll() {
+                Ok(lines) => {
+                    for line in lines {
+                        yield ReturnSuccess::value(value::line(line).into_value(&tag));
+                    }
+                }
+                Err(_) => {
+                    yield Err(ShellError::labeled_error(
+                        "File could not be read",
+                        "file is no longer readable",
+                        path.tag(),
+                    ));
+                    break;
+                }
+            }
+
+            futures_timer::Delay::new(Duration::from_millis(250)).await;
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+/// Tracks the read position of a file so that lines appended after the
+/// position can be picked up. A file that shrinks, or is replaced by another
+/// file at the same path, is assumed to have been truncated or rotated, and is
+/// read again from the start.
+pub struct Follower {
+    path: PathBuf,
+    position: u64,
+    identity: Option<(u64, u64)>,
+    pending: Vec<u8>,
+}
+
+impl Follower {
+    pub fn new(path: impl Into<PathBuf>, position: u64) -> Follower {
+        let path = path.into();
+        let identity = std::fs::metadata(&path)
+            .ok()
+            .and_then(|metadata| file_identity(&metadata));
+
+        Follower {
+            path,
+            position,
+            identity,
+            pending: vec![],
+        }
+    }
+
+    pub fn poll(&mut self) -> std::io::Result<Vec<String>> {
+        // Rotating by renaming the file before creating the next one leaves a moment where
+        // there is no file at the path, which is no different from nothing being appended.
+        let mut file = match std::fs::File::open(&self.path) {
+            Ok(file) => file,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+            Err(err) => return Err(err),
+        };
+        let metadata = file.metadata()?;
+        let len = metadata.len();
+        let identity = file_identity(&metadata);
+
+        if len < self.position || identity != self.identity {
+            self.position = 0;
+            self.identity = identity;
+            self.pending.clear();
+        }
+
+        if len == self.position {
+            return Ok(vec![]);
+        }
+
+        file.seek(SeekFrom::Start(self.position))?;
+
+        let mut bytes = vec![];
+        file.read_to_end(&mut bytes)?;
+        self.position += bytes.len() as u64;
+        self.pending.extend(bytes);
+
+        let mut lines = vec![];
+
+        // Only whole lines are decoded, so a character written in two parts isn't split.
+        while let Some(idx) = self.pending.iter().position(|b| *b == b'\n') {
+            let line: Vec<u8> = self.pending.drain(..=idx).collect();
+            lines.push(
+                String::from_utf8_lossy(&line)
+                    .trim_end_matches(|c| c == '\n' || c == '\r')
+                    .to_string(),
+            );
+        }
+
+        Ok(lines)
+    }
+}
+
+/// The device and inode of a file, which change when a log is rotated even if the new file
+/// is already longer than what was read.
+#[cfg(unix)]
+fn file_identity(metadata: &std::fs::Metadata) -> Option<(u64, u64)> {
+    use std::os::unix::fs::MetadataExt;
+
+    Some((metadata.dev(), metadata.ino()))
+}
+
+#[cfg(not(unix))]
+fn file_identity(_metadata: &std::fs::Metadata) -> Option<(u64, u64)> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Follower;
+    use std::io::{Seek, SeekFrom, Write};
+
+    #[test]
+    fn follows_lines_appended_to_a_file() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        writeln!(file, "andres").unwrap();
+
+        let position = file.as_file().metadata().unwrap().len();
+        let mut follower = Follower::new(file.path(), position);
+
+        assert!(follower.poll().unwrap().is_empty());
+
+        write!(file, "jonathan\nyehu").unwrap();
+        file.flush().unwrap();
+        assert_eq!(follower.poll().unwrap(), vec!["jonathan".to_string()]);
+
+        writeln!(file, "da").unwrap();
+        file.flush().unwrap();
+        assert_eq!(follower.poll().unwrap(), vec!["yehuda".to_string()]);
+    }
+
+    #[test]
+    fn reads_from_the_start_after_truncation() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        writeln!(file, "andres\njonathan\nyehuda").unwrap();
+
+        let position = file.as_file().metadata().unwrap().len();
+        let mut follower = Follower::new(file.path(), position);
+
+        file.as_file().set_len(0).unwrap();
+        file.seek(SeekFrom::Start(0)).unwrap();
+        writeln!(file, "gorby").unwrap();
+        file.flush().unwrap();
+
+        assert_eq!(follower.poll().unwrap(), vec!["gorby".to_string()]);
+    }
+
+    #[test]
+    fn decodes_characters_written_in_two_parts() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        let mut follower = Follower::new(file.path(), 0);
+
+        let word = "andrés\n".as_bytes();
+        // the first byte of é
+        let split = "andr".len() + 1;
+
+        file.write_all(&word[..split]).unwrap();
+        file.flush().unwrap();
+        assert!(follower.poll().unwrap().is_empty());
+
+        file.write_all(&word[split..]).unwrap();
+        file.flush().unwrap();
+        assert_eq!(follower.poll().unwrap(), vec!["andrés".to_string()]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn reads_from_the_start_after_rotation() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("app.log");
+        std::fs::write(&path, "andres\n").unwrap();
+
+        let position = std::fs::metadata(&path).unwrap().len();
+        let mut follower = Follower::new(&path, position);
+
+        std::fs::rename(&path, dir.path().join("app.log.1")).unwrap();
+        std::fs::write(&path, "jonathan\nyehuda\n").unwrap();
+
+        assert_eq!(
+            follower.poll().unwrap(),
+            vec!["jonathan".to_string(), "yehuda".to_string()]
+        );
+    }
+
+    #[test]
+    fn waits_for_a_file_renamed_away_to_be_created_again() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("app.log");
+        std::fs::write(&path, "andres\n").unwrap();
+
+        let position = std::fs::metadata(&path).unwrap().len();
+        let mut follower = Follower::new(&path, position);
+
+        std::fs::rename(&path, dir.path().join("app.log.1")).unwrap();
+        assert!(follower.poll().unwrap().is_empty());
+
+        let mut file = std::fs::File::create(&path).unwrap();
+        assert!(follower.poll().unwrap().is_empty());
+
+        writeln!(file, "jonathan").unwrap();
+        file.flush().unwrap();
+        assert_eq!(follower.poll().unwrap(), vec!["jonathan".to_string()]);
+    }
+}
@@ -0,0 +1,154 @@
+use crate::commands::classified::block::{block_source, run_block, take_error};
+use crate::commands::WholeStreamCommand;
+use crate::context::Context;
+use crate::data::value::duration_seconds;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{channel, RecvTimeoutError};
+use std::time::{Duration, Instant};
+
+pub struct Timeout;
+
+impl WholeStreamCommand for Timeout {
+    fn name(&self) -> &str {
+        "timeout"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("timeout")
+            .required(
+                "block",
+                SyntaxShape::Block,
+                "the pipeline to run, with the input of timeout as its input (eg { watch })",
+            )
+            .required_named(
+                "duration",
+                SyntaxShape::Any,
+                "how long the pipeline may run (like 10s or 2m; plain numbers are seconds)",
+            )
+            .switch(
+                "partial",
+                "pass on the values that arrived before timing out instead of discarding them",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Runs the block's pipeline, stopping it with a timed out error if it runs too long."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        timeout(args, registry)
+    }
+}
+
+fn timeout(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let source = block_source(&args.call_info)?;
+    let mut context = Context::nested(
+        registry.clone(),
+        args.host.clone(),
+        args.shell_manager.clone(),
+    );
+
+    let args = args.evaluate_once(registry)?;
+    let name = args.name_tag();
+    let partial = args.has("partial");
+
+    let seconds = match args.get("duration") {
+        Some(duration) => duration_seconds(duration)?,
+        None => {
+            return Err(ShellError::labeled_error(
+                "Expected a duration",
+                "timeout needs --duration",
+                &name,
+            ))
+        }
+    };
+
+    let deadline = Instant::now() + Duration::from_secs(seconds);
+
+    let ctrl_c = args.ctrl_c.clone();
+    let cancel = CancelOnDrop(context.ctrl_c.clone());
+    let input = args.input;
+
+    // The block's pipeline may block while producing values (external commands read their
+    // output synchronously), so it's driven on its own thread where it can't hold up the
+    // deadline. Cancelling sets the ctrl_c of its context, which stops the commands in it.
+    let (tx, rx) = channel();
+    let stop = context.ctrl_c.clone();
+    std::thread::spawn(move || {
+        futures::executor::block_on(async move {
+            let mut values = match run_block(&source, &mut context, input).await {
+                Ok(values) => values.values,
+                Err(err) => {
+                    let _ = tx.send(Err(err));
+                    return;
+                }
+            };
+
+            while let Some(value) = values.next().await {
+                if stop.load(Ordering::SeqCst) || tx.send(Ok(value)).is_err() {
+                    return;
+                }
+            }
+
+            if let Some(err) = take_error(&mut context) {
+                let _ = tx.send(Err(err));
+            }
+        })
+    });
+
+    let stream = async_stream! {
+        let _cancel = cancel;
+        let mut received = vec![];
+
+        loop {
+            if ctrl_c.load(Ordering::SeqCst) {
+                return;
+            }
+
+            // Wait in short steps so that pressing ctrl-c doesn't have to wait for the deadline.
+            let remaining = deadline.saturating_duration_since(Instant::now());
+
+            match rx.recv_timeout(remaining.min(Duration::from_millis(100))) {
+                Ok(Ok(Value { value: UntaggedValue::Error(err), .. })) | Ok(Err(err)) => {
+                    yield Err(err);
+                    return;
+                }
+                Ok(Ok(value)) if partial => yield ReturnSuccess::value(value),
+                Ok(Ok(value)) => received.push(value),
+                Err(RecvTimeoutError::Disconnected) => break,
+                Err(RecvTimeoutError::Timeout) if Instant::now() < deadline => continue,
+                Err(RecvTimeoutError::Timeout) => {
+                    yield Err(ShellError::labeled_error(
+                        "Pipeline timed out",
+                        format!("timed out after {}s", seconds),
+                        &name,
+                    ));
+                    return;
+                }
+            }
+        }
+
+        for value in received {
+            yield ReturnSuccess::value(value);
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+/// Stops the block's pipeline once timeout is done with it, whether it finished, timed out,
+/// or is no longer read from.
+struct CancelOnDrop(Arc<AtomicBool>);
+
+impl Drop for CancelOnDrop {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+}
@@ -0,0 +1,90 @@
+use crate::commands::from_cookie::cookie_attribute;
+use crate::commands::WholeStreamCommand;
+use crate::data::value;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, ReturnSuccess, ShellTypeName, Signature, UntaggedValue, Value};
+
+pub struct ToCookie;
+
+#[derive(Deserialize)]
+pub struct ToCookieArgs {}
+
+impl WholeStreamCommand for ToCookie {
+    fn name(&self) -> &str {
+        "to-cookie"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("to-cookie")
+    }
+
+    fn usage(&self) -> &str {
+        "Convert a row of names and values into a cookie header value."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, to_cookie)?.run()
+    }
+}
+
+fn to_cookie(
+    ToCookieArgs {}: ToCookieArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    Ok(input
+        .values
+        .map(move |item| match &item.value {
+            UntaggedValue::Row(row) => {
+                let mut cookies = vec![];
+                let mut attributes = vec![];
+
+                for (key, cell) in row.entries.iter() {
+                    match cookie_attribute(key) {
+                        // Flags like Secure are left out entirely when false.
+                        Some(attribute) => match &cell.value {
+                            UntaggedValue::Primitive(Primitive::Boolean(true)) => {
+                                attributes.push(attribute.to_string())
+                            }
+                            UntaggedValue::Primitive(Primitive::Boolean(false)) => {}
+                            _ => attributes.push(format!("{}={}", attribute, cookie_text(cell)?)),
+                        },
+                        None => cookies.push(format!("{}={}", key, cookie_text(cell)?)),
+                    }
+                }
+
+                cookies.extend(attributes);
+
+                ReturnSuccess::value(value::string(cookies.join("; ")).into_value(&name))
+            }
+            _ => Err(ShellError::labeled_error_with_secondary(
+                "Expected a table from pipeline",
+                "requires table input",
+                &name,
+                "value originates from here",
+                item.tag(),
+            )),
+        })
+        .to_output_stream())
+}
+
+fn cookie_text(cell: &Value) -> Result<String, ShellError> {
+    match &cell.value {
+        UntaggedValue::Primitive(Primitive::String(s)) => Ok(s.clone()),
+        UntaggedValue::Primitive(Primitive::Line(s)) => Ok(s.clone()),
+        UntaggedValue::Primitive(Primitive::Int(i)) => Ok(i.to_string()),
+        UntaggedValue::Primitive(Primitive::Decimal(d)) => Ok(d.to_string()),
+        UntaggedValue::Primitive(Primitive::Date(date)) => {
+            Ok(date.format("%a, %d %b %Y %H:%M:%S GMT").to_string())
+        }
+        _ => Err(ShellError::labeled_error(
+            "Expected a string, number or date",
+            format!("found {} instead", cell.type_name()),
+            cell.tag(),
+        )),
+    }
+}
@@ -0,0 +1,124 @@
+use crate::commands::to_md::table_cells;
+use crate::commands::WholeStreamCommand;
+use crate::data::value;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, ReturnSuccess, Signature, UntaggedValue, Value};
+use unicode_width::UnicodeWidthStr;
+
+pub struct ToFixedWidth;
+
+#[derive(Deserialize)]
+pub struct ToFixedWidthArgs {
+    #[serde(rename(deserialize = "right-align"))]
+    right_align: bool,
+}
+
+impl WholeStreamCommand for ToFixedWidth {
+    fn name(&self) -> &str {
+        "to-fixed-width"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("to-fixed-width").switch(
+            "right-align",
+            "line up the numbers in numeric columns on the right",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Convert table into plain text with its columns lined up by spaces"
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, to_fixed_width)?.run()
+    }
+}
+
+fn to_fixed_width(
+    ToFixedWidthArgs { right_align }: ToFixedWidthArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let stream = async_stream! {
+        let input: Vec<Value> = input.values.collect().await;
+
+        if input.is_empty() {
+            return;
+        }
+
+        let (headers, rows) = table_cells(&input, None);
+
+        let widths: Vec<usize> = headers
+            .iter()
+            .enumerate()
+            .map(|(i, header)| {
+                rows.iter()
+                    .map(|row| row[i].width())
+                    .chain(std::iter::once(header.width()))
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+
+        let right: Vec<bool> = headers
+            .iter()
+            .map(|header| right_align && is_numeric_column(&input, header))
+            .collect();
+
+        let mut lines = vec![aligned_line(&headers, &widths, &right)];
+
+        for row in rows {
+            lines.push(aligned_line(&row, &widths, &right));
+        }
+
+        yield ReturnSuccess::value(value::string(lines.join("\n")).into_value(&name));
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+/// Pads by display width, so wide characters (eg 日本) take up the room they are shown in.
+fn aligned_line(cells: &[String], widths: &[usize], right: &[bool]) -> String {
+    let padded: Vec<String> = cells
+        .iter()
+        .enumerate()
+        .map(|(i, cell)| {
+            let padding = " ".repeat(widths[i] - cell.width());
+
+            if right[i] {
+                format!("{}{}", padding, cell)
+            } else {
+                format!("{}{}", cell, padding)
+            }
+        })
+        .collect();
+
+    padded.join("  ").trim_end().to_string()
+}
+
+/// Whether every filled in cell of the column holds a number.
+fn is_numeric_column(values: &[Value], header: &str) -> bool {
+    let mut any = false;
+
+    for item in values {
+        let cell = match &item.value {
+            UntaggedValue::Row(_) => item.get_data(header).borrow().clone(),
+            _ if header == "value" => item.clone(),
+            _ => continue,
+        };
+
+        match cell.value {
+            UntaggedValue::Primitive(Primitive::Nothing) => {}
+            UntaggedValue::Primitive(Primitive::Int(_))
+            | UntaggedValue::Primitive(Primitive::Decimal(_))
+            | UntaggedValue::Primitive(Primitive::Bytes(_)) => any = true,
+            _ => return false,
+        }
+    }
+
+    any
+}
@@ -0,0 +1,172 @@
+use crate::commands::get::get_column_path;
+use crate::commands::WholeStreamCommand;
+use crate::data::value;
+use crate::prelude::*;
+use bigdecimal::BigDecimal;
+use nu_errors::ShellError;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue,
+};
+use nu_source::Tagged;
+use num_traits::{One, Signed, Zero};
+
+pub struct ToFraction;
+
+#[derive(Deserialize)]
+pub struct ToFractionArgs {
+    column: ColumnPath,
+    limit: Option<Tagged<u64>>,
+}
+
+impl WholeStreamCommand for ToFraction {
+    fn name(&self) -> &str {
+        "to-fraction"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("to-fraction")
+            .required(
+                "column",
+                SyntaxShape::ColumnPath,
+                "the column holding the numbers to write as fractions",
+            )
+            .named(
+                "limit",
+                SyntaxShape::Int,
+                "the largest denominator to use, giving the closest fraction within it (eg 100, so 0.3333 is 1/3)",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Writes numbers as reduced fractions, eg 0.5 as 1/2."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, to_fraction)?.run()
+    }
+}
+
+fn to_fraction(
+    ToFractionArgs { column, limit }: ToFractionArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let limit = match limit {
+        Some(limit) if limit.item == 0 => {
+            return Err(ShellError::labeled_error(
+                "Expected a positive limit",
+                "the largest denominator must be at least 1",
+                limit.tag(),
+            ))
+        }
+        limit => limit.map(|limit| BigInt::from(limit.item)),
+    };
+
+    Ok(input
+        .values
+        .map(move |row| {
+            let cell = get_column_path(&column, &row)?;
+
+            let (numerator, denominator) = match &cell.value {
+                UntaggedValue::Primitive(Primitive::Int(i)) => (i.clone(), BigInt::one()),
+                UntaggedValue::Primitive(Primitive::Decimal(d)) => exact_fraction(d),
+                _ => {
+                    return Err(ShellError::labeled_error_with_secondary(
+                        "Expected a number",
+                        format!("found {} instead", cell.type_name()),
+                        cell.tag(),
+                        "row originates from here",
+                        row.tag(),
+                    ))
+                }
+            };
+
+            let (numerator, denominator) = match &limit {
+                Some(limit) => closest_fraction(&numerator, &denominator, limit),
+                None => (numerator, denominator),
+            };
+
+            let fraction = value::string(format!("{}/{}", numerator, denominator));
+
+            match row.replace_data_at_column_path(&column, fraction.into_value(cell.tag())) {
+                Some(row) => ReturnSuccess::value(row),
+                None => Err(ShellError::labeled_error(
+                    "to-fraction could not find the column",
+                    "column name",
+                    &name,
+                )),
+            }
+        })
+        .to_output_stream())
+}
+
+/// A decimal is its digits over a power of ten, which is then reduced.
+fn exact_fraction(decimal: &BigDecimal) -> (BigInt, BigInt) {
+    let (digits, scale) = decimal.as_bigint_and_exponent();
+    let ten = BigInt::from(10);
+
+    if scale >= 0 {
+        reduce(digits, num_traits::pow(ten, scale as usize))
+    } else {
+        (
+            digits * num_traits::pow(ten, (-scale) as usize),
+            BigInt::one(),
+        )
+    }
+}
+
+fn reduce(numerator: BigInt, denominator: BigInt) -> (BigInt, BigInt) {
+    let divisor = gcd(numerator.abs(), denominator.abs());
+
+    if divisor.is_zero() {
+        (numerator, denominator)
+    } else {
+        (numerator / &divisor, denominator / &divisor)
+    }
+}
+
+fn gcd(mut a: BigInt, mut b: BigInt) -> BigInt {
+    while !b.is_zero() {
+        let rest = &a % &b;
+        a = b;
+        b = rest;
+    }
+
+    a
+}
+
+/// Walks the continued fraction of the number, keeping the last convergent whose denominator
+/// is within the limit. Decimals cut short from a repeating one, eg 0.3333, come back as the
+/// fraction they were cut from.
+fn closest_fraction(numerator: &BigInt, denominator: &BigInt, limit: &BigInt) -> (BigInt, BigInt) {
+    if denominator <= limit {
+        return (numerator.clone(), denominator.clone());
+    }
+
+    let negative = numerator.is_negative();
+    let (mut n, mut d) = (numerator.abs(), denominator.clone());
+
+    let (mut h, mut previous_h) = (BigInt::one(), BigInt::zero());
+    let (mut k, mut previous_k) = (BigInt::zero(), BigInt::one());
+
+    while !d.is_zero() {
+        let a = &n / &d;
+        let next_h = &a * &h + &previous_h;
+        let next_k = &a * &k + &previous_k;
+
+        if &next_k > limit {
+            break;
+        }
+
+        previous_h = std::mem::replace(&mut h, next_h);
+        previous_k = std::mem::replace(&mut k, next_k);
+
+        let rest = &n - &a * &d;
+        n = std::mem::replace(&mut d, rest);
+    }
+
+    (if negative { -h } else { h }, k)
+}
@@ -0,0 +1,112 @@
+use crate::commands::to_md::table_cells;
+use crate::commands::WholeStreamCommand;
+use crate::data::value;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, Value};
+use nu_source::Tagged;
+
+pub struct ToHTML;
+
+#[derive(Deserialize)]
+pub struct ToHTMLArgs {
+    #[serde(rename(deserialize = "max-col-width"))]
+    max_col_width: Option<Tagged<usize>>,
+}
+
+impl WholeStreamCommand for ToHTML {
+    fn name(&self) -> &str {
+        "to-html"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("to-html").named(
+            "max-col-width",
+            SyntaxShape::Int,
+            "the most characters to show in a cell before cutting it off with …",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Convert table into an HTML table"
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, to_html)?.run()
+    }
+}
+
+fn to_html(
+    ToHTMLArgs { max_col_width }: ToHTMLArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let max_col_width = max_col_width.map(|width| width.item);
+
+    let stream = async_stream! {
+        let input: Vec<Value> = input.values.collect().await;
+
+        if input.is_empty() {
+            return;
+        }
+
+        // Cells are truncated before they are escaped, so an entity is never cut in half.
+        let (headers, rows) = table_cells(&input, max_col_width);
+        let mut lines = vec!["<table>".to_string()];
+
+        lines.push(html_row("th", &headers));
+
+        for row in rows {
+            lines.push(html_row("td", &row));
+        }
+
+        lines.push("</table>".to_string());
+
+        yield ReturnSuccess::value(value::string(lines.join("\n")).into_value(&name));
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+fn html_row(cell_tag: &str, cells: &[String]) -> String {
+    let cells: Vec<String> = cells
+        .iter()
+        .map(|cell| format!("<{}>{}</{}>", cell_tag, escape(cell), cell_tag))
+        .collect();
+
+    format!("<tr>{}</tr>", cells.concat())
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::html_row;
+    use crate::commands::to_md::truncate;
+
+    #[test]
+    fn escapes_cells_after_truncating_them() {
+        assert_eq!(
+            html_row("td", &[truncate("a & b", 4)]),
+            "<tr><td>a &amp;…</td></tr>"
+        );
+    }
+}
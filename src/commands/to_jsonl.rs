@@ -0,0 +1,59 @@
+use crate::commands::to_json::value_to_json_value;
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, ReturnSuccess, Signature, UntaggedValue};
+
+pub struct ToJSONL;
+
+#[derive(Deserialize)]
+pub struct ToJSONLArgs {}
+
+impl WholeStreamCommand for ToJSONL {
+    fn name(&self) -> &str {
+        "to-jsonl"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("to-jsonl")
+    }
+
+    fn usage(&self) -> &str {
+        "Convert each value into a line of .jsonl text (one JSON document per line)"
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, to_jsonl)?.run()
+    }
+}
+
+fn to_jsonl(
+    ToJSONLArgs {}: ToJSONLArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    Ok(input
+        .values
+        .map(move |value| {
+            let json_value = value_to_json_value(&value)?;
+
+            // Compact output escapes any newlines inside strings, so each value stays on its line.
+            match serde_json::to_string(&json_value) {
+                Ok(line) => ReturnSuccess::value(
+                    UntaggedValue::Primitive(Primitive::String(format!("{}\n", line)))
+                        .into_value(&name),
+                ),
+                Err(_) => Err(ShellError::labeled_error_with_secondary(
+                    "Expected a table with JSON-compatible structure from pipeline",
+                    "requires JSON-compatible input",
+                    &name,
+                    "originates from here",
+                    value.tag(),
+                )),
+            }
+        })
+        .to_output_stream())
+}
@@ -0,0 +1,155 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::value;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+use unicode_segmentation::UnicodeSegmentation;
+
+pub struct ToMD;
+
+#[derive(Deserialize)]
+pub struct ToMDArgs {
+    #[serde(rename(deserialize = "max-col-width"))]
+    max_col_width: Option<Tagged<usize>>,
+}
+
+impl WholeStreamCommand for ToMD {
+    fn name(&self) -> &str {
+        "to-md"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("to-md").named(
+            "max-col-width",
+            SyntaxShape::Int,
+            "the most characters to show in a cell before cutting it off with …",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Convert table into a Markdown table"
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, to_md)?.run()
+    }
+}
+
+fn to_md(
+    ToMDArgs { max_col_width }: ToMDArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let max_col_width = max_col_width.map(|width| width.item);
+
+    let stream = async_stream! {
+        let input: Vec<Value> = input.values.collect().await;
+
+        if input.is_empty() {
+            return;
+        }
+
+        let (headers, rows) = table_cells(&input, max_col_width);
+        let mut lines = vec![];
+
+        lines.push(markdown_row(&headers));
+        lines.push(markdown_row(&vec!["---".to_string(); headers.len()]));
+
+        for row in rows {
+            lines.push(markdown_row(&row));
+        }
+
+        yield ReturnSuccess::value(value::string(lines.join("\n")).into_value(&name));
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+fn markdown_row(cells: &[String]) -> String {
+    let cells: Vec<String> = cells
+        .iter()
+        .map(|cell| cell.replace('|', "\\|").replace('\n', " "))
+        .collect();
+
+    format!("| {} |", cells.join(" | "))
+}
+
+/// Lays the values out as a grid of plain cell strings, shared by the Markdown and HTML
+/// renderers. Rows contribute their columns; any other value goes under a `value` column.
+/// Cells are cut down to `max_col_width` before the renderers escape them.
+pub fn table_cells(
+    values: &[Value],
+    max_col_width: Option<usize>,
+) -> (Vec<String>, Vec<Vec<String>>) {
+    let mut headers: Vec<String> = vec![];
+
+    for item in values {
+        let columns = match &item.value {
+            UntaggedValue::Row(_) => item.data_descriptors(),
+            _ => vec!["value".to_string()],
+        };
+
+        for column in columns {
+            if !headers.contains(&column) {
+                headers.push(column);
+            }
+        }
+    }
+
+    let rows = values
+        .iter()
+        .map(|item| {
+            headers
+                .iter()
+                .map(|header| {
+                    let cell = match &item.value {
+                        UntaggedValue::Row(_) => item.get_data(header).borrow().clone(),
+                        _ if header == "value" => item.clone(),
+                        _ => value::nothing().into_untagged_value(),
+                    };
+
+                    let cell = value::format_leaf(&cell.value).plain_string(std::usize::MAX);
+
+                    match max_col_width {
+                        Some(width) => truncate(&cell, width),
+                        None => cell,
+                    }
+                })
+                .collect()
+        })
+        .collect();
+
+    (headers, rows)
+}
+
+/// Cuts the text down to `width` graphemes, ending with … when anything was dropped.
+pub fn truncate(text: &str, width: usize) -> String {
+    let graphemes: Vec<&str> = text.graphemes(true).collect();
+
+    if graphemes.len() <= width {
+        return text.to_string();
+    }
+
+    format!("{}…", graphemes[..width.saturating_sub(1)].concat())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::truncate;
+
+    #[test]
+    fn truncates_by_grapheme_with_an_ellipsis() {
+        assert_eq!(truncate("Andrés Robalino", 6), "André…");
+        assert_eq!(truncate("n\u{0303}u\u{0303}sh", 3), "n\u{0303}u\u{0303}…");
+    }
+
+    #[test]
+    fn leaves_short_text_alone() {
+        assert_eq!(truncate("nu", 2), "nu");
+        assert_eq!(truncate("", 0), "");
+    }
+}
@@ -0,0 +1,162 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use hex::encode;
+use nu_errors::ShellError;
+use nu_protocol::{
+    Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::Tagged;
+
+pub struct ToSQL;
+
+#[derive(Deserialize)]
+pub struct ToSQLArgs {
+    table: Option<Tagged<String>>,
+}
+
+impl WholeStreamCommand for ToSQL {
+    fn name(&self) -> &str {
+        "to-sql"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("to-sql").named(
+            "table",
+            SyntaxShape::String,
+            "the name of the table to insert the rows into (needed for any rows)",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Convert each row into an SQL INSERT statement, taking the columns from the first row"
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, to_sql)?.run()
+    }
+}
+
+fn to_sql(
+    ToSQLArgs { table }: ToSQLArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let stream = async_stream! {
+        let mut rows = input.values;
+        let mut columns: Option<Vec<String>> = None;
+
+        while let Some(row) = rows.next().await {
+            // Text passes through, so saving SQL that is already written to a .sql file works.
+            let dict = match &row.value {
+                UntaggedValue::Row(dict) => dict,
+                UntaggedValue::Primitive(Primitive::String(_))
+                | UntaggedValue::Primitive(Primitive::Line(_)) => {
+                    yield ReturnSuccess::value(row.clone());
+                    continue;
+                }
+                _ => {
+                    yield Err(ShellError::labeled_error_with_secondary(
+                        "Expected a row",
+                        format!("found {} instead", row.type_name()),
+                        row.tag(),
+                        "requires rows to insert",
+                        &name,
+                    ));
+                    return;
+                }
+            };
+
+            let table = match &table {
+                Some(table) => table,
+                None => {
+                    yield Err(ShellError::labeled_error(
+                        "Missing table name",
+                        "add --table <name> to insert rows",
+                        &name,
+                    ));
+                    return;
+                }
+            };
+
+            let columns = columns.get_or_insert_with(|| dict.keys().cloned().collect());
+
+            // Rows may leave out columns (they become NULL) but can't add any of their own.
+            if let Some(extra) = dict.keys().find(|column| !columns.contains(*column)) {
+                yield Err(ShellError::labeled_error(
+                    "Unknown column",
+                    format!("{} is not a column of the first row", extra),
+                    row.tag(),
+                ));
+                return;
+            }
+
+            let mut values = vec![];
+
+            for column in columns.iter() {
+                match dict.entries.get(column) {
+                    Some(cell) => match sql_value(cell) {
+                        Ok(value) => values.push(value),
+                        Err(err) => {
+                            yield Err(err);
+                            return;
+                        }
+                    },
+                    None => values.push("NULL".to_string()),
+                }
+            }
+
+            let statement = format!(
+                "INSERT INTO {} ({}) VALUES ({});\n",
+                sql_identifier(&table.item),
+                columns.iter().map(|c| sql_identifier(c)).collect::<Vec<_>>().join(", "),
+                values.join(", ")
+            );
+
+            yield ReturnSuccess::value(
+                UntaggedValue::Primitive(Primitive::String(statement)).into_value(&name),
+            );
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+/// Quotes a table or column name, so names with spaces or keywords still work.
+fn sql_identifier(name: &str) -> String {
+    format!("\"{}\"", name.replace("\"", "\"\""))
+}
+
+fn sql_string(text: &str) -> String {
+    format!("'{}'", text.replace("'", "''"))
+}
+
+fn sql_value(cell: &Value) -> Result<String, ShellError> {
+    match &cell.value {
+        UntaggedValue::Primitive(primitive) => match primitive {
+            Primitive::Nothing => Ok("NULL".to_string()),
+            Primitive::Int(i) => Ok(i.to_string()),
+            Primitive::Decimal(d) => Ok(d.to_string()),
+            Primitive::Bytes(b) => Ok(b.to_string()),
+            Primitive::Duration(seconds) => Ok(seconds.to_string()),
+            Primitive::Boolean(true) => Ok("TRUE".to_string()),
+            Primitive::Boolean(false) => Ok("FALSE".to_string()),
+            Primitive::String(s) | Primitive::Line(s) | Primitive::Pattern(s) => Ok(sql_string(s)),
+            Primitive::Path(p) => Ok(sql_string(&p.display().to_string())),
+            Primitive::Date(d) => Ok(sql_string(&d.to_rfc3339())),
+            Primitive::Binary(b) => Ok(format!("X'{}'", encode(b))),
+            _ => Err(not_sql(cell)),
+        },
+        _ => Err(not_sql(cell)),
+    }
+}
+
+fn not_sql(cell: &Value) -> ShellError {
+    ShellError::labeled_error(
+        "Expected a value SQL can hold",
+        format!("found {} instead", cell.type_name()),
+        cell.tag(),
+    )
+}
@@ -0,0 +1,129 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::value;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+
+pub struct ToTree;
+
+#[derive(Deserialize)]
+pub struct ToTreeArgs {
+    #[serde(rename(deserialize = "max-depth"))]
+    max_depth: Option<Tagged<usize>>,
+}
+
+impl WholeStreamCommand for ToTree {
+    fn name(&self) -> &str {
+        "to-tree"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("to-tree").named(
+            "max-depth",
+            SyntaxShape::Int,
+            "the number of levels to show before cutting branches off",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Convert table into an indented tree of text"
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, to_tree)?.run()
+    }
+}
+
+fn to_tree(
+    ToTreeArgs { max_depth }: ToTreeArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let max_depth = max_depth.map(|depth| depth.item);
+
+    let stream = async_stream! {
+        let input: Vec<Value> = input.values.collect().await;
+
+        let to_process_input = if input.len() > 1 {
+            let tag = input[0].tag.clone();
+            vec![Value { value: UntaggedValue::Table(input), tag } ]
+        } else if input.len() == 1 {
+            input
+        } else {
+            vec![]
+        };
+
+        for value in to_process_input {
+            yield ReturnSuccess::value(
+                value::string(render_tree(&value, max_depth)).into_value(&name),
+            );
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+pub fn render_tree(value: &Value, max_depth: Option<usize>) -> String {
+    let mut lines = vec![];
+
+    match branches(value) {
+        Some(children) => render_children(&children, "", 1, max_depth, &mut lines),
+        None => lines.push(leaf(value)),
+    }
+
+    lines.join("\n")
+}
+
+fn render_children(
+    children: &[(String, &Value)],
+    prefix: &str,
+    depth: usize,
+    max_depth: Option<usize>,
+    lines: &mut Vec<String>,
+) {
+    for (idx, (key, child)) in children.iter().enumerate() {
+        let last = idx == children.len() - 1;
+        let connector = if last { "└── " } else { "├── " };
+
+        match branches(child) {
+            Some(_) if max_depth.map_or(false, |max| depth >= max) => {
+                lines.push(format!("{}{}{}: …", prefix, connector, key));
+            }
+            Some(grandchildren) => {
+                lines.push(format!("{}{}{}", prefix, connector, key));
+
+                let prefix = format!("{}{}", prefix, if last { "    " } else { "│   " });
+                render_children(&grandchildren, &prefix, depth + 1, max_depth, lines);
+            }
+            None => lines.push(format!("{}{}{}: {}", prefix, connector, key, leaf(child))),
+        }
+    }
+}
+
+/// Rows branch by key and tables by index. Everything else (including empty rows and
+/// tables) is drawn as a leaf.
+fn branches(value: &Value) -> Option<Vec<(String, &Value)>> {
+    match &value.value {
+        UntaggedValue::Row(row) if !row.entries.is_empty() => Some(
+            row.entries
+                .iter()
+                .map(|(key, value)| (key.clone(), value))
+                .collect(),
+        ),
+        UntaggedValue::Table(rows) if !rows.is_empty() => Some(
+            rows.iter()
+                .enumerate()
+                .map(|(idx, value)| (idx.to_string(), value))
+                .collect(),
+        ),
+        _ => None,
+    }
+}
+
+fn leaf(value: &Value) -> String {
+    value::format_leaf(&value.value).plain_string(std::usize::MAX)
+}
@@ -0,0 +1,115 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::TaggedDictBuilder;
+use crate::prelude::*;
+use indexmap::IndexMap;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+
+pub struct Unflatten;
+
+#[derive(Deserialize)]
+pub struct UnflattenArgs {
+    separator: Option<Tagged<String>>,
+}
+
+impl WholeStreamCommand for Unflatten {
+    fn name(&self) -> &str {
+        "unflatten"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("unflatten").named(
+            "separator",
+            SyntaxShape::String,
+            "the separator between the parts of a column name (defaults to .)",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Nests columns sharing a prefix, eg a.x and a.y, back into rows."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, unflatten)?.run()
+    }
+}
+
+fn unflatten(
+    UnflattenArgs { separator }: UnflattenArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let separator = match separator {
+        Some(separator) if separator.item.is_empty() => {
+            return Err(ShellError::labeled_error(
+                "Expected a separator",
+                "the separator can't be empty",
+                separator.tag(),
+            ))
+        }
+        Some(separator) => separator.item,
+        None => ".".to_string(),
+    };
+
+    Ok(input
+        .values
+        .map(move |row| match &row.value {
+            UntaggedValue::Row(dict) => {
+                let entries = dict
+                    .entries
+                    .iter()
+                    .map(|(column, cell)| (split_column(column, &separator), cell.clone()))
+                    .collect();
+
+                ReturnSuccess::value(nest(entries, &row.tag)?)
+            }
+            _ => ReturnSuccess::value(row),
+        })
+        .to_output_stream())
+}
+
+/// Columns with an empty part, eg a..b, are left as they are.
+fn split_column(column: &str, separator: &str) -> Vec<String> {
+    let parts: Vec<String> = column.split(separator).map(String::from).collect();
+
+    if parts.iter().any(|part| part.is_empty()) {
+        vec![column.to_string()]
+    } else {
+        parts
+    }
+}
+
+fn nest(entries: Vec<(Vec<String>, Value)>, tag: &Tag) -> Result<Value, ShellError> {
+    let mut groups: IndexMap<String, Vec<(Vec<String>, Value)>> = IndexMap::new();
+
+    for (mut parts, cell) in entries {
+        let head = parts.remove(0);
+        groups
+            .entry(head)
+            .or_insert_with(Vec::new)
+            .push((parts, cell));
+    }
+
+    let mut row = TaggedDictBuilder::new(tag);
+
+    for (column, mut group) in groups {
+        if group.len() == 1 && group[0].0.is_empty() {
+            let (_, cell) = group.remove(0);
+            row.insert_value(column, cell);
+        } else if group.iter().any(|(rest, _)| rest.is_empty()) {
+            return Err(ShellError::labeled_error(
+                format!("Column {} is also a prefix of other columns", column),
+                "can't nest these columns",
+                tag,
+            ));
+        } else {
+            row.insert_value(column, nest(group, tag)?);
+        }
+    }
+
+    Ok(row.into_value())
+}
@@ -0,0 +1,88 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::base::shape::{Column, Shape};
+use crate::data::{value, TaggedDictBuilder};
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+
+pub struct Unify;
+
+#[derive(Deserialize)]
+pub struct UnifyArgs {
+    keys: Option<Vec<Tagged<String>>>,
+}
+
+impl WholeStreamCommand for Unify {
+    fn name(&self) -> &str {
+        "unify"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("unify").named(
+            "keys",
+            SyntaxShape::Any,
+            "a list of columns to put first, in this order, eg [name size]",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Gives every row the same columns, filling in the missing ones with nothing."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, unify)?.run()
+    }
+}
+
+fn unify(
+    UnifyArgs { keys }: UnifyArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let mut columns: Vec<String> = keys
+        .unwrap_or_else(Vec::new)
+        .into_iter()
+        .map(|key| key.item)
+        .collect();
+
+    let stream = async_stream! {
+        let rows: Vec<Value> = input.values.collect().await;
+
+        // Columns that weren't asked for keep the order they are first seen in.
+        for row in &rows {
+            if let Shape::Row(row_columns) = Shape::for_value(row) {
+                for column in row_columns {
+                    if let Column::String(column) = column {
+                        if !columns.contains(&column) {
+                            columns.push(column);
+                        }
+                    }
+                }
+            }
+        }
+
+        for row in rows {
+            match &row.value {
+                UntaggedValue::Row(_) => {
+                    let mut unified = TaggedDictBuilder::new(row.tag());
+
+                    for column in &columns {
+                        match row.get_data_by_key(column[..].spanned_unknown()) {
+                            Some(cell) => unified.insert_value(column.clone(), cell),
+                            None => unified.insert_untagged(column.clone(), value::nothing()),
+                        }
+                    }
+
+                    yield ReturnSuccess::value(unified.into_value());
+                }
+                _ => yield ReturnSuccess::value(row),
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
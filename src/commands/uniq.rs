@@ -0,0 +1,102 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::value;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+use std::collections::BTreeMap;
+
+pub struct Uniq;
+
+#[derive(Deserialize)]
+pub struct UniqArgs {
+    on: Option<Vec<Tagged<String>>>,
+    last: bool,
+}
+
+impl WholeStreamCommand for Uniq {
+    fn name(&self) -> &str {
+        "uniq"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("uniq")
+            .named(
+                "on",
+                SyntaxShape::Any,
+                "the columns that make rows the same, eg [name] (defaults to the whole row)",
+            )
+            .switch(
+                "last",
+                "keep the last of the same rows instead of the first",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Drops repeated values, keeping the first (or last) of each in their original order."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, uniq)?.run()
+    }
+}
+
+fn uniq(
+    UniqArgs { on, last }: UniqArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let stream = async_stream! {
+        let rows: Vec<Value> = input.values.collect().await;
+
+        // The index of the row that is kept for each key
+        let mut kept: BTreeMap<Vec<UntaggedValue>, usize> = BTreeMap::new();
+
+        for (index, row) in rows.iter().enumerate() {
+            let key = match uniq_key(row, &on) {
+                Ok(key) => key,
+                Err(err) => {
+                    yield Err(err);
+                    return;
+                }
+            };
+
+            if last {
+                kept.insert(key, index);
+            } else {
+                kept.entry(key).or_insert(index);
+            }
+        }
+
+        let mut indices: Vec<usize> = kept.values().cloned().collect();
+        indices.sort();
+
+        let mut rows: Vec<Option<Value>> = rows.into_iter().map(Some).collect();
+
+        for index in indices {
+            if let Some(row) = rows[index].take() {
+                yield ReturnSuccess::value(row);
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+fn uniq_key(
+    row: &Value,
+    on: &Option<Vec<Tagged<String>>>,
+) -> Result<Vec<UntaggedValue>, ShellError> {
+    let columns = match on {
+        None => return Ok(vec![row.value.clone()]),
+        Some(columns) => columns,
+    };
+
+    columns
+        .iter()
+        .map(|column| value::cell_at(row, column).map(|cell| cell.value))
+        .collect()
+}
@@ -0,0 +1,63 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::{value, TaggedDictBuilder};
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_parser::{Number, Unit};
+use nu_protocol::{Primitive, ReturnSuccess, Signature, UntaggedValue, Value};
+use num_traits::ToPrimitive;
+
+pub struct Units;
+
+#[derive(Deserialize)]
+pub struct UnitsArgs {}
+
+impl WholeStreamCommand for Units {
+    fn name(&self) -> &str {
+        "units"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("units")
+    }
+
+    fn usage(&self) -> &str {
+        "Lists the size and duration units that can follow a number, eg 5kb or 10s."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, units)?.run()
+    }
+}
+
+fn units(
+    UnitsArgs {}: UnitsArgs,
+    RunnableContext { name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let rows: Vec<_> = Unit::all()
+        .iter()
+        .map(|unit| ReturnSuccess::value(unit_row(*unit, &name)))
+        .collect();
+
+    Ok(futures::stream::iter(rows).to_output_stream())
+}
+
+fn unit_row(unit: Unit, tag: &Tag) -> Value {
+    let mut row = TaggedDictBuilder::new(tag);
+    row.insert_untagged("name", value::string(unit.as_str()));
+    row.insert_untagged("category", value::string(unit.category()));
+    row.insert_untagged("multiplier", value::int(multiplier(unit)));
+    row.into_value()
+}
+
+/// What `Unit::compute` turns a 1 into, as a plain number of bytes or seconds.
+pub(crate) fn multiplier(unit: Unit) -> u64 {
+    match unit.compute(&Number::from(1)) {
+        UntaggedValue::Primitive(Primitive::Int(bytes)) => bytes.to_u64().unwrap_or(0),
+        UntaggedValue::Primitive(Primitive::Duration(secs)) => secs,
+        _ => 0,
+    }
+}
@@ -0,0 +1,88 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::TaggedDictBuilder;
+use crate::prelude::*;
+use language_reporting::Label;
+use nu_errors::ShellError;
+use nu_protocol::{Evaluate, ReturnSuccess, Scope, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+
+pub struct UpdateCells;
+
+#[derive(Deserialize)]
+pub struct UpdateCellsArgs {
+    block: Evaluate,
+    rest: Vec<Tagged<String>>,
+}
+
+impl WholeStreamCommand for UpdateCells {
+    fn name(&self) -> &str {
+        "update-cells"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("update-cells")
+            .required(
+                "block",
+                SyntaxShape::Block,
+                "the block to run on each cell, with the cell as $it",
+            )
+            .rest(
+                SyntaxShape::String,
+                "the columns to update (defaults to every column)",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Replaces each cell in the given columns with the result of the block."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, update_cells)?.run()
+    }
+}
+
+fn update_cells(
+    UpdateCellsArgs { block, rest }: UpdateCellsArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let columns: Vec<String> = rest.into_iter().map(|column| column.item).collect();
+
+    Ok(input
+        .values
+        .map(move |row| match &row.value {
+            UntaggedValue::Row(dict) => {
+                let mut updated = TaggedDictBuilder::new(row.tag());
+
+                for (column, cell) in dict.entries.iter() {
+                    if columns.is_empty() || columns.contains(column) {
+                        updated.insert_value(column.clone(), update(&block, cell, column)?);
+                    } else {
+                        updated.insert_value(column.clone(), cell.clone());
+                    }
+                }
+
+                ReturnSuccess::value(updated.into_value())
+            }
+            _ if columns.is_empty() => {
+                ReturnSuccess::value(block.invoke(&Scope::new(row.clone()))?)
+            }
+            _ => ReturnSuccess::value(row.clone()),
+        })
+        .to_output_stream())
+}
+
+/// Keeps the block's own error, pointing out the cell it was updating as well.
+fn update(block: &Evaluate, cell: &Value, column: &str) -> Result<Value, ShellError> {
+    block.invoke(&Scope::new(cell.clone())).map_err(|err| {
+        let diagnostic = err.to_diagnostic().with_label(
+            Label::new_secondary(cell.tag.span)
+                .with_message(format!("while updating the {} column", column)),
+        );
+
+        ShellError::diagnostic(diagnostic)
+    })
+}
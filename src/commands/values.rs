@@ -0,0 +1,85 @@
+use crate::commands::get::get_column_path;
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, ReturnSuccess, Signature, SyntaxShape, UntaggedValue};
+use std::collections::BTreeSet;
+
+pub struct Values;
+
+#[derive(Deserialize)]
+pub struct ValuesArgs {
+    column: ColumnPath,
+    sorted: bool,
+}
+
+impl WholeStreamCommand for Values {
+    fn name(&self) -> &str {
+        "values"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("values")
+            .required(
+                "column",
+                SyntaxShape::ColumnPath,
+                "the column whose distinct values are returned",
+            )
+            .switch(
+                "sorted",
+                "return the values in order instead of as they first appear",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Returns the distinct values of a column as a list."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, values)?.run()
+    }
+}
+
+fn values(
+    ValuesArgs { column, sorted }: ValuesArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let stream = async_stream! {
+        let mut rows = input.values;
+        let mut seen: BTreeSet<UntaggedValue> = BTreeSet::new();
+        let mut found = vec![];
+
+        while let Some(row) = rows.next().await {
+            let cell = match get_column_path(&column, &row) {
+                Ok(cell) => cell,
+                Err(err) => {
+                    yield Err(err);
+                    return;
+                }
+            };
+
+            if !seen.insert(cell.value.clone()) {
+                continue;
+            }
+
+            // Unsorted values can go out as soon as they are first seen.
+            if sorted {
+                found.push(cell);
+            } else {
+                yield ReturnSuccess::value(cell);
+            }
+        }
+
+        found.sort_by(|left, right| left.value.cmp(&right.value));
+
+        for cell in found {
+            yield ReturnSuccess::value(cell);
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
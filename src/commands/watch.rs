@@ -0,0 +1,146 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::{value, TaggedDictBuilder};
+use crate::prelude::*;
+use notify::{DebouncedEvent, RecursiveMode, Watcher};
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, Value};
+use nu_source::Tagged;
+use std::path::PathBuf;
+use std::sync::atomic::Ordering;
+use std::sync::mpsc::{channel, TryRecvError};
+use std::time::{Duration, SystemTime};
+
+#[derive(Deserialize)]
+struct WatchArgs {
+    path: Option<Tagged<PathBuf>>,
+    recursive: bool,
+    debounce: Option<Tagged<usize>>,
+}
+
+pub struct Watch;
+
+impl WholeStreamCommand for Watch {
+    fn name(&self) -> &str {
+        "watch"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("watch")
+            .optional(
+                "path",
+                SyntaxShape::Path,
+                "the path to watch (defaults to the current directory)",
+            )
+            .switch("recursive", "watch subdirectories too")
+            .named(
+                "debounce",
+                SyntaxShape::Int,
+                "coalesce the events for a path arriving within this many milliseconds",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Streams the filesystem events for a path until interrupted."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, watch)?.run()
+    }
+}
+
+fn watch(
+    WatchArgs {
+        path,
+        recursive,
+        debounce,
+    }: WatchArgs,
+    RunnableContext {
+        name,
+        shell_manager,
+        ctrl_c,
+        ..
+    }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let cwd = PathBuf::from(shell_manager.path());
+    let (target, target_tag) = match path {
+        Some(path) => (cwd.join(&path.item), path.tag),
+        None => (cwd, name.clone()),
+    };
+
+    let delay = Duration::from_millis(debounce.map(|ms| ms.item as u64).unwrap_or(0));
+    let mode = if recursive {
+        RecursiveMode::Recursive
+    } else {
+        RecursiveMode::NonRecursive
+    };
+
+    let (tx, rx) = channel();
+    let mut watcher = notify::watcher(tx, delay).map_err(|err| {
+        ShellError::labeled_error("Could not start watching", err.to_string(), &name)
+    })?;
+
+    watcher.watch(&target, mode).map_err(|err| {
+        ShellError::labeled_error("Could not watch the path", err.to_string(), &target_tag)
+    })?;
+
+    let stream = async_stream! {
+        // Keep the watcher alive for as long as the stream is read
+        let _watcher = watcher;
+
+        loop {
+            if ctrl_c.load(Ordering::SeqCst) {
+                break;
+            }
+
+            let event = match rx.try_recv() {
+                Ok(event) => event,
+                Err(TryRecvError::Empty) => {
+                    futures_timer::Delay::new(Duration::from_millis(100)).await;
+                    continue;
+                }
+                Err(TryRecvError::Disconnected) => break,
+            };
+
+            match event {
+                DebouncedEvent::Error(err, _) => {
+                    yield Err(ShellError::labeled_error(
+                        "Error while watching",
+                        err.to_string(),
+                        &name,
+                    ));
+                }
+                event => {
+                    for (kind, path) in describe(event) {
+                        yield ReturnSuccess::value(event_row(kind, path, &name));
+                    }
+                }
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+/// Notices and rescans are dropped. A rename is reported as the removal of the old path
+/// followed by the creation of the new one.
+fn describe(event: DebouncedEvent) -> Vec<(&'static str, PathBuf)> {
+    match event {
+        DebouncedEvent::Create(path) => vec![("created", path)],
+        DebouncedEvent::Write(path) | DebouncedEvent::Chmod(path) => vec![("modified", path)],
+        DebouncedEvent::Remove(path) => vec![("removed", path)],
+        DebouncedEvent::Rename(from, to) => vec![("removed", from), ("created", to)],
+        _ => vec![],
+    }
+}
+
+fn event_row(kind: &str, path: PathBuf, tag: &Tag) -> Value {
+    let mut row = TaggedDictBuilder::new(tag);
+    row.insert_untagged("event", value::string(kind));
+    row.insert_untagged("path", value::path(path));
+    row.insert_untagged("timestamp", value::system_date(SystemTime::now()));
+    row.into_value()
+}
@@ -0,0 +1,192 @@
+use crate::commands::get::get_column_path;
+use crate::commands::WholeStreamCommand;
+use crate::data::value;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue,
+};
+use nu_source::Tagged;
+use textwrap::{NoHyphenation, Wrapper};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+pub struct WrapText;
+
+#[derive(Deserialize)]
+pub struct WrapTextArgs {
+    column: ColumnPath,
+    width: Tagged<usize>,
+    hard: bool,
+}
+
+impl WholeStreamCommand for WrapText {
+    fn name(&self) -> &str {
+        "wrap-text"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("wrap-text")
+            .required(
+                "column",
+                SyntaxShape::ColumnPath,
+                "the column holding the text to wrap",
+            )
+            .required_named(
+                "width",
+                SyntaxShape::Int,
+                "how many columns of the screen each line may take up",
+            )
+            .switch(
+                "hard",
+                "break words that are wider than a line instead of giving them a line of their own",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Wraps the text in a column onto lines that fit the width, breaking between words."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, wrap_text)?.run()
+    }
+}
+
+fn wrap_text(
+    WrapTextArgs {
+        column,
+        width,
+        hard,
+    }: WrapTextArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    if width.item == 0 {
+        return Err(ShellError::labeled_error(
+            "Expected a width of at least one column",
+            "width must be more than 0",
+            width.tag(),
+        ));
+    }
+
+    let width = width.item;
+
+    Ok(input
+        .values
+        .map(move |row| {
+            let cell = get_column_path(&column, &row)?;
+
+            let text = match &cell.value {
+                UntaggedValue::Primitive(Primitive::String(s)) => s,
+                UntaggedValue::Primitive(Primitive::Line(s)) => s,
+                _ => {
+                    return Err(ShellError::labeled_error_with_secondary(
+                        "Expected a string",
+                        format!("found {} instead", cell.type_name()),
+                        cell.tag(),
+                        "row originates from here",
+                        row.tag(),
+                    ))
+                }
+            };
+
+            let wrapped = value::string(wrap(text, width, hard)).into_value(cell.tag());
+
+            match row.replace_data_at_column_path(&column, wrapped) {
+                Some(row) => ReturnSuccess::value(row),
+                None => Err(ShellError::labeled_error(
+                    "wrap-text could not find the column",
+                    "column name",
+                    &name,
+                )),
+            }
+        })
+        .to_output_stream())
+}
+
+/// Line breaks already in the text are kept, and each line is wrapped on its own.
+fn wrap(text: &str, width: usize, hard: bool) -> String {
+    let wrapper = Wrapper::with_splitter(width, NoHyphenation).break_words(false);
+
+    text.lines()
+        .map(|line| wrap_line(line, width, hard, &wrapper).join("\n"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Words wider than the width get a line of their own, or are broken into pieces that fit
+/// when wrapping hard. The words between them are left to the wrapper, which loses track of
+/// the line width after a word it can't fit, so it only ever sees words that fit.
+fn wrap_line(
+    line: &str,
+    width: usize,
+    hard: bool,
+    wrapper: &Wrapper<NoHyphenation>,
+) -> Vec<String> {
+    let mut lines = vec![];
+    let mut fitting: Vec<String> = vec![];
+
+    for word in line.split(' ') {
+        let pieces = if hard && word.width() > width {
+            break_word(word, width)
+        } else {
+            vec![word.to_string()]
+        };
+
+        for piece in pieces {
+            if piece.width() > width {
+                fill(wrapper, &mut fitting, &mut lines);
+                lines.push(piece);
+            } else {
+                fitting.push(piece);
+            }
+        }
+    }
+
+    fill(wrapper, &mut fitting, &mut lines);
+
+    if lines.is_empty() {
+        lines.push(String::new());
+    }
+
+    lines
+}
+
+fn fill(wrapper: &Wrapper<NoHyphenation>, words: &mut Vec<String>, lines: &mut Vec<String>) {
+    let text = words.join(" ");
+    words.clear();
+
+    lines.extend(
+        wrapper
+            .wrap(&text)
+            .into_iter()
+            .map(|line| line.into_owned()),
+    );
+}
+
+/// Splits a word into pieces no wider than the width, between graphemes so that combining
+/// marks stay with their letter. A grapheme wider than the width (eg a CJK character when
+/// the width is 1) still gets a piece of its own.
+fn break_word(word: &str, width: usize) -> Vec<String> {
+    let mut pieces = vec![];
+    let mut current = String::new();
+    let mut current_width = 0;
+
+    for grapheme in word.graphemes(true) {
+        let grapheme_width = grapheme.width();
+
+        if current_width + grapheme_width > width && !current.is_empty() {
+            pieces.push(std::mem::replace(&mut current, String::new()));
+            current_width = 0;
+        }
+
+        current.push_str(grapheme);
+        current_width += grapheme_width;
+    }
+
+    pieces.push(current);
+    pieces
+}
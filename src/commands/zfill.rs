@@ -0,0 +1,127 @@
+use crate::commands::get::get_column_path;
+use crate::commands::WholeStreamCommand;
+use crate::data::value;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue,
+};
+use nu_source::Tagged;
+
+pub struct Zfill;
+
+#[derive(Deserialize)]
+pub struct ZfillArgs {
+    column: ColumnPath,
+    width: Tagged<usize>,
+}
+
+impl WholeStreamCommand for Zfill {
+    fn name(&self) -> &str {
+        "zfill"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("zfill")
+            .required(
+                "column",
+                SyntaxShape::ColumnPath,
+                "the column holding the numbers to pad",
+            )
+            .required(
+                "width",
+                SyntaxShape::Int,
+                "the width to pad the numbers to, counting the sign",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Pads the numbers in a column with leading zeros, keeping any sign in front."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, zfill)?.run()
+    }
+}
+
+fn zfill(
+    ZfillArgs { column, width }: ZfillArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let width = width.item;
+
+    Ok(input
+        .values
+        .map(move |row| {
+            let cell = get_column_path(&column, &row)?;
+
+            let text = match &cell.value {
+                UntaggedValue::Primitive(Primitive::Int(i)) => i.to_string(),
+                UntaggedValue::Primitive(Primitive::String(s)) => s.trim().to_string(),
+                UntaggedValue::Primitive(Primitive::Line(s)) => s.trim().to_string(),
+                _ => {
+                    return Err(ShellError::labeled_error_with_secondary(
+                        "Expected a number",
+                        format!("found {} instead", cell.type_name()),
+                        cell.tag(),
+                        "row originates from here",
+                        row.tag(),
+                    ))
+                }
+            };
+
+            let padded = match zero_filled(&text, width) {
+                Some(padded) => value::string(padded),
+                None => {
+                    return Err(ShellError::labeled_error_with_secondary(
+                        "Expected a number",
+                        format!("`{}` isn't a number", text),
+                        cell.tag(),
+                        "row originates from here",
+                        row.tag(),
+                    ))
+                }
+            };
+
+            match row.replace_data_at_column_path(&column, padded.into_value(cell.tag())) {
+                Some(row) => ReturnSuccess::value(row),
+                None => Err(ShellError::labeled_error(
+                    "zfill could not find the column",
+                    "column name",
+                    &name,
+                )),
+            }
+        })
+        .to_output_stream())
+}
+
+/// Puts zeros between the sign and the digits until the text is `width` long. Text that is
+/// already as wide is left as it is.
+fn zero_filled(text: &str, width: usize) -> Option<String> {
+    let (sign, digits) = match text.chars().next() {
+        Some(sign @ '-') | Some(sign @ '+') => (Some(sign), &text[1..]),
+        _ => (None, text),
+    };
+
+    let is_number = !digits.is_empty()
+        && digits.chars().any(|c| c.is_ascii_digit())
+        && digits.chars().all(|c| c.is_ascii_digit() || c == '.')
+        && digits.matches('.').count() <= 1;
+
+    if !is_number {
+        return None;
+    }
+
+    let zeros = width.saturating_sub(text.len());
+
+    Some(format!(
+        "{}{}{}",
+        sign.map(String::from).unwrap_or_default(),
+        "0".repeat(zeros),
+        digits
+    ))
+}
@@ -1,11 +1,12 @@
 use crate::commands::{command::CommandArgs, Command, UnevaluatedCallInfo};
+use crate::data::config;
 use crate::env::host::Host;
 use crate::shell::shell_manager::ShellManager;
 use crate::stream::{InputStream, OutputStream};
 use indexmap::IndexMap;
 use nu_errors::ShellError;
 use nu_parser::{hir, hir::syntax_shape::ExpandContext, hir::syntax_shape::SignatureRegistry};
-use nu_protocol::{errln, Signature};
+use nu_protocol::{errln, Primitive, Signature, UntaggedValue};
 use nu_source::{Tag, Text};
 use std::error::Error;
 use std::sync::atomic::AtomicBool;
@@ -69,6 +70,21 @@ impl CommandRegistry {
     }
 }
 
+/// Bare words are strings unless strict mode is turned on. A config that can't be read
+/// leaves it off rather than stopping every command.
+fn strict_mode() -> bool {
+    let config = match config::config(Tag::unknown()) {
+        Ok(config) => config,
+        Err(_) => return false,
+    };
+
+    match config.get("strict").map(|value| &value.value) {
+        Some(UntaggedValue::Primitive(Primitive::Boolean(strict))) => *strict,
+        Some(UntaggedValue::Primitive(Primitive::String(strict))) => strict == "true",
+        _ => false,
+    }
+}
+
 #[derive(Clone)]
 pub struct Context {
     registry: CommandRegistry,
@@ -76,6 +92,7 @@ pub struct Context {
     pub current_errors: Arc<Mutex<Vec<ShellError>>>,
     pub ctrl_c: Arc<AtomicBool>,
     pub(crate) shell_manager: ShellManager,
+    strict: bool,
 }
 
 impl Context {
@@ -92,6 +109,24 @@ impl Context {
             source,
             self.shell_manager.homedir(),
         )
+        .strict(self.strict)
+    }
+
+    /// A context for a pipeline run by a command, with a ctrl_c of its own so that the
+    /// command can stop the pipeline without interrupting the one it is part of.
+    pub(crate) fn nested(
+        registry: CommandRegistry,
+        host: Arc<Mutex<Box<dyn Host>>>,
+        shell_manager: ShellManager,
+    ) -> Context {
+        Context {
+            registry,
+            host,
+            current_errors: Arc::new(Mutex::new(vec![])),
+            ctrl_c: Arc::new(AtomicBool::new(false)),
+            shell_manager,
+            strict: strict_mode(),
+        }
     }
 
     pub(crate) fn basic() -> Result<Context, Box<dyn Error>> {
@@ -102,6 +137,7 @@ impl Context {
             current_errors: Arc::new(Mutex::new(vec![])),
             ctrl_c: Arc::new(AtomicBool::new(false)),
             shell_manager: ShellManager::basic(registry)?,
+            strict: strict_mode(),
         })
     }
 
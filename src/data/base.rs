@@ -185,12 +185,18 @@ fn coerce_compare_primitive(
         (Bytes(left), Decimal(right)) => {
             CompareValues::Decimals(BigDecimal::from(*left), right.clone())
         }
+        (Bytes(left), Bytes(right)) => {
+            CompareValues::Ints(BigInt::from(*left), BigInt::from(*right))
+        }
         (String(left), String(right)) => CompareValues::String(left.clone(), right.clone()),
         (Line(left), String(right)) => CompareValues::String(left.clone(), right.clone()),
         (String(left), Line(right)) => CompareValues::String(left.clone(), right.clone()),
         (Line(left), Line(right)) => CompareValues::String(left.clone(), right.clone()),
         (Date(left), Date(right)) => CompareValues::Date(left.clone(), right.clone()),
         (Date(left), Duration(right)) => CompareValues::DateDuration(left.clone(), right.clone()),
+        (Duration(left), Duration(right)) => {
+            CompareValues::Ints(BigInt::from(*left), BigInt::from(*right))
+        }
         _ => return Err((left.type_name(), right.type_name())),
     })
 }
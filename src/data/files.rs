@@ -37,9 +37,11 @@ pub(crate) fn dir_entry_dict(
 
         #[cfg(unix)]
         {
-            use std::os::unix::fs::PermissionsExt;
+            use std::os::unix::fs::{MetadataExt, PermissionsExt};
             let mode = metadata.permissions().mode();
             dict.insert_untagged("mode", value::string(umask::Mode::from(mode).to_string()));
+            dict.insert_untagged("uid", value::int(metadata.uid()));
+            dict.insert_untagged("gid", value::int(metadata.gid()));
         }
     }
 
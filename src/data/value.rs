@@ -1,16 +1,18 @@
 use crate::data::base::coerce_compare;
+use crate::data::base::property_get::get_data_by_key;
 use crate::data::base::shape::{Column, InlineShape, TypeShape};
 use crate::data::primitive::style_primitive;
 use crate::data::value;
 use bigdecimal::BigDecimal;
-use chrono::DateTime;
+use chrono::{DateTime, Utc};
 use indexmap::IndexMap;
 use nu_errors::ShellError;
 use nu_parser::Number;
 use nu_parser::Operator;
-use nu_protocol::{ColumnPath, PathMember, Primitive, UntaggedValue, Value};
+use nu_protocol::{ColumnPath, PathMember, Primitive, ShellTypeName, UntaggedValue, Value};
 use nu_source::{DebugDocBuilder, PrettyDebug, Tagged};
 use num_bigint::BigInt;
+use num_traits::{ToPrimitive, Zero};
 use std::path::PathBuf;
 use std::time::SystemTime;
 
@@ -82,6 +84,10 @@ pub fn system_date(s: SystemTime) -> UntaggedValue {
     UntaggedValue::Primitive(Primitive::Date(s.into()))
 }
 
+pub fn date(d: impl Into<DateTime<Utc>>) -> UntaggedValue {
+    UntaggedValue::Primitive(Primitive::Date(d.into()))
+}
+
 pub fn date_from_str(s: Tagged<&str>) -> Result<UntaggedValue, ShellError> {
     let date = DateTime::parse_from_rfc3339(s.item).map_err(|err| {
         ShellError::labeled_error(
@@ -100,6 +106,73 @@ pub fn nothing() -> UntaggedValue {
     UntaggedValue::Primitive(Primitive::Nothing)
 }
 
+/// The number an int or decimal holds, for commands that do their math in floats.
+pub fn as_f64<'a>(value: impl Into<&'a UntaggedValue>) -> Option<f64> {
+    match value.into() {
+        UntaggedValue::Primitive(Primitive::Int(i)) => i.to_f64(),
+        UntaggedValue::Primitive(Primitive::Decimal(d)) => d.to_f64(),
+        _ => None,
+    }
+}
+
+/// The number an int or decimal holds, without rounding it to a float.
+pub fn as_decimal<'a>(value: impl Into<&'a UntaggedValue>) -> Option<BigDecimal> {
+    match value.into() {
+        UntaggedValue::Primitive(Primitive::Int(i)) => Some(BigDecimal::from(i.clone())),
+        UntaggedValue::Primitive(Primitive::Decimal(d)) => Some(d.clone()),
+        _ => None,
+    }
+}
+
+/// The number in a cell of the row, erroring on the cell if it holds anything else.
+pub fn number_in(cell: &Value, row: &Value) -> Result<f64, ShellError> {
+    as_f64(cell).ok_or_else(|| {
+        ShellError::labeled_error_with_secondary(
+            "Expected a number",
+            format!("found {} instead", cell.type_name()),
+            cell.tag(),
+            "row originates from here",
+            row.tag(),
+        )
+    })
+}
+
+/// The cell in the given column of the row, erroring on the column if the row lacks it.
+pub fn cell_at(row: &Value, column: &Tagged<String>) -> Result<Value, ShellError> {
+    match get_data_by_key(row, column.borrow_spanned()) {
+        Some(cell) => Ok(cell),
+        None => Err(ShellError::labeled_error_with_secondary(
+            "Unknown column",
+            "row does not contain this column",
+            column.tag(),
+            "value originates from here",
+            row.tag(),
+        )),
+    }
+}
+
+/// The number in the given column of the row, erroring if the row lacks the column or the
+/// cell holds anything but a number.
+pub fn number_at(row: &Value, column: &Tagged<String>) -> Result<f64, ShellError> {
+    number_in(&cell_at(row, column)?, row)
+}
+
+/// Accepts durations like `10s`, or plain numbers counted as seconds.
+pub fn duration_seconds(duration: &Value) -> Result<u64, ShellError> {
+    match &duration.value {
+        UntaggedValue::Primitive(Primitive::Duration(secs)) => Some(*secs),
+        UntaggedValue::Primitive(Primitive::Int(secs)) => secs.to_u64(),
+        _ => None,
+    }
+    .ok_or_else(|| {
+        ShellError::labeled_error(
+            "Expected a duration",
+            "expected a duration like 10s",
+            duration.tag(),
+        )
+    })
+}
+
 pub fn compare_values(
     operator: &Operator,
     left: &UntaggedValue,
@@ -131,6 +204,43 @@ pub fn compare_values(
     }
 }
 
+pub fn compute_values(
+    operator: &Operator,
+    left: &UntaggedValue,
+    right: &UntaggedValue,
+) -> Result<UntaggedValue, (&'static str, &'static str)> {
+    match (left, right) {
+        (UntaggedValue::Primitive(lhs), UntaggedValue::Primitive(rhs)) => match (lhs, rhs) {
+            (Primitive::Int(x), Primitive::Int(y)) => match operator {
+                Operator::Plus => Ok(int(x + y)),
+                Operator::Minus => Ok(int(x - y)),
+                _ => Err((left.type_name(), right.type_name())),
+            },
+            (Primitive::Int(x), Primitive::Decimal(y)) => match operator {
+                Operator::Plus => Ok(decimal(BigDecimal::zero() + x + y)),
+                Operator::Minus => Ok(decimal(BigDecimal::zero() + x - y)),
+                _ => Err((left.type_name(), right.type_name())),
+            },
+            (Primitive::Decimal(x), Primitive::Int(y)) => match operator {
+                Operator::Plus => Ok(decimal(x.clone() + (BigDecimal::zero() + y))),
+                Operator::Minus => Ok(decimal(x.clone() - (BigDecimal::zero() + y))),
+                _ => Err((left.type_name(), right.type_name())),
+            },
+            (Primitive::Decimal(x), Primitive::Decimal(y)) => match operator {
+                Operator::Plus => Ok(decimal(x.clone() + y)),
+                Operator::Minus => Ok(decimal(x.clone() - y)),
+                _ => Err((left.type_name(), right.type_name())),
+            },
+            _ => Err((left.type_name(), right.type_name())),
+        },
+        (UntaggedValue::Table(x), UntaggedValue::Table(y)) => match operator {
+            Operator::Plus => Ok(UntaggedValue::Table(x.iter().chain(y).cloned().collect())),
+            _ => Err((left.type_name(), right.type_name())),
+        },
+        _ => Err((left.type_name(), right.type_name())),
+    }
+}
+
 pub fn format_type<'a>(value: impl Into<&'a UntaggedValue>, width: usize) -> String {
     TypeShape::from_value(value.into()).colored_string(width)
 }
@@ -20,6 +20,7 @@ pub fn apply_operator(
         Operator::Dot => Ok(value::boolean(false)),
         Operator::Contains => contains(left, right).map(value::boolean),
         Operator::NotContains => contains(left, right).map(Not::not).map(value::boolean),
+        Operator::Plus | Operator::Minus => value::compute_values(op, left, right),
     }
 }
 
@@ -1,5 +1,6 @@
 use crate::commands::command::EvaluatedWholeStreamCommandArgs;
 use crate::commands::cp::CopyArgs;
+use crate::commands::ls::LsArgs;
 use crate::commands::mkdir::MkdirArgs;
 use crate::commands::mv::MoveArgs;
 use crate::commands::rm::RemoveArgs;
@@ -10,7 +11,6 @@ use crate::shell::shell::Shell;
 use crate::utils::FileStructure;
 use nu_errors::ShellError;
 use nu_protocol::{Primitive, ReturnSuccess, UntaggedValue};
-use nu_source::Tagged;
 use rustyline::completion::FilenameCompleter;
 use rustyline::hint::{Hinter, HistoryHinter};
 use std::path::{Path, PathBuf};
@@ -87,10 +87,16 @@ impl Shell for FilesystemShell {
 
     fn ls(
         &self,
-        pattern: Option<Tagged<PathBuf>>,
+        LsArgs {
+            path: pattern,
+            full,
+            all,
+            long,
+            resolve,
+        }: LsArgs,
         context: &RunnableContext,
-        full: bool,
     ) -> Result<OutputStream, ShellError> {
+        let full = full || long;
         let cwd = self.path();
         let mut full_path = PathBuf::from(self.path());
 
@@ -133,7 +139,10 @@ impl Shell for FilesystemShell {
                         }
                         if let Ok(entry) = entry {
                             let filepath = entry.path();
-                            if let Ok(metadata) = std::fs::symlink_metadata(&filepath) {
+                            if !all && is_hidden(&filepath) {
+                                continue;
+                            }
+                            if let Ok(metadata) = entry_metadata(&filepath, resolve) {
                                 let filename = if let Ok(fname) = filepath.strip_prefix(&cwd) {
                                     fname
                                 } else {
@@ -165,6 +174,9 @@ impl Shell for FilesystemShell {
             }
         };
 
+        // Hidden files are only hidden when the pattern itself doesn't ask for them
+        let show_hidden = all || is_hidden(&full_path);
+
         // Enumerate the entries from the glob and add each
         let stream = async_stream! {
             for entry in entries {
@@ -172,7 +184,10 @@ impl Shell for FilesystemShell {
                     break;
                 }
                 if let Ok(entry) = entry {
-                    if let Ok(metadata) = std::fs::symlink_metadata(&entry) {
+                    if !show_hidden && is_hidden(&entry) {
+                        continue;
+                    }
+                    if let Ok(metadata) = entry_metadata(&entry, resolve) {
                         let filename = if let Ok(fname) = entry.strip_prefix(&cwd) {
                             fname
                         } else {
@@ -1137,3 +1152,21 @@ impl Shell for FilesystemShell {
         self.hinter.hint(line, pos, ctx)
     }
 }
+
+fn is_hidden(path: &Path) -> bool {
+    path.file_name()
+        .map(|name| name.to_string_lossy().starts_with('.'))
+        .unwrap_or(false)
+}
+
+/// With `resolve`, symlinks are followed so they report what they point to. Broken links
+/// fall back to the link itself.
+fn entry_metadata(path: &Path, resolve: bool) -> std::io::Result<std::fs::Metadata> {
+    let metadata = std::fs::symlink_metadata(path)?;
+
+    if resolve && metadata.file_type().is_symlink() {
+        return Ok(std::fs::metadata(path).unwrap_or(metadata));
+    }
+
+    Ok(metadata)
+}
@@ -1,5 +1,6 @@
 use crate::commands::command::EvaluatedWholeStreamCommandArgs;
 use crate::commands::cp::CopyArgs;
+use crate::commands::ls::LsArgs;
 use crate::commands::mkdir::MkdirArgs;
 use crate::commands::mv::MoveArgs;
 use crate::commands::rm::RemoveArgs;
@@ -8,7 +9,6 @@ use crate::prelude::*;
 use crate::shell::shell::Shell;
 use nu_errors::ShellError;
 use nu_protocol::{Primitive, ReturnSuccess, ShellTypeName, UntaggedValue, Value};
-use nu_source::Tagged;
 use std::ffi::OsStr;
 use std::path::PathBuf;
 
@@ -132,12 +132,7 @@ impl Shell for HelpShell {
         self.path = path.clone();
     }
 
-    fn ls(
-        &self,
-        _pattern: Option<Tagged<PathBuf>>,
-        _context: &RunnableContext,
-        _full: bool,
-    ) -> Result<OutputStream, ShellError> {
+    fn ls(&self, _args: LsArgs, _context: &RunnableContext) -> Result<OutputStream, ShellError> {
         Ok(self
             .commands()
             .map(|x| ReturnSuccess::value(x))
@@ -1,24 +1,19 @@
 use crate::commands::command::EvaluatedWholeStreamCommandArgs;
 use crate::commands::cp::CopyArgs;
+use crate::commands::ls::LsArgs;
 use crate::commands::mkdir::MkdirArgs;
 use crate::commands::mv::MoveArgs;
 use crate::commands::rm::RemoveArgs;
 use crate::prelude::*;
 use crate::stream::OutputStream;
 use nu_errors::ShellError;
-use nu_source::Tagged;
 use std::path::PathBuf;
 
 pub trait Shell: std::fmt::Debug {
     fn name(&self) -> String;
     fn homedir(&self) -> Option<PathBuf>;
 
-    fn ls(
-        &self,
-        pattern: Option<Tagged<PathBuf>>,
-        context: &RunnableContext,
-        full: bool,
-    ) -> Result<OutputStream, ShellError>;
+    fn ls(&self, args: LsArgs, context: &RunnableContext) -> Result<OutputStream, ShellError>;
     fn cd(&self, args: EvaluatedWholeStreamCommandArgs) -> Result<OutputStream, ShellError>;
     fn cp(&self, args: CopyArgs, name: Tag, path: &str) -> Result<OutputStream, ShellError>;
     fn mkdir(&self, args: MkdirArgs, name: Tag, path: &str) -> Result<OutputStream, ShellError>;
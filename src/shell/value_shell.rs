@@ -1,5 +1,6 @@
 use crate::commands::command::EvaluatedWholeStreamCommandArgs;
 use crate::commands::cp::CopyArgs;
+use crate::commands::ls::LsArgs;
 use crate::commands::mkdir::MkdirArgs;
 use crate::commands::mv::MoveArgs;
 use crate::commands::rm::RemoveArgs;
@@ -8,7 +9,6 @@ use crate::shell::shell::Shell;
 use crate::utils::ValueStructure;
 use nu_errors::ShellError;
 use nu_protocol::{ReturnSuccess, ShellTypeName, UntaggedValue, Value};
-use nu_source::Tagged;
 use std::ffi::OsStr;
 use std::path::{Path, PathBuf};
 
@@ -91,9 +91,8 @@ impl Shell for ValueShell {
 
     fn ls(
         &self,
-        target: Option<Tagged<PathBuf>>,
+        LsArgs { path: target, .. }: LsArgs,
         context: &RunnableContext,
-        _full: bool,
     ) -> Result<OutputStream, ShellError> {
         let mut full_path = PathBuf::from(self.path());
         let name_tag = context.name.clone();
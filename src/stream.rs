@@ -1,5 +1,15 @@
 use crate::prelude::*;
+use nu_errors::ShellError;
 use nu_protocol::{ReturnSuccess, ReturnValue, Value};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+fn interrupted(tag: Tag) -> ShellError {
+    ShellError::labeled_error(
+        "Interrupted",
+        "ctrl-c was pressed before the input was converted",
+        tag,
+    )
+}
 
 pub struct InputStream {
     pub(crate) values: BoxStream<'static, Value>,
@@ -17,6 +27,19 @@ impl InputStream {
         values.collect()
     }
 
+    /// Ends the stream once `ctrl_c` is set, so that a command collecting all of its input
+    /// stops pulling it. The output of such a command should be made interruptible too, so
+    /// that what it makes of the partial input ends in an error instead of passing for all
+    /// of it.
+    pub fn interruptible(self, ctrl_c: &Arc<AtomicBool>) -> InputStream {
+        let ctrl_c = ctrl_c.clone();
+
+        InputStream::from_stream(
+            self.values
+                .take_while(move |_| futures::future::ready(!ctrl_c.load(Ordering::SeqCst))),
+        )
+    }
+
     pub fn from_stream(input: impl Stream<Item = Value> + Send + 'static) -> InputStream {
         InputStream {
             values: input.boxed(),
@@ -88,6 +111,28 @@ impl OutputStream {
         }
     }
 
+    /// Once `ctrl_c` is set, the values left are dropped and the stream ends with an
+    /// "Interrupted" error labeled at `tag`.
+    pub fn interruptible(self, ctrl_c: &Arc<AtomicBool>, tag: impl Into<Tag>) -> OutputStream {
+        let ctrl_c = ctrl_c.clone();
+        let tag = tag.into();
+        let mut values = self.values;
+
+        OutputStream::new(async_stream! {
+            while let Some(value) = values.next().await {
+                if ctrl_c.load(Ordering::SeqCst) {
+                    break;
+                }
+
+                yield value;
+            }
+
+            if ctrl_c.load(Ordering::SeqCst) {
+                yield Err(interrupted(tag));
+            }
+        })
+    }
+
     pub fn drain_vec(&mut self) -> impl Future<Output = Vec<ReturnValue>> {
         let mut values: BoxStream<'static, ReturnValue> = VecDeque::new().boxed();
         std::mem::swap(&mut values, &mut self.values);
@@ -170,3 +215,81 @@ impl From<Vec<Value>> for OutputStream {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{InputStream, OutputStream};
+    use crate::data::value;
+    use futures::executor::block_on;
+    use futures::stream::{self, StreamExt};
+    use nu_protocol::{ReturnSuccess, ReturnValue};
+    use nu_source::Tag;
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[test]
+    fn passes_everything_on_when_not_interrupted() {
+        let ctrl_c = Arc::new(AtomicBool::new(false));
+        let input: InputStream = vec![
+            value::int(1).into_untagged_value(),
+            value::int(2).into_untagged_value(),
+        ]
+        .into();
+
+        let output =
+            OutputStream::from(input.interruptible(&ctrl_c)).interruptible(&ctrl_c, Tag::unknown());
+        let output: Vec<ReturnValue> = block_on(output.collect());
+
+        assert_eq!(output.len(), 2);
+        assert!(output.iter().all(|value| value.is_ok()));
+    }
+
+    #[test]
+    fn stops_pulling_input_once_interrupted() {
+        let ctrl_c = Arc::new(AtomicBool::new(false));
+        let pulled = Arc::new(AtomicUsize::new(0));
+
+        let flag = ctrl_c.clone();
+        let counter = pulled.clone();
+        let values = stream::iter(0..1_000_000).map(move |n| {
+            if counter.fetch_add(1, Ordering::SeqCst) == 2 {
+                flag.store(true, Ordering::SeqCst);
+            }
+
+            value::int(n).into_untagged_value()
+        });
+
+        let collected: Vec<_> = block_on(
+            InputStream::from_stream(values)
+                .interruptible(&ctrl_c)
+                .collect(),
+        );
+
+        assert_eq!(collected.len(), 2);
+        assert_eq!(pulled.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn ends_output_with_an_error_once_interrupted() {
+        let ctrl_c = Arc::new(AtomicBool::new(false));
+
+        let flag = ctrl_c.clone();
+        let values = stream::iter(0..10).map(move |n| {
+            if n == 2 {
+                flag.store(true, Ordering::SeqCst);
+            }
+
+            ReturnSuccess::value(value::int(n).into_untagged_value())
+        });
+
+        let output: Vec<ReturnValue> = block_on(
+            OutputStream::new(values)
+                .interruptible(&ctrl_c, Tag::unknown())
+                .collect(),
+        );
+
+        assert_eq!(output.len(), 3);
+        assert!(output[..2].iter().all(|value| value.is_ok()));
+        assert!(output[2].is_err());
+    }
+}
@@ -70,3 +70,137 @@ fn ls_lists_regular_files_using_question_mark_wildcard() {
         assert_eq!(actual, "3");
     })
 }
+
+#[test]
+fn ls_lists_dotfiles() {
+    Playground::setup("ls_test_4", |dirs, sandbox| {
+        sandbox
+            .with_files(vec![
+                EmptyFile("yehuda.txt"),
+                EmptyFile(".jonathan.txt"),
+                EmptyFile(".andres"),
+            ])
+            .mkdir("projects");
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                ls
+                | count
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "4");
+    })
+}
+
+#[test]
+fn list_hides_dotfiles_unless_all_is_given() {
+    Playground::setup("list_test_1", |dirs, sandbox| {
+        sandbox
+            .with_files(vec![
+                EmptyFile("yehuda.txt"),
+                EmptyFile(".jonathan.txt"),
+                EmptyFile(".andres"),
+            ])
+            .mkdir("projects");
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                list
+                | count
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "2");
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                list --all
+                | count
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "4");
+    })
+}
+
+#[test]
+fn ls_reports_directories_in_the_type_column() {
+    Playground::setup("ls_test_5", |dirs, sandbox| {
+        sandbox
+            .with_files(vec![EmptyFile("yehuda.txt")])
+            .mkdir("projects")
+            .mkdir("notes");
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                ls
+                | where type == Directory
+                | count
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "2");
+    })
+}
+
+#[cfg(unix)]
+#[test]
+fn ls_long_adds_permission_and_owner_columns() {
+    Playground::setup("ls_test_6", |dirs, sandbox| {
+        sandbox.with_files(vec![EmptyFile("yehuda.txt")]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                ls --long
+                | get uid
+                | count
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "1");
+    })
+}
+
+#[cfg(unix)]
+#[test]
+fn ls_resolve_reports_what_symlinks_point_to() {
+    Playground::setup("ls_test_7", |dirs, sandbox| {
+        sandbox.with_files(vec![EmptyFile("yehuda.txt")]);
+
+        std::os::unix::fs::symlink(dirs.test().join("yehuda.txt"), dirs.test().join("link"))
+            .expect("can not create symlink");
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                ls link
+                | get type
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "Symlink");
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                ls --resolve link
+                | get type
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "File");
+    })
+}
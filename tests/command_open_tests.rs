@@ -228,3 +228,81 @@ fn errors_if_file_not_found() {
     assert!(actual.contains("File could not be opened"));
     assert!(actual.contains("file not found"));
 }
+
+#[test]
+fn open_glob_combines_the_matching_files() {
+    Playground::setup("open_test_glob_1", |dirs, sandbox| {
+        sandbox.with_files(vec![
+            FileWithContentToBeTrimmed(
+                "january.csv",
+                r#"
+                    name,amount
+                    rent,800
+                    food,200
+                "#,
+            ),
+            FileWithContentToBeTrimmed(
+                "february.csv",
+                r#"
+                    name,amount
+                    rent,800
+                "#,
+            ),
+            FileWithContentToBeTrimmed("notes.txt", "not a table"),
+        ]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open *.csv --glob
+                | where source == january.csv
+                | count
+                | echo $it
+            "#
+        ));
+
+        let total = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open *.csv --glob
+                | count
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "2");
+        assert_eq!(total, "3");
+    })
+}
+
+#[test]
+fn open_glob_stops_at_files_that_fail_to_parse() {
+    Playground::setup("open_test_glob_2", |dirs, sandbox| {
+        sandbox.with_files(vec![
+            FileWithContentToBeTrimmed(
+                "good.json",
+                r#"
+                    {"name": "rent"}
+                "#,
+            ),
+            FileWithContentToBeTrimmed("bad.json", "{ not json"),
+        ]);
+
+        let actual = nu_error!(
+            cwd: dirs.test(),
+            "open *.json --glob"
+        );
+
+        let skipped = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open *.json --glob --skip-errors
+                | get source
+                | echo $it
+            "#
+        ));
+
+        assert!(actual.contains("Could not parse as JSON"));
+        assert_eq!(skipped, "good.json");
+    })
+}
@@ -549,3 +549,6168 @@ fn save_can_write_out_bson() {
         );
     })
 }
+
+#[test]
+fn tail_gets_last_lines_of_a_file() {
+    Playground::setup("tail_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "caballeros.txt",
+            r#"
+                Andrés N. Robalino
+                Jonathan Turner
+                Yehuda Katz
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                tail caballeros.txt 2
+                | first 1
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "Jonathan Turner");
+    })
+}
+
+#[test]
+fn tail_gets_ten_lines_when_no_amount_given() {
+    Playground::setup("tail_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "numbers.txt",
+            "1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n11\n12\n",
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                tail numbers.txt
+                | count
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "10");
+    })
+}
+
+#[test]
+fn sniff_detects_utf8_text() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", h::pipeline(
+        r#"
+            sniff cargo_sample.toml
+            | format "{mime} {encoding}"
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "application/toml utf-8");
+}
+
+#[test]
+fn sniff_detects_utf16_byte_order_mark() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", h::pipeline(
+        r#"
+            sniff utf16.ini
+            | get encoding
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "utf-16le");
+}
+
+#[test]
+fn sniff_detects_binary_data() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", h::pipeline(
+        r#"
+            sniff sample.db
+            | get binary
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "Yes");
+}
+
+#[test]
+fn partition_splits_rows_by_condition() {
+    Playground::setup("partition_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_amigos.json",
+            r#"
+                {
+                    "amigos": [
+                        {"name":    "Yehuda", "rusty_luck": 1},
+                        {"name":  "Jonathan", "rusty_luck": 5},
+                        {"name":    "Andres", "rusty_luck": 6},
+                        {"name": "GorbyPuff", "rusty_luck": 2}
+                    ]
+                }
+            "#,
+        )]);
+
+        let matched = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open los_tres_amigos.json
+                | get amigos
+                | partition rusty_luck > 3
+                | get matched
+                | count
+                | echo $it
+            "#
+        ));
+
+        let unmatched = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open los_tres_amigos.json
+                | get amigos
+                | partition rusty_luck > 3
+                | get unmatched
+                | last 1
+                | get name
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(matched, "2");
+        assert_eq!(unmatched, "GorbyPuff");
+    })
+}
+
+#[test]
+fn str_collect_concatenates_strings() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", h::pipeline(
+        r#"
+            echo a b c
+            | str-collect
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "abc");
+}
+
+#[test]
+fn str_collect_joins_strings_with_separator() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", h::pipeline(
+        r#"
+            echo a b c
+            | str-collect --separator "-"
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "a-b-c");
+}
+
+#[test]
+fn str_collect_errors_on_non_strings_when_strict() {
+    let actual = nu_error!(
+        cwd: "tests/fixtures/formats", h::pipeline(
+        r#"
+            echo a 1
+            | str-collect --strict
+        "#
+    ));
+
+    assert!(actual.contains("Expected a string"));
+}
+
+#[test]
+fn pad_aligns_strings_in_a_column() {
+    Playground::setup("pad_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_caballeros.csv",
+            r#"
+                first_name,last_name
+                Andrés,Robalino
+                Jonathan,Turner
+                Yehuda,Katz
+            "#,
+        )]);
+
+        let left = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open los_tres_caballeros.csv
+                | pad first_name 8 --char "."
+                | first 1
+                | get first_name
+                | echo $it
+            "#
+        ));
+
+        let right = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open los_tres_caballeros.csv
+                | pad first_name 8 --char "." --align right
+                | first 1
+                | get first_name
+                | echo $it
+            "#
+        ));
+
+        let center = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open los_tres_caballeros.csv
+                | pad first_name 9 --char "." --align center
+                | last 1
+                | get first_name
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(left, "Andrés..");
+        assert_eq!(right, "..Andrés");
+        assert_eq!(center, ".Yehuda..");
+    })
+}
+
+#[test]
+fn pad_leaves_long_strings_unless_truncating() {
+    Playground::setup("pad_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_caballeros.csv",
+            r#"
+                first_name,last_name
+                Andrés,Robalino
+                Jonathan,Turner
+                Yehuda,Katz
+            "#,
+        )]);
+
+        let unchanged = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open los_tres_caballeros.csv
+                | pad last_name 4
+                | first 1
+                | get last_name
+                | echo $it
+            "#
+        ));
+
+        let truncated = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open los_tres_caballeros.csv
+                | pad last_name 4 --truncate
+                | first 1
+                | get last_name
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(unchanged, "Robalino");
+        assert_eq!(truncated, "Roba");
+    })
+}
+
+#[test]
+fn chunk_bytes_splits_binary_data() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", h::pipeline(
+        r#"
+            open sample.db --raw
+            | chunk-bytes 1024
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "16");
+}
+
+#[test]
+fn chunk_bytes_errors_if_not_binary() {
+    let actual = nu_error!(
+        cwd: "tests/fixtures/formats", h::pipeline(
+        r#"
+            open cargo_sample.toml --raw
+            | chunk-bytes 2
+        "#
+    ));
+
+    assert!(actual.contains("Expected binary data from pipeline"));
+}
+
+#[test]
+fn expand_bytes_makes_a_row_per_byte() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", h::pipeline(
+        r#"
+            open sample.bson --raw
+            | chunk-bytes 8
+            | first 1
+            | expand-bytes
+            | where offset == 4
+            | format "{byte} {hex} {char}"
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "7 07 .");
+}
+
+#[test]
+fn expand_bytes_keeps_only_printable_bytes() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", h::pipeline(
+        r#"
+            open sample.bson --raw
+            | chunk-bytes 8
+            | first 1
+            | expand-bytes --printable-only
+            | get char
+            | str-collect
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "1_id");
+}
+
+#[test]
+fn reverse_reverses_the_rows_of_a_table() {
+    Playground::setup("reverse_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_caballeros.csv",
+            r#"
+                first_name,last_name
+                Andrés,Robalino
+                Jonathan,Turner
+                Yehuda,Katz
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open los_tres_caballeros.csv
+                | reverse --rows
+                | first 1
+                | get first_name
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "Yehuda");
+    })
+}
+
+#[test]
+fn reverse_reverses_multibyte_strings_in_a_column() {
+    Playground::setup("reverse_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_caballeros.csv",
+            r#"
+                first_name,last_name
+                Andrés,Robalino
+                Jonathan,Turner
+                Yehuda,Katz
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open los_tres_caballeros.csv
+                | reverse first_name
+                | first 1
+                | get first_name
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "sérdnA");
+    })
+}
+
+#[test]
+fn cumulative_sum_adds_up_an_integer_column() {
+    Playground::setup("cumulative_sum_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "sales.json",
+            r#"
+                {
+                    "sales": [
+                        {"day": "mon", "amount": 1},
+                        {"day": "tue", "amount": 2},
+                        {"day": "wed", "amount": 3}
+                    ]
+                }
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open sales.json
+                | get sales
+                | cumulative-sum amount
+                | last 1
+                | get amount_total
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "6");
+    })
+}
+
+#[test]
+fn cumulative_sum_promotes_mixed_numbers_to_decimals() {
+    Playground::setup("cumulative_sum_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "sales.json",
+            r#"
+                {
+                    "sales": [
+                        {"day": "mon", "amount": 1},
+                        {"day": "tue", "amount": 1.5},
+                        {"day": "wed", "amount": 2}
+                    ]
+                }
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open sales.json
+                | get sales
+                | cumulative-sum amount --into running
+                | last 1
+                | get running
+                | echo $it
+            "#
+        ));
+
+        assert!(actual.starts_with("4.5"));
+    })
+}
+
+#[test]
+fn cumulative_sum_errors_on_non_numeric_cells() {
+    Playground::setup("cumulative_sum_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "sales.json",
+            r#"
+                {
+                    "sales": [
+                        {"day": "mon", "amount": 1},
+                        {"day": "tue", "amount": "two"}
+                    ]
+                }
+            "#,
+        )]);
+
+        let actual = nu_error!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open sales.json
+                | get sales
+                | cumulative-sum amount
+            "#
+        ));
+
+        assert!(actual.contains("Expected a number"));
+    })
+}
+
+#[test]
+fn parse_unit_turns_strings_into_sizes() {
+    Playground::setup("parse_unit_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "downloads.csv",
+            r#"
+                name,size,timeout
+                nu,5kb,10s
+                rust,1kb,2m
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open downloads.csv
+                | parse-unit size
+                | where size > 2kb
+                | get name
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "nu");
+    })
+}
+
+#[test]
+fn parse_unit_turns_strings_into_durations() {
+    Playground::setup("parse_unit_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "downloads.csv",
+            r#"
+                name,size,timeout
+                nu,5kb,10s
+                rust,1kb,2m
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open downloads.csv
+                | parse-unit timeout
+                | first 1
+                | get timeout
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "10 secs");
+    })
+}
+
+#[test]
+fn parse_unit_errors_on_unparseable_strings_unless_lenient() {
+    Playground::setup("parse_unit_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "downloads.csv",
+            r#"
+                name,size,timeout
+                nu,5kb,10s
+                rust,lots,2m
+            "#,
+        )]);
+
+        let strict = nu_error!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open downloads.csv
+                | parse-unit size
+            "#
+        ));
+
+        let lenient = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open downloads.csv
+                | parse-unit size --lenient
+                | last 1
+                | get size
+                | echo $it
+            "#
+        ));
+
+        assert!(strict.contains("Expected a size or duration"));
+        assert_eq!(lenient, "lots");
+    })
+}
+
+#[test]
+fn diff_shows_added_rows() {
+    let actual = nu!(
+        cwd: ".", h::pipeline(
+        r#"
+            echo [nu rust]
+            | diff { [nu rust zsh] }
+            | get new
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "zsh");
+}
+
+#[test]
+fn diff_shows_removed_rows() {
+    Playground::setup("diff_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_amigos.json",
+            r#"
+                {
+                    "amigos": [
+                        {"name": "Yehuda", "rusty_luck": 1},
+                        {"name": "Jonathan", "rusty_luck": 1},
+                        {"name": "Andres", "rusty_luck": 1}
+                    ]
+                }
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open los_tres_amigos.json
+                | get amigos
+                | diff { [$it.0 $it.2] }
+                | where status == removed
+                | get old.name
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "Jonathan");
+    })
+}
+
+#[test]
+fn diff_on_key_shows_changed_cells() {
+    Playground::setup("diff_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "releases.json",
+            r#"
+                {
+                    "releases": [
+                        {
+                            "package": "nu",
+                            "version": "0.6.1",
+                            "next": {"package": "nu", "version": "0.6.2"}
+                        },
+                        {
+                            "package": "nu-source",
+                            "version": "0.6.1",
+                            "next": {"package": "nu-source", "version": "0.6.1"}
+                        }
+                    ]
+                }
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open releases.json
+                | get releases
+                | diff { [$it.0.next $it.1.next] } --on package
+                | where status == changed
+                | where column == version
+                | format "{package}: {old} -> {new}"
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "nu: 0.6.1 -> 0.6.2");
+    })
+}
+
+#[test]
+fn diff_on_key_rejects_repeated_keys() {
+    Playground::setup("diff_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "releases.json",
+            r#"
+                {
+                    "releases": [
+                        {"package": "nu", "version": "0.6.1"},
+                        {"package": "nu", "version": "0.6.2"}
+                    ]
+                }
+            "#,
+        )]);
+
+        let actual = nu_error!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open releases.json
+                | get releases
+                | diff { [$it.0] } --on package
+            "#
+        ));
+
+        assert!(actual.contains("Duplicate key"));
+    })
+}
+
+#[test]
+fn pivot_wide_reshapes_a_long_table() {
+    Playground::setup("pivot_wide_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "temperatures.csv",
+            r#"
+                city,month,temp
+                paris,jan,5
+                paris,feb,7
+                oslo,jan,-3
+                oslo,feb,-1
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open temperatures.csv
+                | pivot-wide --index city --columns month --values temp
+                | where city == oslo
+                | format "{jan} {feb}"
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "-3 -1");
+    })
+}
+
+#[test]
+fn pivot_wide_fills_missing_combinations_with_nothing() {
+    Playground::setup("pivot_wide_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "temperatures.csv",
+            r#"
+                city,month,temp
+                paris,jan,5
+                paris,feb,7
+                oslo,jan,-3
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open temperatures.csv
+                | pivot-wide --index city --columns month --values temp
+                | compact feb
+                | count
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "1");
+    })
+}
+
+#[test]
+fn every_returns_every_nth_row() {
+    let actual = nu!(
+        cwd: ".", h::pipeline(
+        r#"
+            echo [a b c d e f g h]
+            | every 3
+            | str-collect --separator ","
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "a,d,g");
+}
+
+#[test]
+fn every_starts_at_the_offset() {
+    let actual = nu!(
+        cwd: ".", h::pipeline(
+        r#"
+            echo [a b c d e f g h]
+            | every 3 --offset 1
+            | str-collect --separator ","
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "b,e,h");
+}
+
+#[test]
+fn every_with_skip_drops_every_nth_row() {
+    let actual = nu!(
+        cwd: ".", h::pipeline(
+        r#"
+            echo [a b c d e f g h]
+            | every 3 --skip
+            | str-collect --separator ","
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "b,c,e,f,h");
+}
+
+#[test]
+fn to_tree_draws_nested_rows() {
+    Playground::setup("to_tree_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "nu.json",
+            r#"
+                {
+                    "name": "nu",
+                    "authors": ["yehuda", "jonathan"],
+                    "meta": {"license": "MIT"}
+                }
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open nu.json
+                | to-tree
+                | lines
+                | last 2
+                | str-collect --separator "|"
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "└── meta|    └── license: MIT");
+    })
+}
+
+#[test]
+fn to_tree_draws_tables_with_indexed_branches() {
+    Playground::setup("to_tree_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "nu.json",
+            r#"
+                {
+                    "name": "nu",
+                    "authors": ["yehuda", "jonathan"],
+                    "meta": {"license": "MIT"}
+                }
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open nu.json
+                | to-tree
+                | lines
+                | nth 3
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "│   └── 1: jonathan");
+    })
+}
+
+#[test]
+fn to_tree_cuts_off_branches_past_the_max_depth() {
+    Playground::setup("to_tree_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "nu.json",
+            r#"
+                {
+                    "name": "nu",
+                    "authors": ["yehuda", "jonathan"],
+                    "meta": {"license": "MIT"}
+                }
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open nu.json
+                | to-tree --max-depth 1
+                | lines
+                | str-collect --separator "|"
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "├── name: nu|├── authors: …|└── meta: …");
+    })
+}
+
+#[test]
+fn query_matches_wildcards() {
+    Playground::setup("query_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "library.json",
+            r#"
+                {
+                    "books": [
+                        {"title": "Moby Dick", "author": {"name": "Melville"}},
+                        {"title": "Dracula", "author": {"name": "Stoker"}}
+                    ]
+                }
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open library.json
+                | query "books.*.title" --values-only
+                | str-collect --separator ","
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "Moby Dick,Dracula");
+    })
+}
+
+#[test]
+fn query_matches_descendants() {
+    Playground::setup("query_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "library.json",
+            r#"
+                {
+                    "books": [
+                        {"title": "Moby Dick", "author": {"name": "Melville"}},
+                        {"title": "Dracula", "author": {"name": "Stoker"}}
+                    ]
+                }
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open library.json
+                | query "$..name"
+                | last 1
+                | format "{path}={value}"
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "$.books.1.author.name=Stoker");
+    })
+}
+
+#[test]
+fn query_without_matches_returns_an_empty_table() {
+    Playground::setup("query_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "library.json",
+            r#"
+                {
+                    "books": [
+                        {"title": "Moby Dick", "author": {"name": "Melville"}},
+                        {"title": "Dracula", "author": {"name": "Stoker"}}
+                    ]
+                }
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open library.json
+                | query "$..isbn"
+                | count
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "0");
+    })
+}
+
+#[test]
+fn rows_to_record_turns_key_value_rows_into_a_row() {
+    Playground::setup("rows_to_record_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "settings.csv",
+            r#"
+                name,setting
+                editor,vim
+                shell,nu
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open settings.csv
+                | rows-to-record --key name --value setting
+                | get shell
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "nu");
+    })
+}
+
+#[test]
+fn rows_to_record_collects_repeated_keys() {
+    Playground::setup("rows_to_record_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "settings.csv",
+            r#"
+                name,setting
+                editor,vim
+                editor,emacs
+                shell,nu
+            "#,
+        )]);
+
+        let collected = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open settings.csv
+                | rows-to-record --key name --value setting --collect
+                | get editor
+                | count
+                | echo $it
+            "#
+        ));
+
+        let actual = nu_error!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open settings.csv
+                | rows-to-record --key name --value setting
+            "#
+        ));
+
+        assert_eq!(collected, "2");
+        assert!(actual.contains("Repeated key"));
+    })
+}
+
+#[test]
+fn rows_to_record_stringifies_numeric_keys() {
+    Playground::setup("rows_to_record_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "ports.json",
+            r#"
+                {
+                    "ports": [
+                        {"port": 8080, "service": "web"}
+                    ]
+                }
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open ports.json
+                | get ports
+                | rows-to-record --key port --value service
+                | pivot
+                | format "{Column0}={Column1}"
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "8080=web");
+    })
+}
+
+#[test]
+fn update_cells_updates_the_selected_columns() {
+    Playground::setup("update_cells_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "repos.json",
+            r#"
+                {
+                    "repos": [
+                        {"name": "nu", "stars": 12, "forks": 9}
+                    ]
+                }
+            "#,
+        )]);
+
+        let updated = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open repos.json
+                | get repos
+                | update-cells { $it > 10 } stars
+                | get stars
+                | echo $it
+            "#
+        ));
+
+        let untouched = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open repos.json
+                | get repos
+                | update-cells { $it > 10 } stars
+                | get forks
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(updated, "Yes");
+        assert_eq!(untouched, "9");
+    })
+}
+
+#[test]
+fn update_cells_updates_every_column_by_default() {
+    Playground::setup("update_cells_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "scores.json",
+            r#"
+                {
+                    "scores": [
+                        {"first": 3, "second": 12}
+                    ]
+                }
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open scores.json
+                | get scores
+                | update-cells { $it > 10 }
+                | pivot
+                | get Column1
+                | str-collect --separator ","
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "No,Yes");
+    })
+}
+
+#[test]
+fn update_cells_points_at_the_failing_cell() {
+    Playground::setup("update_cells_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "repos.json",
+            r#"
+                {
+                    "repos": [
+                        {"name": "nu", "stars": 12, "forks": 9}
+                    ]
+                }
+            "#,
+        )]);
+
+        let actual = nu_error!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open repos.json
+                | get repos
+                | update-cells { $it.missing } name
+            "#
+        ));
+
+        assert!(actual.contains("while updating the name column"));
+    })
+}
+
+#[test]
+fn split_docs_splits_on_blank_lines() {
+    Playground::setup("split_docs_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "shells.txt",
+            r#"
+                {"name": "nu"}
+
+                {"name": "zsh"}
+
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open shells.txt
+                | split-docs
+                | last 1
+                | from-json
+                | get name
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "zsh");
+    })
+}
+
+#[test]
+fn split_docs_splits_on_dashes() {
+    Playground::setup("split_docs_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "shells.txt",
+            r#"
+                name: nu
+                ---
+                name: zsh
+                ---
+                name: fish
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open shells.txt
+                | split-docs --separator "---"
+                | count
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "3");
+    })
+}
+
+#[test]
+fn summary_describes_numeric_columns() {
+    Playground::setup("summary_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "scores.json",
+            r#"
+                [
+                    {"low": 1, "high": 10},
+                    {"low": 3, "high": 30},
+                    {"low": 5, "high": 20}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open scores.json
+                | summary
+                | where column == high
+                | get max
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "30");
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open scores.json
+                | summary
+                | where mean == 3
+                | get column
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "low");
+    })
+}
+
+#[test]
+fn summary_counts_distinct_values_of_string_columns() {
+    Playground::setup("summary_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "shells.json",
+            r#"
+                [
+                    {"name": "nu", "stars": 1},
+                    {"name": "zsh", "stars": 2},
+                    {"name": "nu", "stars": 3}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open shells.json
+                | summary
+                | where column == name
+                | format "{count}-{distinct}"
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "3-2");
+    })
+}
+
+#[test]
+fn normalize_columns_lowercases_column_names() {
+    Playground::setup("normalize_columns_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_caballeros.csv",
+            r#"
+                First_Name,LAST_NAME,Rusty_Luck
+                Andrés,Robalino,1
+                Jonathan,Turner,1
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open los_tres_caballeros.csv
+                | normalize-columns lower
+                | first 1
+                | get last_name
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "Robalino");
+    })
+}
+
+#[test]
+fn normalize_columns_snake_cases_column_names() {
+    Playground::setup("normalize_columns_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_caballeros.json",
+            r#"
+                [
+                    {"First Name": "Andrés", "lastName": "Robalino", "rusty-luck": 1}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open los_tres_caballeros.json
+                | normalize-columns snake
+                | format "{first_name} {last_name} {rusty_luck}"
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "Andrés Robalino 1");
+    })
+}
+
+#[test]
+fn normalize_columns_errors_when_names_collide() {
+    Playground::setup("normalize_columns_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_caballeros.json",
+            r#"
+                [
+                    {"name": "Andrés", "Name": "Robalino"}
+                ]
+            "#,
+        )]);
+
+        let actual = nu_error!(
+            cwd: dirs.test(),
+            "open los_tres_caballeros.json | normalize-columns lower"
+        );
+
+        assert!(actual.contains("Column names collide"));
+    })
+}
+
+#[test]
+fn normalize_columns_title_cases_snake_and_kebab_names() {
+    Playground::setup("normalize_columns_test_4", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_caballeros.json",
+            r#"
+                [
+                    {"first_name": "Andrés", "last-name": "Robalino"}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "open los_tres_caballeros.json | normalize-columns title | to-json | echo $it"
+        );
+
+        assert_eq!(actual, r#"{"First Name":"Andrés","Last Name":"Robalino"}"#);
+    })
+}
+
+#[test]
+fn normalize_columns_title_keeps_the_given_acronyms() {
+    Playground::setup("normalize_columns_test_5", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_caballeros.json",
+            r#"
+                [
+                    {"user_id": 1, "home_url": "nushell.sh"}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open los_tres_caballeros.json
+                | normalize-columns title --keep [ID URL]
+                | to-json
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, r#"{"User ID":1,"Home URL":"nushell.sh"}"#);
+    })
+}
+
+/// Serves each (path, status, body) as JSON from a local server and returns its address.
+fn serve_json_pages(pages: Vec<(&'static str, &'static str, String)>) -> String {
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+
+    let listener = TcpListener::bind("127.0.0.1:0").expect("could not start mock server");
+    let address = format!("http://{}", listener.local_addr().unwrap());
+
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = match stream {
+                Ok(stream) => stream,
+                Err(_) => continue,
+            };
+
+            let mut request = vec![];
+            let mut buffer = [0; 1024];
+
+            while !String::from_utf8_lossy(&request).contains("\r\n\r\n") {
+                match stream.read(&mut buffer) {
+                    Ok(0) | Err(_) => break,
+                    Ok(read) => request.extend_from_slice(&buffer[..read]),
+                }
+            }
+
+            let request = String::from_utf8_lossy(&request).to_string();
+            let path = request.split_whitespace().nth(1).unwrap_or("/").to_string();
+
+            let (status, body) = match pages.iter().find(|(page, _, _)| *page == path) {
+                Some((_, status, body)) => (*status, body.clone()),
+                None => ("404 Not Found", String::new()),
+            };
+
+            let _ = write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            );
+        }
+    });
+
+    address
+}
+
+#[test]
+fn fetch_follows_next_links_and_joins_pages() {
+    let address = serve_json_pages(vec![
+        (
+            "/shells",
+            "200 OK",
+            r#"{"shells": [{"name": "nu"}, {"name": "zsh"}], "next": "/shells?page=2"}"#
+                .to_string(),
+        ),
+        (
+            "/shells?page=2",
+            "200 OK",
+            r#"{"shells": [{"name": "fish"}], "next": null}"#.to_string(),
+        ),
+    ]);
+
+    let actual = nu!(
+        cwd: ".",
+        &format!(
+            r#"fetch {}/shells --next next | get name | str-collect --separator "," | echo $it"#,
+            address
+        )
+    );
+
+    assert_eq!(actual, "nu,zsh,fish");
+
+    let actual = nu!(
+        cwd: ".",
+        &format!(
+            "fetch {}/shells --next next --max-pages 1 | count | echo $it",
+            address
+        )
+    );
+
+    assert_eq!(actual, "2");
+}
+
+#[test]
+fn fetch_errors_with_the_status_of_failed_requests() {
+    let address = serve_json_pages(vec![(
+        "/missing",
+        "404 Not Found",
+        r#"{"error": "not here"}"#.to_string(),
+    )]);
+
+    let actual = nu_error!(cwd: ".", &format!("fetch {}/missing", address));
+
+    assert!(actual.contains("404"));
+}
+
+#[test]
+fn unify_fills_in_missing_columns() {
+    Playground::setup("unify_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "shells.txt",
+            r#"
+                {"name": "nu"}
+                {"stars": 3}
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open shells.txt
+                | from-json --objects
+                | unify
+                | to-json
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(
+            actual,
+            r#"[{"name":"nu","stars":null},{"name":null,"stars":3}]"#
+        );
+    })
+}
+
+#[test]
+fn unify_puts_the_given_keys_first() {
+    Playground::setup("unify_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "shells.txt",
+            r#"
+                {"name": "nu", "stars": 3}
+                {"kind": "shell"}
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open shells.txt
+                | from-json --objects
+                | unify --keys [stars name]
+                | first 1
+                | to-json
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, r#"{"stars":3,"name":"nu","kind":null}"#);
+    })
+}
+
+#[test]
+fn split_paths_splits_colon_separated_paths() {
+    let actual = nu!(
+        cwd: ".", h::pipeline(
+        r#"
+            echo "/usr/local/bin::/usr/bin:/bin"
+            | split-paths --separator ":"
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "3");
+
+    let actual = nu!(
+        cwd: ".", h::pipeline(
+        r#"
+            echo "/usr/local/bin::/usr/bin:/bin"
+            | split-paths --separator ":"
+            | nth 1
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "/usr/bin");
+}
+
+#[test]
+fn split_paths_splits_semicolon_separated_paths() {
+    let actual = nu!(
+        cwd: ".", h::pipeline(
+        r#"
+            echo "C:/tools;;D:/bin"
+            | split-paths --separator ";" --keep-empty
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "3");
+}
+
+#[test]
+fn path_join_joins_relative_segments() {
+    let actual = nu!(
+        cwd: ".",
+        "path-join projects nushell ./src ../tests | echo $it"
+    );
+
+    let expected = std::path::PathBuf::from("projects")
+        .join("nushell")
+        .join("tests");
+
+    assert_eq!(actual, expected.display().to_string());
+}
+
+#[test]
+fn path_join_starts_over_at_an_absolute_segment() {
+    let actual = nu!(cwd: ".", "path-join projects /etc nu | echo $it");
+
+    let mut expected = std::path::PathBuf::from("projects");
+    expected.push("/etc");
+    expected.push("nu");
+
+    assert_eq!(actual, expected.display().to_string());
+}
+
+#[test]
+fn path_parse_splits_a_file_path() {
+    let actual = nu!(
+        cwd: ".", h::pipeline(
+        r#"
+            echo "/home/viking/spam.tar.gz"
+            | path-parse
+            | format "{stem} {extension} {filename}"
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "spam.tar gz spam.tar.gz");
+}
+
+#[test]
+fn path_parse_gives_nothing_for_a_missing_extension() {
+    let actual = nu!(
+        cwd: ".", h::pipeline(
+        r#"
+            echo "/home/viking/Makefile"
+            | path-parse
+            | compact extension
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "0");
+}
+
+#[test]
+fn path_parse_splits_a_directory_path() {
+    let actual = nu!(
+        cwd: ".", h::pipeline(
+        r#"
+            echo "/home/viking/projects/"
+            | path-parse --component filename
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "projects");
+}
+
+#[test]
+fn path_parse_replaces_a_column_in_place() {
+    Playground::setup("path_parse_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "files.csv",
+            r#"
+                name,size
+                /home/viking/spam.txt,10
+                /home/viking/eggs.md,20
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open files.csv
+                | path-parse name --component stem
+                | get name
+                | str-collect --separator ","
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "spam,eggs");
+    })
+}
+
+#[test]
+fn path_set_extension_replaces_an_extension() {
+    let actual = nu!(
+        cwd: ".",
+        r#"echo "spam.txt" | path-set-extension md | echo $it"#
+    );
+
+    assert_eq!(actual, "spam.md");
+}
+
+#[test]
+fn path_set_extension_adds_an_extension() {
+    Playground::setup("path_set_extension_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "files.csv",
+            r#"
+                name,size
+                Makefile,10
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open files.csv
+                | path-set-extension .bak name
+                | get name
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "Makefile.bak");
+    })
+}
+
+#[test]
+fn path_set_extension_removes_an_extension() {
+    let actual = nu!(
+        cwd: ".",
+        r#"echo "spam.tar.gz" | path-set-extension "" | echo $it"#
+    );
+
+    assert_eq!(actual, "spam.tar");
+}
+
+#[test]
+fn path_expand_expands_the_home_directory() {
+    let actual = nu!(cwd: ".", r#"echo "~/projects" | path-expand | echo $it"#);
+
+    let expected = dirs::home_dir().expect("home directory").join("projects");
+
+    assert_eq!(actual, expected.display().to_string());
+}
+
+#[test]
+fn path_expand_expands_variables() {
+    std::env::set_var("NU_PATH_EXPAND_TEST_ROOT", "/opt/nu");
+
+    let actual = nu!(
+        cwd: ".",
+        r#"echo "$NU_PATH_EXPAND_TEST_ROOT/bin" | path-expand | echo $it"#
+    );
+
+    let expected = std::env::current_dir()
+        .expect("current directory")
+        .join("/opt/nu/bin");
+
+    assert_eq!(actual, expected.display().to_string());
+}
+
+#[test]
+fn path_expand_errors_on_missing_variables_when_strict() {
+    let actual = nu_error!(
+        cwd: ".",
+        r#"echo "$NU_PATH_EXPAND_TEST_MISSING/bin" | path-expand --strict"#
+    );
+
+    assert!(actual.contains("is not set"));
+}
+
+#[test]
+fn path_type_reports_files_and_directories() {
+    Playground::setup("path_type_test_1", |dirs, sandbox| {
+        sandbox
+            .with_files(vec![EmptyFile("andres.txt")])
+            .mkdir("projects");
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                ls
+                | sort-by name
+                | path-type name
+                | get type
+                | str-collect --separator ","
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "File,Directory");
+    })
+}
+
+#[test]
+fn path_type_reports_missing_paths() {
+    Playground::setup("path_type_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![EmptyFile("andres.txt")]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"echo "jonathan.txt" | path-type | get exists | echo $it"#
+        );
+
+        assert_eq!(actual, "No");
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                echo [andres.txt jonathan.txt]
+                | path-type --only-existing
+                | count
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "1");
+    })
+}
+
+#[test]
+fn path_canonicalize_resolves_relative_paths() {
+    Playground::setup("path_canonicalize_test_1", |dirs, sandbox| {
+        sandbox
+            .within("projects")
+            .with_files(vec![EmptyFile("andres.txt")]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"echo "projects/../projects/andres.txt" | path-canonicalize | echo $it"#
+        );
+
+        let expected = dunce::canonicalize(dirs.test().join("projects").join("andres.txt"))
+            .expect("canonical path");
+
+        assert_eq!(actual, expected.display().to_string());
+    })
+}
+
+#[test]
+fn path_canonicalize_resolves_absolute_paths() {
+    Playground::setup("path_canonicalize_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![EmptyFile("andres.txt")]);
+
+        let absolute = dirs.test().join(".").join("andres.txt");
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            &format!(
+                r#"echo "{}" | path-canonicalize | echo $it"#,
+                absolute.display()
+            )
+        );
+
+        let expected = dunce::canonicalize(dirs.test().join("andres.txt")).expect("canonical path");
+
+        assert_eq!(actual, expected.display().to_string());
+    })
+}
+
+#[test]
+fn path_canonicalize_passes_missing_paths_through_when_allowed() {
+    Playground::setup("path_canonicalize_test_3", |dirs, _| {
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"echo "jonathan.txt" | path-canonicalize --allow-missing | echo $it"#
+        );
+
+        assert_eq!(actual, "jonathan.txt");
+    })
+}
+
+#[test]
+fn du_sums_the_size_of_a_directory_tree() {
+    Playground::setup("du_test_1", |dirs, sandbox| {
+        sandbox
+            .with_files(vec![FileWithContent("a.txt", "12345")])
+            .mkdir("nested")
+            .within("nested")
+            .with_files(vec![FileWithContent("b.txt", "1234567890")]);
+
+        let shallow = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                du .
+                | echo $it
+            "#
+        ));
+
+        let deep = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                du . --recursive
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(shallow.trim(), "5 B");
+        assert_eq!(deep.trim(), "15 B");
+    })
+}
+
+#[test]
+fn du_gives_the_size_of_each_entry() {
+    Playground::setup("du_test_2", |dirs, sandbox| {
+        sandbox
+            .with_files(vec![
+                FileWithContent("a.txt", "12345"),
+                FileWithContent("b.txt", "1234567890"),
+            ])
+            .mkdir("empty");
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                du *.txt --by-entry
+                | where name == b.txt
+                | get size
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual.trim(), "10 B");
+    })
+}
+
+#[test]
+fn watch_reports_created_files() {
+    Playground::setup("watch_test_1", |dirs, _| {
+        let cwd = dirs.test().to_path_buf();
+        let done = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let stop = done.clone();
+
+        // nu may take a while to start watching, so files keep being created until it
+        // has seen one rather than after a fixed wait.
+        let writer = std::thread::spawn(move || {
+            let mut created = 0;
+
+            while !stop.load(std::sync::atomic::Ordering::SeqCst) {
+                std::thread::sleep(std::time::Duration::from_millis(500));
+                std::fs::write(cwd.join(format!("yehuda{}.txt", created)), "hello")
+                    .expect("can not create file");
+                created += 1;
+            }
+        });
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                watch
+                | first
+                | get event
+                | echo $it
+            "#
+        ));
+
+        done.store(true, std::sync::atomic::Ordering::SeqCst);
+        writer.join().expect("writer thread panicked");
+
+        assert_eq!(actual, "created");
+    })
+}
+
+#[test]
+fn timeout_passes_on_blocks_that_finish_in_time() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", h::pipeline(
+        r#"
+            timeout { echo [1 2 3] } --duration 10s
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "3");
+}
+
+#[test]
+fn timeout_stops_blocks_that_run_too_long() {
+    Playground::setup("timeout_test_1", |dirs, _| {
+        let started = std::time::Instant::now();
+
+        let actual = nu_error!(
+            cwd: dirs.test(),
+            "timeout { watch } --duration 1s"
+        );
+
+        assert!(actual.contains("timed out"));
+        assert!(started.elapsed() < std::time::Duration::from_secs(10));
+    })
+}
+
+#[test]
+fn timeout_passes_on_errors_of_the_block() {
+    let actual = nu_error!(
+        cwd: "tests/fixtures/formats",
+        "timeout { open i_dont_exist.txt } --duration 10s"
+    );
+
+    assert!(actual.contains("file not found"));
+}
+
+#[test]
+fn retry_runs_the_block_again_until_it_works() {
+    Playground::setup("retry_test_1", |dirs, _| {
+        let flag = dirs.test().join("flag.txt");
+
+        // The file shows up while retry is waiting between attempts.
+        let writer = std::thread::spawn(move || {
+            std::thread::sleep(std::time::Duration::from_millis(1500));
+            std::fs::write(flag, "ready").unwrap();
+        });
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                echo flag.txt
+                | retry { open $it } --times 10 --delay 1s
+                | echo $it
+            "#
+        ));
+
+        writer.join().unwrap();
+
+        assert_eq!(actual, "ready");
+    })
+}
+
+#[test]
+fn retry_gives_up_on_a_block_that_always_fails() {
+    Playground::setup("retry_test_2", |dirs, _| {
+        let actual = nu_error!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                echo i_dont_exist.txt
+                | retry { open $it } --times 2
+            "#
+        ));
+
+        assert!(actual.contains("file not found"));
+        assert!(actual.contains("gave up after 2 attempts"));
+    })
+}
+
+#[test]
+fn benchmark_gives_the_output_and_duration_of_a_block() {
+    Playground::setup("benchmark_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "repos.json",
+            r#"
+                {
+                    "repos": [
+                        {"name": "nu", "stars": 12}
+                    ]
+                }
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open repos.json
+                | get repos
+                | benchmark { echo $it.name }
+                | format "{output} {duration}"
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "nu 0 secs");
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open repos.json
+                | get repos
+                | benchmark { echo $it.name } --rounds 3
+                | get mean_ms
+                | echo $it
+            "#
+        ));
+
+        assert!(actual.parse::<u64>().unwrap() < 1000);
+    })
+}
+
+#[cfg(not(windows))]
+#[test]
+fn benchmark_reports_at_least_the_time_the_block_sleeps() {
+    let actual = nu!(
+        cwd: ".", h::pipeline(
+        r#"
+            echo 1
+            | benchmark { ^sleep 1 }
+            | get duration_ms
+            | echo $it
+        "#
+    ));
+
+    assert!(actual.parse::<u64>().unwrap() >= 1000);
+}
+
+#[test]
+fn benchmark_passes_on_errors_from_the_block() {
+    let actual = nu_error!(
+        cwd: "tests/fixtures/formats", h::pipeline(
+        r#"
+            echo i_dont_exist.txt
+            | benchmark { open $it }
+        "#
+    ));
+
+    assert!(actual.contains("file not found"));
+}
+
+#[test]
+fn seq_counts_integers() {
+    let count = nu!(
+        cwd: ".", h::pipeline(
+        r#"
+            seq 1 10 3
+            | count
+            | echo $it
+        "#
+    ));
+
+    let last = nu!(
+        cwd: ".", h::pipeline(
+        r#"
+            seq 1 10 3
+            | last
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(count, "4");
+    assert_eq!(last, "10");
+}
+
+#[test]
+fn seq_counts_with_a_decimal_step() {
+    let actual = nu!(
+        cwd: ".", h::pipeline(
+        r#"
+            seq 0 1 0.25
+            | where $it > 0.6
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "2");
+}
+
+#[test]
+fn seq_counts_days() {
+    let actual = nu!(
+        cwd: ".", h::pipeline(
+        r#"
+            seq 2019-01-30 2019-02-02 --dates
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "4");
+}
+
+#[test]
+fn seq_is_empty_when_start_is_past_end() {
+    let actual = nu!(
+        cwd: ".", h::pipeline(
+        r#"
+            seq 5 1
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "0");
+}
+
+#[test]
+fn seq_rejects_a_zero_step() {
+    let actual = nu_error!(cwd: ".", "seq 1 5 0");
+
+    assert!(actual.contains("Step can't be zero"));
+}
+
+#[test]
+fn repeat_emits_a_value_the_given_number_of_times() {
+    let actual = nu!(
+        cwd: ".", h::pipeline(
+        r#"
+            echo nu
+            | repeat 3
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "3");
+}
+
+#[test]
+fn repeat_emits_a_whole_stream_in_order() {
+    let actual = nu!(
+        cwd: ".", h::pipeline(
+        r#"
+            echo [a b]
+            | repeat 2
+            | str-collect --separator ","
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "a,b,a,b");
+}
+
+#[test]
+fn repeat_with_a_count_of_zero_is_empty() {
+    let actual = nu!(
+        cwd: ".", h::pipeline(
+        r#"
+            echo [a b]
+            | repeat 0
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "0");
+}
+
+#[test]
+fn cross_pairs_every_row_with_every_other_row() {
+    let actual = nu!(
+        cwd: ".", h::pipeline(
+        r#"
+            echo [a b]
+            | cross { [x y z] }
+            | format "{value}{value_other}"
+            | str-collect --separator ","
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "ax,ay,az,bx,by,bz");
+}
+
+#[test]
+fn cross_with_an_empty_side_is_empty() {
+    let actual = nu!(
+        cwd: ".", h::pipeline(
+        r#"
+            echo [a b]
+            | where $it == c
+            | cross { [x y z] }
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "0");
+}
+
+#[test]
+fn sample_with_a_seed_is_reproducible() {
+    let first = nu!(
+        cwd: ".", h::pipeline(
+        r#"
+            echo [a b c d e f g h]
+            | sample 3 --seed 42
+            | str-collect --separator ","
+            | echo $it
+        "#
+    ));
+
+    let second = nu!(
+        cwd: ".", h::pipeline(
+        r#"
+            echo [a b c d e f g h]
+            | sample 3 --seed 42
+            | str-collect --separator ","
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(first, second);
+    assert_eq!(first.split(',').count(), 3);
+}
+
+#[test]
+fn sample_never_picks_more_rows_than_there_are() {
+    let actual = nu!(
+        cwd: ".", h::pipeline(
+        r#"
+            echo [a b c]
+            | sample 10
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "3");
+}
+
+#[test]
+fn frequency_counts_values_most_frequent_first() {
+    Playground::setup("frequency_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "paints.json",
+            r#"
+                [
+                    {"color": "red"}, {"color": "blue"}, {"color": "yellow"}, {"color": "red"},
+                    {"color": "green"}, {"color": "blue"}, {"color": "red"}, {"color": "white"}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open paints.json
+                | frequency color
+                | format "{value}:{count}"
+                | str-collect --separator ","
+                | echo $it
+            "#
+        ));
+
+        let half = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open paints.json
+                | frequency color
+                | where fraction > 0.3
+                | get value
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "red:3,blue:2,green:1,white:1,yellow:1");
+        assert_eq!(half, "red");
+    })
+}
+
+#[test]
+fn frequency_buckets_the_rest_into_other() {
+    Playground::setup("frequency_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "paints.json",
+            r#"
+                [
+                    {"color": "red"}, {"color": "blue"}, {"color": "yellow"}, {"color": "red"},
+                    {"color": "green"}, {"color": "blue"}, {"color": "red"}, {"color": "white"}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open paints.json
+                | frequency color --top 2
+                | format "{value}:{count}"
+                | str-collect --separator ","
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "red:3,blue:2,other:3");
+    })
+}
+
+#[test]
+fn items_lists_the_keys_and_values_of_a_row() {
+    Playground::setup("items_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "config.json",
+            r#"
+                {"name": "nu", "version": "0.6.2", "plugins": {"inc": true}}
+            "#,
+        )]);
+
+        let keys = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open config.json
+                | items
+                | get key
+                | str-collect --separator ","
+                | echo $it
+            "#
+        ));
+
+        let nested = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open config.json
+                | items
+                | where key == plugins
+                | get value.inc
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(keys, "name,version,plugins");
+        assert_eq!(nested, "Yes");
+    })
+}
+
+#[test]
+fn items_rejects_tables() {
+    let actual = nu_error!(
+        cwd: "tests/fixtures/formats", h::pipeline(
+        r#"
+            open caco3_plastics.csv
+            | items
+        "#
+    ));
+
+    assert!(actual.contains("Expected a single row"));
+}
+
+#[test]
+fn into_bool_reads_the_usual_spellings() {
+    Playground::setup("into_bool_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "features.csv",
+            r#"
+                name,enabled
+                color,Yes
+                paging,0
+                history,TRUE
+                hints,off
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open features.csv
+                | into-bool enabled
+                | get enabled
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "YesNoYesNo");
+    })
+}
+
+#[test]
+fn into_bool_takes_custom_spellings() {
+    Playground::setup("into_bool_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "features.csv",
+            r#"
+                name,enabled
+                color,si
+                paging,nein
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open features.csv
+                | into-bool enabled --true [si ja] --false [no nein]
+                | get enabled
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "YesNo");
+    })
+}
+
+#[test]
+fn into_bool_errors_on_other_values_unless_told_to_use_null() {
+    Playground::setup("into_bool_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "features.csv",
+            r#"
+                name,enabled
+                color,yes
+                paging,maybe
+            "#,
+        )]);
+
+        let actual = nu_error!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open features.csv
+                | into-bool enabled
+            "#
+        ));
+
+        let lenient = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open features.csv
+                | into-bool enabled --on-error null
+                | get name
+                | str-collect --separator ","
+                | echo $it
+            "#
+        ));
+
+        assert!(actual.contains("Expected a boolean"));
+        assert_eq!(lenient, "color,paging");
+    })
+}
+
+#[test]
+fn debug_ast_renders_a_pipeline() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", h::pipeline(
+        r#"
+            debug --ast "ls | first 1"
+        "#
+    ));
+
+    assert!(actual.contains("internal command"));
+    assert!(actual.contains("first"));
+}
+
+#[test]
+fn debug_ast_renders_a_binary_expression() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", h::pipeline(
+        r#"
+            debug --ast "where size > 10"
+        "#
+    ));
+
+    assert!(actual.contains("where"));
+    assert!(actual.contains(">"));
+}
+
+#[test]
+fn debug_ast_renders_the_error_of_malformed_input() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", h::pipeline(
+        r#"
+            debug --ast "ls | pick"
+        "#
+    ));
+
+    assert!(actual.contains("pick"));
+    assert!(actual.contains("missing mandatory rest arguments"));
+}
+
+#[test]
+fn units_lists_the_byte_units_with_their_multipliers() {
+    let names = nu!(
+        cwd: "tests/fixtures/formats", h::pipeline(
+        r#"
+            units
+            | where category == bytesize
+            | get name
+            | str-collect --separator ","
+            | echo $it
+        "#
+    ));
+
+    let kilobyte = nu!(
+        cwd: "tests/fixtures/formats", h::pipeline(
+        r#"
+            units
+            | where name == KB
+            | get multiplier
+            | echo $it
+        "#
+    ));
+
+    let petabyte = nu!(
+        cwd: "tests/fixtures/formats", h::pipeline(
+        r#"
+            units
+            | where name == PB
+            | get multiplier
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(names, "B,KB,MB,GB,TB,PB");
+    assert_eq!(kilobyte, "1024");
+    assert_eq!(petabyte, "1125899906842624");
+}
+
+#[test]
+fn convert_unit_expresses_kilobytes_in_megabytes() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", h::pipeline(
+        r#"
+            echo 5120kb
+            | convert-unit mb
+            | get value
+            | echo $it
+        "#
+    ));
+
+    let kept = nu!(
+        cwd: "tests/fixtures/formats", h::pipeline(
+        r#"
+            echo 5100kb
+            | convert-unit mb --keep-type
+            | echo $it
+        "#
+    ));
+
+    let expected = nu!(cwd: "tests/fixtures/formats", "echo 5mb | echo $it");
+
+    assert_eq!(actual.parse::<f64>().unwrap(), 5.0);
+    assert_eq!(kept, expected);
+}
+
+#[test]
+fn convert_unit_errors_on_an_incompatible_unit() {
+    let actual = nu_error!(
+        cwd: "tests/fixtures/formats", h::pipeline(
+        r#"
+            echo 5kb
+            | convert-unit s
+        "#
+    ));
+
+    assert!(actual.contains("Incompatible unit"));
+}
+
+#[test]
+fn capture_adds_a_column_per_named_group() {
+    Playground::setup("capture_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "emails.csv",
+            r#"
+                name,email
+                andres,andres@nushell.sh
+                jonathan,unknown
+                yehuda,yehuda@rust.lang
+            "#,
+        )]);
+
+        let hosts = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open emails.csv
+                | capture email --regex "(?P<user>[a-z]+)@(?P<host>[a-z.]+)"
+                | where name != jonathan
+                | get host
+                | str-collect --separator ","
+                | echo $it
+            "#
+        ));
+
+        let unmatched = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open emails.csv
+                | capture email --regex "(?P<user>[a-z]+)@(?P<host>[a-z.]+)"
+                | nth 1
+                | get user
+                | echo $it
+            "#
+        ));
+
+        let only_matches = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open emails.csv
+                | capture email --regex "(?P<user>[a-z]+)@(?P<host>[a-z.]+)" --only-matches
+                | get user
+                | str-collect --separator ","
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(hosts, "nushell.sh,rust.lang");
+        assert_eq!(unmatched, "");
+        assert_eq!(only_matches, "andres,yehuda");
+    })
+}
+
+#[test]
+fn capture_all_gives_a_table_of_matches() {
+    Playground::setup("capture_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "contacts.csv",
+            r#"
+                team,emails
+                core,andres@nushell.sh yehuda@rust.lang
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open contacts.csv
+                | capture emails --regex "(?P<user>[a-z]+)@(?P<host>[a-z.]+)" --all
+                | get matches
+                | get user
+                | str-collect --separator ","
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "andres,yehuda");
+    })
+}
+
+#[test]
+fn capture_errors_on_an_invalid_regex() {
+    let actual = nu_error!(
+        cwd: "tests/fixtures/formats",
+        r#"open caco3_plastics.csv | capture importer --regex "(?P<name>[A-Z""#
+    );
+
+    assert!(actual.contains("Invalid regex"));
+}
+
+#[test]
+fn str_distance_between_columns() {
+    Playground::setup("str_distance_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "words.csv",
+            r#"
+                typed,meant
+                open,open
+                opem,open
+                book,back
+            "#,
+        )]);
+
+        let identical = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open words.csv
+                | str-distance typed meant
+                | where distance == 0
+                | get typed
+                | echo $it
+            "#
+        ));
+
+        let one_edit = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open words.csv
+                | str-distance typed meant
+                | where distance == 1
+                | get typed
+                | echo $it
+            "#
+        ));
+
+        let literal = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open words.csv
+                | str-distance typed open --literal --into edits
+                | nth 2
+                | get edits
+                | echo $it
+            "#
+        ));
+
+        let normalized = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open words.csv
+                | str-distance typed meant --normalized
+                | nth 2
+                | get distance
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(identical, "open");
+        assert_eq!(one_edit, "opem");
+        assert_eq!(literal, "4");
+        assert_eq!(normalized.parse::<f64>().unwrap(), 0.5);
+    })
+}
+
+#[test]
+fn str_distance_errors_on_non_strings() {
+    Playground::setup("str_distance_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "counts.json",
+            r#"
+                [{"name": "one", "count": 1}]
+            "#,
+        )]);
+
+        let actual = nu_error!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open counts.json
+                | str-distance name count
+            "#
+        ));
+
+        assert!(actual.contains("Expected a string"));
+    })
+}
+
+#[test]
+fn fuzzy_find_ranks_rows_by_closeness() {
+    Playground::setup("fuzzy_find_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "commands.csv",
+            r#"
+                name
+                sort-by
+                split-row
+                str-collect
+                sort-byy
+                Sort
+            "#,
+        )]);
+
+        let ranked = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open commands.csv
+                | fuzzy-find name sort-by
+                | first 3
+                | get name
+                | str-collect --separator ","
+                | echo $it
+            "#
+        ));
+
+        let close = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open commands.csv
+                | fuzzy-find name sort-by --threshold 0.5
+                | get name
+                | str-collect --separator ","
+                | echo $it
+            "#
+        ));
+
+        let everything = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open commands.csv
+                | fuzzy-find name ""
+                | count
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(ranked, "sort-by,sort-byy,Sort");
+        assert_eq!(close, "sort-by,sort-byy,Sort");
+        assert_eq!(everything, "5");
+    })
+}
+
+#[test]
+fn progress_passes_values_through_unchanged() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", h::pipeline(
+        r#"
+            echo [alpha beta gamma]
+            | progress --total 3
+            | str-collect --separator ","
+            | echo $it
+        "#
+    ));
+
+    let counter = nu_error!(
+        cwd: "tests/fixtures/formats", h::pipeline(
+        r#"
+            echo [alpha beta gamma]
+            | progress --total 3
+            | str-collect --separator ","
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "alpha,beta,gamma");
+    assert!(counter.contains("3/3 (100%)"));
+}
+
+#[test]
+fn sort_by_key_sorts_by_a_computed_key() {
+    Playground::setup("sort_by_key_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "members.json",
+            r#"
+                [
+                    {"name": "andres", "meta": {"rank": 3, "team": "core"}},
+                    {"name": "jonathan", "meta": {"rank": 1, "team": "web"}},
+                    {"name": "yehuda", "meta": {"rank": 2, "team": "core"}}
+                ]
+            "#,
+        )]);
+
+        let by_rank = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open members.json
+                | sort-by-key { $it.meta.rank }
+                | get name
+                | str-collect --separator ","
+                | echo $it
+            "#
+        ));
+
+        let by_rank_reversed = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open members.json
+                | sort-by-key { $it.meta.rank } --reverse
+                | get name
+                | str-collect --separator ","
+                | echo $it
+            "#
+        ));
+
+        let by_team = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open members.json
+                | sort-by-key { $it.meta.team } --reverse
+                | get name
+                | str-collect --separator ","
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(by_rank, "jonathan,yehuda,andres");
+        assert_eq!(by_rank_reversed, "andres,yehuda,jonathan");
+        assert_eq!(by_team, "jonathan,andres,yehuda");
+    })
+}
+
+#[test]
+fn sort_by_key_compares_ints_and_decimals_by_value() {
+    Playground::setup("sort_by_key_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "members.json",
+            r#"
+                [
+                    {"name": "andres", "score": 2},
+                    {"name": "jonathan", "score": 1.5},
+                    {"name": "yehuda", "score": 3}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open members.json
+                | sort-by-key { $it.score }
+                | get name
+                | str-collect --separator ","
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "jonathan,andres,yehuda");
+    })
+}
+
+#[test]
+fn uniq_keeps_first_or_last_occurrences() {
+    let first = nu!(
+        cwd: ".", h::pipeline(
+        r#"
+            echo [a b a c b]
+            | uniq
+            | str-collect --separator ","
+            | echo $it
+        "#
+    ));
+
+    let last = nu!(
+        cwd: ".", h::pipeline(
+        r#"
+            echo [a b a c b]
+            | uniq --last
+            | str-collect --separator ","
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(first, "a,b,c");
+    assert_eq!(last, "a,c,b");
+}
+
+#[test]
+fn uniq_keeps_last_row_for_each_key() {
+    Playground::setup("uniq_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "updates.json",
+            r#"
+                [
+                    {"id": "andres", "status": "away"},
+                    {"id": "jonathan", "status": "busy"},
+                    {"id": "andres", "status": "online"},
+                    {"id": "yehuda", "status": "away"},
+                    {"id": "jonathan", "status": "offline"}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open updates.json
+                | uniq --on [id] --last
+                | get status
+                | str-collect --separator ","
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "online,away,offline");
+    })
+}
+
+#[test]
+fn dedup_adjacent_collapses_runs_only() {
+    let actual = nu!(
+        cwd: ".", h::pipeline(
+        r#"
+            echo [a a b b b a c c]
+            | dedup-adjacent
+            | str-collect --separator ","
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "a,b,a,c");
+}
+
+#[test]
+fn dedup_adjacent_counts_runs() {
+    let values = nu!(
+        cwd: ".", h::pipeline(
+        r#"
+            echo [a a b b b a c c]
+            | dedup-adjacent --count
+            | get value
+            | str-collect --separator ","
+            | echo $it
+        "#
+    ));
+
+    let longest = nu!(
+        cwd: ".", h::pipeline(
+        r#"
+            echo [a a b b b a c c]
+            | dedup-adjacent --count
+            | where count == 3
+            | get value
+            | echo $it
+        "#
+    ));
+
+    let runs = nu!(
+        cwd: ".", h::pipeline(
+        r#"
+            echo [a a b b b a c c]
+            | dedup-adjacent --count
+            | where count == 1
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(values, "a,b,a,c");
+    assert_eq!(longest, "b");
+    assert_eq!(runs, "1");
+}
+
+#[test]
+fn reduce_folds_a_sum() {
+    Playground::setup("reduce_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "members.json",
+            r#"
+                [
+                    {"name": "andres", "commits": 10},
+                    {"name": "jonathan", "commits": 20},
+                    {"name": "yehuda", "commits": 12}
+                ]
+            "#,
+        )]);
+
+        let total = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open members.json
+                | reduce 0 { $acc + $it.commits }
+                | echo $it
+            "#
+        ));
+
+        let empty = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open members.json
+                | where commits > 100
+                | reduce 0 { $acc + $it.commits }
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(total, "42");
+        assert_eq!(empty, "0");
+    })
+}
+
+#[test]
+fn reduce_folds_into_a_table() {
+    let actual = nu!(
+        cwd: ".", h::pipeline(
+        r#"
+            echo [a b c]
+            | reduce [z] { $acc + [$it] }
+            | str-collect --separator ","
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "z,a,b,c");
+}
+
+#[test]
+fn reduce_reports_type_errors_in_the_block() {
+    let actual = nu_error!(
+        cwd: ".", h::pipeline(
+        r#"
+            echo [1 2 a]
+            | reduce 0 { $acc + $it }
+        "#
+    ));
+
+    assert!(actual.contains("Coercion error"));
+}
+
+#[test]
+fn describe_annotates_a_scalar() {
+    let plain = nu!(
+        cwd: ".", h::pipeline(
+        r#"
+            echo 5
+            | describe
+            | echo $it
+        "#
+    ));
+
+    let inline = nu!(
+        cwd: ".", h::pipeline(
+        r#"
+            echo 5
+            | describe --inline
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(plain, "integer");
+    assert_eq!(inline, "5 (integer)");
+}
+
+#[test]
+fn describe_annotates_each_cell_of_rows() {
+    Playground::setup("describe_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![
+            FileWithContentToBeTrimmed(
+                "member.json",
+                r#"
+                    {"name": "andres", "commits": 10}
+                "#,
+            ),
+            FileWithContentToBeTrimmed(
+                "members.json",
+                r#"
+                    [
+                        {"name": "andres", "commits": 10},
+                        {"name": "jonathan", "commits": 20}
+                    ]
+                "#,
+            ),
+        ]);
+
+        let row = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open member.json
+                | describe --inline
+                | get commits
+                | echo $it
+            "#
+        ));
+
+        let table = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open members.json
+                | describe --inline
+                | get name
+                | str-collect --separator ","
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(row, "10 (integer)");
+        assert_eq!(table, "andres (string),jonathan (string)");
+    })
+}
+
+#[test]
+fn unflatten_nests_dotted_columns() {
+    Playground::setup("unflatten_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "flat.json",
+            r#"
+                {
+                    "name": "nu",
+                    "author.name": "andres",
+                    "author.handle.github": "andrasio",
+                    "version": "0.6.2"
+                }
+            "#,
+        )]);
+
+        let nested = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open flat.json
+                | unflatten
+                | get author.handle.github
+                | echo $it
+            "#
+        ));
+
+        let top_level = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open flat.json
+                | unflatten
+                | get version
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(nested, "andrasio");
+        assert_eq!(top_level, "0.6.2");
+    })
+}
+
+#[test]
+fn unflatten_uses_a_custom_separator() {
+    Playground::setup("unflatten_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "flat.json",
+            r#"
+                {"name": "nu", "author_name": "andres", "author_email": "andres@nu", "version.major": 0}
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open flat.json
+                | unflatten --separator _
+                | get author
+                | get email
+                | echo $it
+            "#
+        ));
+
+        let untouched = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open flat.json
+                | unflatten --separator _
+                | get "version.major"
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "andres@nu");
+        assert_eq!(untouched, "0");
+    })
+}
+
+#[test]
+fn from_json_parses_json_strings_in_a_column() {
+    Playground::setup("from_json_column_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "events.json",
+            r#"
+                [
+                    {"kind": "push", "payload": "{\"branch\": \"master\", \"commits\": 3}"},
+                    {"kind": "tag", "payload": "{\"branch\": \"release\", \"commits\": 0}"}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open events.json
+                | from-json --column payload
+                | get payload.branch
+                | str-collect --separator ","
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "master,release");
+    })
+}
+
+#[test]
+fn from_json_column_keeps_non_json_cells_when_lenient() {
+    Playground::setup("from_json_column_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "events.json",
+            r#"
+                [
+                    {"kind": "push", "payload": "{\"branch\": \"master\"}"},
+                    {"kind": "note", "payload": "{oops"},
+                    {"kind": "word", "payload": "hello"}
+                ]
+            "#,
+        )]);
+
+        let lenient = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open events.json
+                | from-json --column payload --lenient
+                | where kind == note
+                | get payload
+                | echo $it
+            "#
+        ));
+
+        let word = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open events.json
+                | from-json --column payload --lenient
+                | where kind == word
+                | get payload
+                | echo $it
+            "#
+        ));
+
+        let strict = nu_error!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open events.json
+                | from-json --column payload
+            "#
+        ));
+
+        let plain_word = nu_error!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open events.json
+                | where kind == word
+                | from-json --column payload
+            "#
+        ));
+
+        assert_eq!(lenient, "{oops");
+        assert_eq!(word, "hello");
+        assert!(strict.contains("Could not parse as JSON"));
+        assert!(plain_word.contains("Could not parse as JSON"));
+    })
+}
+
+#[test]
+fn percentile_computes_median_and_90th() {
+    Playground::setup("percentile_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "latencies.json",
+            r#"
+                [
+                    {"ms": 40},
+                    {"ms": 15},
+                    {"ms": 50},
+                    {"ms": 20},
+                    {"ms": 35}
+                ]
+            "#,
+        )]);
+
+        let median = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open latencies.json
+                | percentile ms 50 90
+                | get p50
+                | echo $it
+            "#
+        ));
+
+        let ninetieth = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open latencies.json
+                | percentile ms 50 90
+                | get p90
+                | echo $it
+            "#
+        ));
+
+        let nearest = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open latencies.json
+                | percentile ms 90 --method nearest
+                | get p90
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(median.parse::<f64>().unwrap(), 35.0);
+        assert_eq!(ninetieth.parse::<f64>().unwrap(), 46.0);
+        assert_eq!(nearest.parse::<f64>().unwrap(), 50.0);
+    })
+}
+
+#[test]
+fn percentile_errors_when_out_of_range() {
+    let actual = nu_error!(
+        cwd: "tests/fixtures/formats", h::pipeline(
+        r#"
+            open caco3_plastics.csv
+            | percentile tariff_item 120
+        "#
+    ));
+
+    assert!(actual.contains("Percentile out of range"));
+}
+
+#[test]
+fn bin_spreads_equal_width_bins() {
+    Playground::setup("bin_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "latencies.json",
+            r#"
+                [
+                    {"ms": 15},
+                    {"ms": 20},
+                    {"ms": 35},
+                    {"ms": 40},
+                    {"ms": 50}
+                ]
+            "#,
+        )]);
+
+        let first = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open latencies.json
+                | bin ms --bins 5
+                | where bin == "[15, 22)"
+                | count
+                | echo $it
+            "#
+        ));
+
+        let largest = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open latencies.json
+                | bin ms --bins 5
+                | where ms == 50
+                | get bin
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(first, "2");
+        assert_eq!(largest, "[43, 50]");
+    })
+}
+
+#[test]
+fn bin_uses_explicit_edges() {
+    Playground::setup("bin_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "latencies.json",
+            r#"
+                [
+                    {"ms": 15},
+                    {"ms": 20},
+                    {"ms": 35},
+                    {"ms": 40},
+                    {"ms": 50}
+                ]
+            "#,
+        )]);
+
+        let at_edge = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open latencies.json
+                | bin ms --edges [0 20 40]
+                | where ms == 20
+                | get bin
+                | echo $it
+            "#
+        ));
+
+        let overflow = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open latencies.json
+                | bin ms --edges [0 20 40] --into range
+                | where ms == 50
+                | get range
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(at_edge, "[20, 40]");
+        assert_eq!(overflow, "(40, inf)");
+    })
+}
+
+#[test]
+fn schema_describes_uniform_columns() {
+    Playground::setup("schema_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "members.json",
+            r#"
+                [
+                    {"name": "andres", "commits": 10},
+                    {"name": "jonathan", "commits": 20}
+                ]
+            "#,
+        )]);
+
+        let names = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open members.json
+                | schema
+                | get name
+                | str-collect --separator ","
+                | echo $it
+            "#
+        ));
+
+        let types = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open members.json
+                | schema
+                | get type
+                | str-collect --separator ","
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(names, "name,commits");
+        assert_eq!(types, "string,integer");
+    })
+}
+
+#[test]
+fn schema_joins_mixed_types() {
+    Playground::setup("schema_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "readings.json",
+            r#"
+                [
+                    {"sensor": "a", "reading": 10},
+                    {"sensor": "b", "reading": "offline"},
+                    {"sensor": "c"}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open readings.json
+                | schema
+                | where name == reading
+                | get type
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "integer | string | nothing");
+    })
+}
+
+#[test]
+fn moving_average_averages_a_full_window() {
+    Playground::setup("moving_average_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "latencies.json",
+            r#"
+                [
+                    {"ms": 10},
+                    {"ms": 20},
+                    {"ms": 30},
+                    {"ms": 40}
+                ]
+            "#,
+        )]);
+
+        let full = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open latencies.json
+                | moving-average ms --window 2
+                | where ms == 40
+                | get ms_average
+                | echo $it
+            "#
+        ));
+
+        let filling = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open latencies.json
+                | moving-average ms --window 2
+                | where ms == 10
+                | get ms_average
+                | describe
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(full.parse::<f64>().unwrap(), 35.0);
+        assert_eq!(filling, "nothing");
+    })
+}
+
+#[test]
+fn moving_average_averages_partial_windows() {
+    Playground::setup("moving_average_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "latencies.json",
+            r#"
+                [
+                    {"ms": 10},
+                    {"ms": 20},
+                    {"ms": 30},
+                    {"ms": 40}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open latencies.json
+                | moving-average ms --window 3 --partial --into smooth
+                | where ms == 20
+                | get smooth
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual.parse::<f64>().unwrap(), 15.0);
+    })
+}
+
+#[test]
+fn moving_average_errors_on_non_numbers() {
+    let actual = nu_error!(
+        cwd: "tests/fixtures/formats", h::pipeline(
+        r#"
+            open caco3_plastics.csv
+            | moving-average origin --window 2
+        "#
+    ));
+
+    assert!(actual.contains("Expected a number"));
+}
+
+#[test]
+fn split_into_deals_rows_round_robin() {
+    let first = nu!(
+        cwd: ".", h::pipeline(
+        r#"
+            echo [a b c d e]
+            | split-into 2
+            | get part_0
+            | str-collect --separator ","
+            | echo $it
+        "#
+    ));
+
+    let second = nu!(
+        cwd: ".", h::pipeline(
+        r#"
+            echo [a b c d e]
+            | split-into 2
+            | get part_1
+            | str-collect --separator ","
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(first, "a,c,e");
+    assert_eq!(second, "b,d");
+}
+
+#[test]
+fn split_into_keeps_rows_with_the_same_key_together() {
+    Playground::setup("split_into_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "members.json",
+            r#"
+                [
+                    {"name": "andres", "team": "core"},
+                    {"name": "jonathan", "team": "web"},
+                    {"name": "yehuda", "team": "core"},
+                    {"name": "jason", "team": "docs"},
+                    {"name": "sophia", "team": "web"}
+                ]
+            "#,
+        )]);
+
+        let first = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open members.json
+                | split-into 2 --by team
+                | get part_0
+                | uniq --on [team]
+                | count
+                | echo $it
+            "#
+        ));
+
+        let second = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open members.json
+                | split-into 2 --by team
+                | get part_1
+                | uniq --on [team]
+                | count
+                | echo $it
+            "#
+        ));
+
+        // A team split across both parts would be counted twice.
+        let teams = first.parse::<usize>().unwrap() + second.parse::<usize>().unwrap();
+
+        assert_eq!(teams, 3);
+    })
+}
+
+#[test]
+fn correlation_of_related_columns() {
+    Playground::setup("correlation_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "readings.json",
+            r#"
+                [
+                    {"hours": 1, "score": 52, "errors": 8},
+                    {"hours": 2, "score": 54, "errors": 6},
+                    {"hours": 3, "score": 56, "errors": 4},
+                    {"hours": "n/a", "score": 90, "errors": 0},
+                    {"hours": 4, "score": 58, "errors": 2}
+                ]
+            "#,
+        )]);
+
+        let correlated = nu!(
+            cwd: dirs.test(),
+            "open readings.json | correlation hours score | echo $it"
+        );
+
+        let anti_correlated = nu!(
+            cwd: dirs.test(),
+            "open readings.json | correlation hours errors | echo $it"
+        );
+
+        let skipped = nu!(
+            cwd: dirs.test(),
+            "open readings.json | correlation hours score --report-skipped | get skipped | echo $it"
+        );
+
+        assert_eq!(correlated.parse::<f64>().unwrap(), 1.0);
+        assert_eq!(anti_correlated.parse::<f64>().unwrap(), -1.0);
+        assert_eq!(skipped, "1");
+    })
+}
+
+#[test]
+fn correlation_needs_two_numeric_rows() {
+    Playground::setup("correlation_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "readings.json",
+            r#"
+                [
+                    {"hours": 1, "score": 52},
+                    {"hours": "n/a", "score": 90}
+                ]
+            "#,
+        )]);
+
+        let actual = nu_error!(
+            cwd: dirs.test(),
+            "open readings.json | correlation hours score"
+        );
+
+        assert!(actual.contains("Not enough data"));
+    })
+}
+
+#[test]
+fn base_convert_writes_hex() {
+    Playground::setup("base_convert_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "registers.json",
+            r#"
+                [
+                    {"name": "status", "value": 255},
+                    {"name": "offset", "value": -16}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open registers.json
+                | base-convert value --to 16 --prefix
+                | get value
+                | str-collect --separator ","
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "0xff,-0x10");
+    })
+}
+
+#[test]
+fn base_convert_reads_binary() {
+    Playground::setup("base_convert_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "flags.json",
+            r#"
+                [
+                    {"name": "read", "bits": "0b100"},
+                    {"name": "write", "bits": "10"},
+                    {"name": "none", "bits": "-1"}
+                ]
+            "#,
+        )]);
+
+        let sum = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open flags.json
+                | base-convert bits --from 2
+                | get bits
+                | sum
+                | echo $it
+            "#
+        ));
+
+        let invalid = nu_error!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open flags.json
+                | base-convert name --from 2
+            "#
+        ));
+
+        assert_eq!(sum, "5");
+        assert!(invalid.contains("Could not read as a base 2 integer"));
+    })
+}
+
+#[test]
+fn base_convert_rejects_multibyte_digits() {
+    Playground::setup("base_convert_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "prices.json",
+            r#"
+                [
+                    {"price": "€1"}
+                ]
+            "#,
+        )]);
+
+        let actual = nu_error!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open prices.json
+                | base-convert price --from 16
+            "#
+        ));
+
+        assert!(actual.contains("Could not read as a base 16 integer"));
+    })
+}
+
+#[test]
+fn base_convert_rejects_a_second_sign() {
+    Playground::setup("base_convert_test_4", |dirs, sandbox| {
+        sandbox.with_files(vec![
+            FileWithContentToBeTrimmed(
+                "minus.json",
+                r#"
+                    [
+                        {"offset": "--5"}
+                    ]
+                "#,
+            ),
+            FileWithContentToBeTrimmed(
+                "plus.json",
+                r#"
+                    [
+                        {"offset": "+-5"}
+                    ]
+                "#,
+            ),
+        ]);
+
+        let minus = nu_error!(
+            cwd: dirs.test(),
+            "open minus.json | base-convert offset --from 16"
+        );
+
+        let plus = nu_error!(
+            cwd: dirs.test(),
+            "open plus.json | base-convert offset --from 16"
+        );
+
+        assert!(minus.contains("Could not read as a base 16 integer"));
+        assert!(plus.contains("Could not read as a base 16 integer"));
+    })
+}
+
+#[test]
+fn wrap_text_breaks_between_words() {
+    Playground::setup("wrap_text_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "notes.json",
+            r#"
+                [
+                    {"text": "the quick brown fox jumps over the lazy dog"}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open notes.json
+                | wrap-text text --width 10
+                | get text
+                | lines
+                | str-collect --separator "/"
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "the quick/brown fox/jumps over/the lazy/dog");
+    })
+}
+
+#[test]
+fn wrap_text_breaks_long_words_when_hard() {
+    Playground::setup("wrap_text_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "notes.json",
+            r#"
+                [
+                    {"text": "see supercalifragilistic now"}
+                ]
+            "#,
+        )]);
+
+        let soft = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open notes.json
+                | wrap-text text --width 8
+                | get text
+                | lines
+                | str-collect --separator "/"
+                | echo $it
+            "#
+        ));
+
+        let hard = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open notes.json
+                | wrap-text text --width 8 --hard
+                | get text
+                | lines
+                | str-collect --separator "/"
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(soft, "see/supercalifragilistic/now");
+        assert_eq!(hard, "see/supercal/ifragili/stic now");
+    })
+}
+
+#[test]
+fn wrap_text_measures_wide_characters() {
+    Playground::setup("wrap_text_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "notes.json",
+            r#"
+                [
+                    {"text": "日本語のテキスト"}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open notes.json
+                | wrap-text text --width 6 --hard
+                | get text
+                | lines
+                | str-collect --separator "/"
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "日本語/のテキ/スト");
+    })
+}
+
+#[test]
+fn wrap_text_keeps_combining_marks_with_their_letter() {
+    Playground::setup("wrap_text_test_4", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "notes.json",
+            r#"
+                [
+                    {"text": "cafe\u0301s"}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open notes.json
+                | wrap-text text --width 2 --hard
+                | get text
+                | lines
+                | str-collect --separator "/"
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "ca/fe\u{301}/s");
+    })
+}
+
+#[test]
+fn delta_subtracts_the_previous_row() {
+    Playground::setup("delta_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "sales.json",
+            r#"
+                {
+                    "sales": [
+                        {"day": "mon", "amount": 10},
+                        {"day": "tue", "amount": 15},
+                        {"day": "wed", "amount": 7}
+                    ]
+                }
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open sales.json
+                | get sales
+                | delta amount
+                | last 2
+                | get amount_delta
+                | str-collect --separator "/"
+            "#
+        ));
+
+        assert_eq!(actual, "5/-8");
+    })
+}
+
+#[test]
+fn delta_leaves_the_first_row_empty_unless_asked_for_zero() {
+    Playground::setup("delta_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "sales.json",
+            r#"
+                {
+                    "sales": [
+                        {"day": "mon", "amount": 10},
+                        {"day": "tue", "amount": 15}
+                    ]
+                }
+            "#,
+        )]);
+
+        let empty = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open sales.json
+                | get sales
+                | delta amount
+                | first 1
+                | get amount_delta
+                | describe
+            "#
+        ));
+
+        let zero = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open sales.json
+                | get sales
+                | delta amount --first-zero --into change
+                | first 1
+                | get change
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(empty, "nothing");
+        assert_eq!(zero, "0");
+    })
+}
+
+#[test]
+fn delta_computes_the_percentage_change() {
+    Playground::setup("delta_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "sales.json",
+            r#"
+                {
+                    "sales": [
+                        {"day": "mon", "amount": 100},
+                        {"day": "tue", "amount": 150},
+                        {"day": "wed", "amount": 75}
+                    ]
+                }
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open sales.json
+                | get sales
+                | delta amount --percent
+                | last 1
+                | get amount_delta
+                | echo $it
+            "#
+        ));
+
+        assert!(actual.starts_with("-50"));
+    })
+}
+
+#[test]
+fn delta_errors_on_non_numeric_cells() {
+    Playground::setup("delta_test_4", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "sales.json",
+            r#"
+                {
+                    "sales": [
+                        {"day": "mon", "amount": 1},
+                        {"day": "tue", "amount": "two"}
+                    ]
+                }
+            "#,
+        )]);
+
+        let actual = nu_error!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open sales.json
+                | get sales
+                | delta amount
+            "#
+        ));
+
+        assert!(actual.contains("Expected a number"));
+    })
+}
+
+#[test]
+fn concat_joins_a_column_of_tables() {
+    Playground::setup("concat_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "teams.json",
+            r#"
+                {
+                    "teams": [
+                        {"team": "nu", "members": [{"name": "andres"}, {"name": "jonathan"}]},
+                        {"team": "docs", "members": [{"name": "yehuda"}]}
+                    ]
+                }
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open teams.json
+                | get teams
+                | concat members
+                | get name
+                | str-collect --separator "/"
+            "#
+        ));
+
+        assert_eq!(actual, "andres/jonathan/yehuda");
+    })
+}
+
+#[test]
+fn concat_copies_the_key_into_each_row() {
+    Playground::setup("concat_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "teams.json",
+            r#"
+                {
+                    "teams": [
+                        {"team": "nu", "members": [{"name": "andres"}, {"name": "jonathan"}]},
+                        {"team": "docs", "members": [{"name": "yehuda"}]}
+                    ]
+                }
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open teams.json
+                | get teams
+                | concat members --key team
+                | get team
+                | str-collect --separator "/"
+            "#
+        ));
+
+        assert_eq!(actual, "nu/nu/docs");
+    })
+}
+
+#[test]
+fn concat_errors_on_cells_that_are_not_tables() {
+    Playground::setup("concat_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "teams.json",
+            r#"
+                {
+                    "teams": [
+                        {"team": "nu", "members": [{"name": "andres"}]},
+                        {"team": "docs", "members": "yehuda"}
+                    ]
+                }
+            "#,
+        )]);
+
+        let actual = nu_error!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open teams.json
+                | get teams
+                | concat members
+            "#
+        ));
+
+        assert!(actual.contains("Expected a table"));
+    })
+}
+
+#[test]
+fn mode_finds_the_most_common_value() {
+    Playground::setup("mode_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "pets.csv",
+            r#"
+                name,kind
+                andres,cat
+                jonathan,dog
+                yehuda,cat
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open pets.csv
+                | mode kind
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "cat");
+    })
+}
+
+#[test]
+fn mode_returns_every_tied_value_with_all() {
+    Playground::setup("mode_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "pets.csv",
+            r#"
+                name,kind
+                andres,dog
+                jonathan,cat
+                yehuda,fish
+                jason,dog
+                ashley,cat
+            "#,
+        )]);
+
+        let all = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open pets.csv
+                | mode kind --all
+                | str-collect --separator "/"
+            "#
+        ));
+
+        let first = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open pets.csv
+                | mode kind
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(all, "cat/dog");
+        assert_eq!(first, "cat");
+    })
+}
+
+#[test]
+fn mode_errors_on_empty_input() {
+    Playground::setup("mode_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "pets.csv",
+            r#"
+                name,kind
+                andres,cat
+            "#,
+        )]);
+
+        let actual = nu_error!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open pets.csv
+                | where kind == dog
+                | mode kind
+            "#
+        ));
+
+        assert!(actual.contains("No values to find the mode of"));
+    })
+}
+
+#[test]
+fn scale_rescales_from_zero_to_one() {
+    Playground::setup("scale_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "scores.json",
+            r#"
+                [
+                    {"name": "andres", "score": 10},
+                    {"name": "jonathan", "score": 20},
+                    {"name": "yehuda", "score": 30}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open scores.json
+                | scale score
+                | where score == 0.5
+                | get name
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "jonathan");
+    })
+}
+
+#[test]
+fn scale_standardizes_with_zscore() {
+    Playground::setup("scale_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "scores.json",
+            r#"
+                [
+                    {"name": "andres", "score": 2},
+                    {"name": "jonathan", "score": 4},
+                    {"name": "yehuda", "score": 4},
+                    {"name": "jason", "score": 4},
+                    {"name": "ashley", "score": 5},
+                    {"name": "sophia", "score": 5},
+                    {"name": "liam", "score": 7},
+                    {"name": "emma", "score": 9}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open scores.json
+                | scale score --method zscore
+                | where score == 2
+                | get name
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "emma");
+    })
+}
+
+#[test]
+fn scale_maps_a_constant_column_to_zero() {
+    Playground::setup("scale_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "scores.json",
+            r#"
+                [
+                    {"name": "andres", "score": 7},
+                    {"name": "jonathan", "score": 7}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open scores.json
+                | scale score
+                | where score == 0
+                | count
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "2");
+    })
+}
+
+#[test]
+fn into_date_reads_epoch_seconds() {
+    Playground::setup("into_date_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "logins.json",
+            r#"
+                [
+                    {"user": "andres", "at": 1577836800}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open logins.json
+                | into-date at
+                | to-json
+                | echo $it
+            "#
+        ));
+
+        assert!(actual.contains("2020-01-01 00:00:00 UTC"));
+    })
+}
+
+#[test]
+fn into_date_reads_epoch_milliseconds_from_strings() {
+    Playground::setup("into_date_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "logins.csv",
+            r#"
+                user,at
+                andres,1577836800500
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open logins.csv
+                | into-date at --unit ms --from-string
+                | to-json
+                | echo $it
+            "#
+        ));
+
+        assert!(actual.contains("2020-01-01 00:00:00.500 UTC"));
+    })
+}
+
+#[test]
+fn into_date_errors_on_epochs_out_of_range() {
+    Playground::setup("into_date_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "logins.json",
+            r#"
+                [
+                    {"user": "andres", "at": 1577836800},
+                    {"user": "jonathan", "at": 9223372036854775807}
+                ]
+            "#,
+        )]);
+
+        let actual = nu_error!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open logins.json
+                | into-date at
+            "#
+        ));
+
+        assert!(actual.contains("Epoch out of range"));
+    })
+}
+
+#[test]
+fn values_lists_distinct_values_in_order_of_appearance() {
+    Playground::setup("values_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "pets.csv",
+            r#"
+                name,kind
+                andres,dog
+                jonathan,cat
+                yehuda,dog
+                jason,bird
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open pets.csv
+                | values kind
+                | str-collect --separator "/"
+            "#
+        ));
+
+        assert_eq!(actual, "dog/cat/bird");
+    })
+}
+
+#[test]
+fn values_sorts_the_distinct_values() {
+    Playground::setup("values_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "pets.csv",
+            r#"
+                name,kind
+                andres,dog
+                jonathan,cat
+                yehuda,dog
+                jason,bird
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open pets.csv
+                | values kind --sorted
+                | str-collect --separator "/"
+            "#
+        ));
+
+        assert_eq!(actual, "bird/cat/dog");
+    })
+}
+
+#[test]
+fn values_suggests_a_column_when_it_is_missing() {
+    Playground::setup("values_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "pets.csv",
+            r#"
+                name,kind
+                andres,dog
+            "#,
+        )]);
+
+        let actual = nu_error!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open pets.csv
+                | values knid
+            "#
+        ));
+
+        assert!(actual.contains("did you mean 'kind'?"));
+    })
+}
+
+#[test]
+fn crosstab_counts_pairs_of_values() {
+    Playground::setup("crosstab_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "pets.csv",
+            r#"
+                owner,kind
+                andres,dog
+                andres,cat
+                andres,dog
+                jonathan,cat
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open pets.csv
+                | crosstab owner kind
+                | where owner == jonathan
+                | get dog
+                | echo $it
+            "#
+        ));
+
+        let andres = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open pets.csv
+                | crosstab owner kind
+                | where owner == andres
+                | get dog
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "0");
+        assert_eq!(andres, "2");
+    })
+}
+
+#[test]
+fn crosstab_normalizes_counts_into_fractions() {
+    Playground::setup("crosstab_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "pets.csv",
+            r#"
+                owner,kind
+                andres,dog
+                andres,cat
+                andres,dog
+                jonathan,cat
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open pets.csv
+                | crosstab owner kind --normalize
+                | where owner == andres
+                | get dog
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "0.5");
+    })
+}
+
+#[test]
+fn row_diff_lists_the_differing_column() {
+    Playground::setup("row_diff_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "amigos.json",
+            r#"
+                [
+                    {"name": "Andres", "lang": "rust", "city": "Guayaquil"},
+                    {"name": "Andres", "lang": "rust", "city": "Quito"}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open amigos.json
+                | row-diff
+                | where column == city
+                | get right
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "Quito");
+    })
+}
+
+#[test]
+fn row_diff_lists_columns_present_on_one_side() {
+    Playground::setup("row_diff_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "amigos.json",
+            r#"
+                [
+                    {"name": "Jonathan", "lang": "rust"},
+                    {"name": "Jonathan", "lang": "rust", "editor": "vim"}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open amigos.json
+                | row-diff
+                | where column == editor
+                | get left
+                | describe
+            "#
+        ));
+
+        assert_eq!(actual, "nothing");
+    })
+}
+
+#[test]
+fn row_diff_gives_nothing_for_identical_rows() {
+    Playground::setup("row_diff_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "amigos.json",
+            r#"
+                [
+                    {"name": "Yehuda", "lang": "rust"}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open amigos.json
+                | row-diff { $it.0 }
+                | count
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "0");
+    })
+}
+
+#[test]
+fn to_fraction_writes_decimals_as_reduced_fractions() {
+    Playground::setup("to_fraction_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "shares.json",
+            r#"
+                [
+                    {"name": "andres", "share": 0.5},
+                    {"name": "jonathan", "share": 0.75}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open shares.json
+                | to-fraction share
+                | get share
+                | str-collect --separator " "
+            "#
+        ));
+
+        assert_eq!(actual, "1/2 3/4");
+    })
+}
+
+#[test]
+fn to_fraction_finds_the_closest_fraction_within_the_limit() {
+    Playground::setup("to_fraction_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "shares.json",
+            r#"
+                [
+                    {"name": "andres", "share": 0.3333}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open shares.json
+                | to-fraction share --limit 100
+                | get share
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "1/3");
+    })
+}
+
+#[test]
+fn to_fraction_is_exact_without_a_limit() {
+    Playground::setup("to_fraction_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "shares.json",
+            r#"
+                [
+                    {"name": "andres", "share": 0.3333},
+                    {"name": "jonathan", "share": 0.0001},
+                    {"name": "yehuda", "share": 1.0001}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open shares.json
+                | to-fraction share
+                | get share
+                | str-collect --separator " "
+            "#
+        ));
+
+        assert_eq!(actual, "3333/10000 1/10000 10001/10000");
+    })
+}
+
+#[test]
+fn from_fraction_reads_fractions_into_decimals() {
+    Playground::setup("from_fraction_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "shares.csv",
+            r#"
+                name,share
+                andres,3/4
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open shares.csv
+                | from-fraction share
+                | where share == 0.75
+                | get name
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "andres");
+    })
+}
+
+#[test]
+fn from_fraction_errors_on_a_zero_denominator() {
+    Playground::setup("from_fraction_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "shares.csv",
+            r#"
+                name,share
+                andres,3/0
+            "#,
+        )]);
+
+        let actual = nu_error!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open shares.csv
+                | from-fraction share
+            "#
+        ));
+
+        assert!(actual.contains("Division by zero"));
+    })
+}
+
+#[test]
+fn round_keeps_the_given_decimal_places() {
+    Playground::setup("round_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "prices.json",
+            r#"
+                [
+                    {"item": "arepa", "price": 3.14159},
+                    {"item": "empanada", "price": 2.676}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open prices.json
+                | round price --to 2
+                | get price
+                | str-collect --separator " "
+            "#
+        ));
+
+        assert_eq!(actual, "3.14 2.68");
+    })
+}
+
+#[test]
+fn round_floors_with_floor_mode() {
+    Playground::setup("round_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "prices.json",
+            r#"
+                [
+                    {"item": "arepa", "price": 3.99},
+                    {"item": "empanada", "price": -1.2}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open prices.json
+                | round price --mode floor
+                | get price
+                | str-collect --separator " "
+            "#
+        ));
+
+        assert_eq!(actual, "3 -2");
+    })
+}
+
+#[test]
+fn round_to_negative_places_rounds_to_tens_and_hundreds() {
+    Playground::setup("round_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "visits.json",
+            r#"
+                [
+                    {"page": "home", "visits": 1250},
+                    {"page": "docs", "visits": 1249}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open visits.json
+                | round visits --to -2
+                | get visits
+                | str-collect --separator " "
+            "#
+        ));
+
+        assert_eq!(actual, "1300 1200");
+    })
+}
+
+#[test]
+fn abs_turns_negative_integers_and_decimals_positive() {
+    Playground::setup("abs_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "balances.json",
+            r#"
+                [
+                    {"name": "andres", "balance": -5, "change": -1.5},
+                    {"name": "jonathan", "balance": 3, "change": 2.25}
+                ]
+            "#,
+        )]);
+
+        let balances = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open balances.json
+                | abs balance change
+                | get balance
+                | str-collect --separator " "
+            "#
+        ));
+
+        let changes = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open balances.json
+                | abs balance change
+                | get change
+                | str-collect --separator " "
+            "#
+        ));
+
+        assert_eq!(balances, "5 3");
+        assert_eq!(changes, "1.5 2.25");
+    })
+}
+
+#[test]
+fn abs_works_on_the_values_themselves() {
+    let actual = nu!(
+        cwd: ".", h::pipeline(
+        r#"
+            echo [-3 4]
+            | abs
+            | str-collect --separator " "
+        "#
+    ));
+
+    assert_eq!(actual, "3 4");
+}
+
+#[test]
+fn sign_gives_minus_one_zero_or_one() {
+    Playground::setup("sign_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "balances.json",
+            r#"
+                [
+                    {"name": "andres", "balance": -5},
+                    {"name": "jonathan", "balance": 0},
+                    {"name": "yehuda", "balance": 0.5}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open balances.json
+                | sign balance
+                | get balance
+                | str-collect --separator " "
+            "#
+        ));
+
+        assert_eq!(actual, "-1 0 1");
+    })
+}
+
+#[test]
+fn sign_errors_on_non_numeric_cells() {
+    Playground::setup("sign_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "balances.json",
+            r#"
+                [
+                    {"name": "andres", "balance": "lots"}
+                ]
+            "#,
+        )]);
+
+        let actual = nu_error!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open balances.json
+                | sign balance
+            "#
+        ));
+
+        assert!(actual.contains("Expected a number"));
+    })
+}
+
+#[test]
+fn clamp_raises_numbers_below_the_minimum_and_lowers_those_above_the_maximum() {
+    Playground::setup("clamp_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "readings.json",
+            r#"
+                [
+                    {"sensor": "a", "level": -4},
+                    {"sensor": "b", "level": 7},
+                    {"sensor": "c", "level": 12.5}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open readings.json
+                | clamp level --min 0 --max 10
+                | get level
+                | str-collect --separator " "
+            "#
+        ));
+
+        assert_eq!(actual, "0 7 10");
+    })
+}
+
+#[test]
+fn clamp_with_one_bound_only_limits_that_side() {
+    Playground::setup("clamp_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "readings.json",
+            r#"
+                [
+                    {"sensor": "a", "level": -4},
+                    {"sensor": "b", "level": 70}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open readings.json
+                | clamp level --min 0
+                | get level
+                | str-collect --separator " "
+            "#
+        ));
+
+        assert_eq!(actual, "0 70");
+    })
+}
+
+#[test]
+fn clamp_errors_when_the_minimum_is_above_the_maximum() {
+    Playground::setup("clamp_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "readings.json",
+            r#"
+                [
+                    {"sensor": "a", "level": 4}
+                ]
+            "#,
+        )]);
+
+        let actual = nu_error!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open readings.json
+                | clamp level --min 10 --max 5
+            "#
+        ));
+
+        assert!(actual.contains("the minimum is greater than the maximum"));
+    })
+}
+
+#[test]
+fn math_takes_square_roots() {
+    Playground::setup("math_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "areas.json",
+            r#"
+                [
+                    {"room": "kitchen", "area": 16}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open areas.json
+                | math sqrt area
+                | get area
+                | echo $it
+            "#
+        ));
+
+        assert!(actual.starts_with("4"));
+    })
+}
+
+#[test]
+fn math_takes_base_ten_logarithms() {
+    Playground::setup("math_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "visits.json",
+            r#"
+                [
+                    {"page": "home", "visits": 1000}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open visits.json
+                | math log visits
+                | where visits > 2.99
+                | where visits < 3.01
+                | get page
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "home");
+    })
+}
+
+#[test]
+fn math_raises_to_a_power() {
+    Playground::setup("math_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "sides.json",
+            r#"
+                [
+                    {"cube": "small", "side": 2}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open sides.json
+                | math pow side 3
+                | get side
+                | echo $it
+            "#
+        ));
+
+        assert!(actual.starts_with("8"));
+    })
+}
+
+#[test]
+fn math_errors_outside_the_domain_unless_allowed() {
+    Playground::setup("math_test_4", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "areas.json",
+            r#"
+                [
+                    {"room": "kitchen", "area": -16}
+                ]
+            "#,
+        )]);
+
+        let actual = nu_error!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open areas.json
+                | math sqrt area
+            "#
+        ));
+
+        let allowed = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open areas.json
+                | math sqrt area --allow-nan
+                | get area
+                | describe
+            "#
+        ));
+
+        assert!(actual.contains("Outside the function's domain"));
+        assert_eq!(allowed, "nothing");
+    })
+}
+
+#[test]
+fn str_case_writes_title_case() {
+    Playground::setup("str_case_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "books.json",
+            r#"
+                [
+                    {"title": "the rust_programming-LANGUAGE"}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open books.json
+                | str-case title title
+                | get title
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "The Rust Programming Language");
+    })
+}
+
+#[test]
+fn str_case_writes_snake_case() {
+    Playground::setup("str_case_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "fields.json",
+            r#"
+                [
+                    {"field": "parseHTTPRequest"}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open fields.json
+                | str-case field snake
+                | get field
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "parse_http_request");
+    })
+}
+
+#[test]
+fn str_case_writes_camel_case() {
+    Playground::setup("str_case_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "fields.json",
+            r#"
+                [
+                    {"field": "User first-name"}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open fields.json
+                | str-case field camel
+                | get field
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "userFirstName");
+    })
+}
+
+#[test]
+fn str_case_folds_unicode_case() {
+    Playground::setup("str_case_test_4", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "streets.json",
+            r#"
+                [
+                    {"street": "élan straße"}
+                ]
+            "#,
+        )]);
+
+        let upper = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open streets.json
+                | str-case street upper
+                | get street
+                | echo $it
+            "#
+        ));
+
+        let title = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open streets.json
+                | str-case street title
+                | get street
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(upper, "ÉLAN STRASSE");
+        assert_eq!(title, "Élan Straße");
+    })
+}
+
+#[test]
+fn zfill_pads_short_numbers_with_zeros() {
+    Playground::setup("zfill_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "orders.json",
+            r#"
+                [
+                    {"id": 7},
+                    {"id": "42"}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open orders.json
+                | zfill id 4
+                | get id
+                | str-collect --separator " "
+            "#
+        ));
+
+        assert_eq!(actual, "0007 0042");
+    })
+}
+
+#[test]
+fn zfill_keeps_the_sign_in_front() {
+    Playground::setup("zfill_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "orders.json",
+            r#"
+                [
+                    {"id": -7}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open orders.json
+                | zfill id 4
+                | get id
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "-007");
+    })
+}
+
+#[test]
+fn zfill_leaves_wide_numbers_unchanged() {
+    Playground::setup("zfill_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "orders.json",
+            r#"
+                [
+                    {"id": 123456}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open orders.json
+                | zfill id 4
+                | get id
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "123456");
+    })
+}
+
+#[test]
+fn zfill_errors_on_strings_that_are_not_numbers() {
+    Playground::setup("zfill_test_4", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "orders.json",
+            r#"
+                [
+                    {"id": "abc"}
+                ]
+            "#,
+        )]);
+
+        let actual = nu_error!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open orders.json
+                | zfill id 4
+            "#
+        ));
+
+        assert!(actual.contains("isn't a number"));
+    })
+}
+
+#[test]
+fn str_substring_slices_forward() {
+    Playground::setup("str_substring_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "files.json",
+            r#"
+                [
+                    {"name": "hello world"}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open files.json
+                | str-substring name 0 5
+                | get name
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "hello");
+    })
+}
+
+#[test]
+fn str_substring_counts_negative_indices_from_the_end() {
+    Playground::setup("str_substring_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "files.json",
+            r#"
+                [
+                    {"name": "report.csv"}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open files.json
+                | str-substring name 0 -4
+                | get name
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "report");
+    })
+}
+
+#[test]
+fn str_substring_clamps_indices_on_multibyte_strings() {
+    Playground::setup("str_substring_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "files.json",
+            r#"
+                [
+                    {"name": "¡café ñu!"}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open files.json
+                | str-substring name 2 100
+                | get name
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "afé ñu!");
+    })
+}
+
+#[test]
+fn str_index_of_finds_the_first_occurrence() {
+    Playground::setup("str_index_of_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "paths.json",
+            r#"
+                [
+                    {"path": "año/src/lib.rs"}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open paths.json
+                | str-index-of path "/"
+                | get index
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "3");
+    })
+}
+
+#[test]
+fn str_index_of_gives_minus_one_when_absent() {
+    Playground::setup("str_index_of_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "paths.json",
+            r#"
+                [
+                    {"path": "src/lib.rs"}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open paths.json
+                | str-index-of path ".toml" --into at
+                | get at
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "-1");
+    })
+}
+
+#[test]
+fn str_index_of_finds_the_last_occurrence_with_end() {
+    Playground::setup("str_index_of_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "paths.json",
+            r#"
+                [
+                    {"path": "año/src/lib.rs"}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open paths.json
+                | str-index-of path "/" --end
+                | get index
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "7");
+    })
+}
+
+#[test]
+fn str_index_of_ignores_case_when_insensitive() {
+    Playground::setup("str_index_of_test_4", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "paths.json",
+            r#"
+                [
+                    {"path": "docs/README.md"}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open paths.json
+                | str-index-of path readme --insensitive
+                | get index
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "5");
+    })
+}
+
+#[test]
+fn str_starts_with_keeps_matching_rows() {
+    Playground::setup("str_starts_with_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "files.json",
+            r#"
+                [
+                    {"name": "src/lib.rs"},
+                    {"name": "tests/commands_test.rs"},
+                    {"name": "src/cli.rs"},
+                    {"name": 5}
+                ]
+            "#,
+        )]);
+
+        let matching = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open files.json
+                | str-starts-with name "src/"
+                | get name
+                | str-collect --separator " "
+            "#
+        ));
+
+        let none = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open files.json
+                | str-starts-with name "docs/"
+                | count
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(matching, "src/lib.rs src/cli.rs");
+        assert_eq!(none, "0");
+    })
+}
+
+#[test]
+fn str_ends_with_ignores_case_when_insensitive() {
+    Playground::setup("str_ends_with_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "files.json",
+            r#"
+                [
+                    {"name": "README.MD"},
+                    {"name": "lib.rs"}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open files.json
+                | str-ends-with name ".md" --insensitive
+                | get name
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "README.MD");
+    })
+}
+
+#[test]
+fn str_ends_with_inverted_keeps_the_rows_that_do_not_match() {
+    Playground::setup("str_ends_with_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "files.json",
+            r#"
+                [
+                    {"name": "README.md"},
+                    {"name": "lib.rs"},
+                    {"name": "cli.rs"}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open files.json
+                | str-ends-with name ".rs" --invert
+                | get name
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "README.md");
+    })
+}
+
+#[test]
+fn fill_nulls_fills_every_null_cell() {
+    Playground::setup("fill_nulls_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_caballeros.json",
+            r#"
+                [
+                    {"name": "Andrés", "lucky_number": null},
+                    {"name": null, "lucky_number": 3}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open los_tres_caballeros.json
+                | fill-nulls --with "N/A"
+                | to-json
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(
+            actual,
+            r#"[{"name":"Andrés","lucky_number":"N/A"},{"name":"N/A","lucky_number":3}]"#
+        );
+    })
+}
+
+#[test]
+fn fill_nulls_fills_only_the_given_columns() {
+    Playground::setup("fill_nulls_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_caballeros.json",
+            r#"
+                [
+                    {"name": null, "lucky_number": null}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open los_tres_caballeros.json
+                | fill-nulls name --with "N/A"
+                | to-json
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, r#"{"name":"N/A","lucky_number":null}"#);
+    })
+}
+
+#[test]
+fn fill_nulls_includes_empty_strings_when_asked() {
+    Playground::setup("fill_nulls_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_caballeros.json",
+            r#"
+                [
+                    {"name": "", "rusty_luck": null}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open los_tres_caballeros.json
+                | fill-nulls --with "-" --include-empty
+                | format "{name}{rusty_luck}"
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "--");
+    })
+}
+
+#[test]
+fn schema_diff_shows_added_and_removed_columns() {
+    Playground::setup("schema_diff_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "members.json",
+            r#"
+                [
+                    {"name": "andres", "nick": "andy", "next": {"name": "andres", "commits": 10}}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open members.json
+                | schema-diff { $it.next }
+                | format "{column}:{change}"
+                | str-collect --separator " "
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "nick:removed next:removed commits:added");
+    })
+}
+
+#[test]
+fn schema_diff_shows_retyped_columns() {
+    Playground::setup("schema_diff_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "members.json",
+            r#"
+                [
+                    {"commits": 10, "next": {"commits": "ten"}}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open members.json
+                | schema-diff { $it.next }
+                | where change == retyped
+                | format "{column}:{change}:{old}:{new}"
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "commits:retyped:integer:string");
+    })
+}
+
+#[test]
+fn json_pointer_resolves_nested_keys_and_array_indices() {
+    Playground::setup("json_pointer_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_caballeros.json",
+            r#"
+                [
+                    {"name": "Andrés", "address": {"city": "Guayaquil"}, "langs": ["rust", "nu"]}
+                ]
+            "#,
+        )]);
+
+        let nested = nu!(
+            cwd: dirs.test(),
+            r#"open los_tres_caballeros.json | json-pointer "/address/city" | echo $it"#
+        );
+
+        let indexed = nu!(
+            cwd: dirs.test(),
+            r#"open los_tres_caballeros.json | json-pointer "/langs/1" | echo $it"#
+        );
+
+        assert_eq!(nested, "Guayaquil");
+        assert_eq!(indexed, "nu");
+    })
+}
+
+#[test]
+fn json_pointer_decodes_escaped_tokens() {
+    Playground::setup("json_pointer_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "paths.json",
+            r#"
+                [
+                    {"a/b": {"c~d": "found"}}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"open paths.json | json-pointer "/a~1b/c~0d" | echo $it"#
+        );
+
+        assert_eq!(actual, "found");
+    })
+}
+
+#[test]
+fn json_pointer_errors_on_missing_values_when_strict() {
+    Playground::setup("json_pointer_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_caballeros.json",
+            r#"
+                [
+                    {"name": "Andrés"}
+                ]
+            "#,
+        )]);
+
+        let lenient = nu!(
+            cwd: dirs.test(),
+            r#"open los_tres_caballeros.json | json-pointer "/address/city" | describe | echo $it"#
+        );
+
+        let strict = nu_error!(
+            cwd: dirs.test(),
+            r#"open los_tres_caballeros.json | json-pointer "/address/city" --strict"#
+        );
+
+        assert_eq!(lenient, "nothing");
+        assert!(strict.contains("Pointer does not resolve"));
+    })
+}
+
+#[test]
+fn cumulative_max_keeps_the_largest_number_so_far() {
+    Playground::setup("cumulative_max_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "latencies.json",
+            r#"
+                [
+                    {"ms": 10},
+                    {"ms": 30},
+                    {"ms": 20},
+                    {"ms": 40}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open latencies.json
+                | cumulative-max ms
+                | format "{ms_max}"
+                | str-collect --separator " "
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "10 30 30 40");
+    })
+}
+
+#[test]
+fn cumulative_min_keeps_the_earliest_date_so_far() {
+    Playground::setup("cumulative_min_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "events.json",
+            r#"
+                [
+                    {"at": 1577923200},
+                    {"at": 1577836800},
+                    {"at": 1578009600}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open events.json
+                | into-date at
+                | cumulative-min at --into earliest
+                | last 1
+                | get earliest
+                | to-json
+                | echo $it
+            "#
+        ));
+
+        assert!(actual.contains("2020-01-01 00:00:00 UTC"));
+    })
+}
+
+#[test]
+fn cumulative_max_compares_file_sizes() {
+    Playground::setup("cumulative_max_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![
+            FileWithContent("a.txt", &"a".repeat(10)),
+            FileWithContent("b.txt", &"b".repeat(30)),
+            FileWithContent("c.txt", &"c".repeat(20)),
+        ]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                ls
+                | sort-by name
+                | cumulative-max size
+                | get size_max
+                | to-json
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "[10,30,30]");
+    })
+}
+
+#[test]
+fn outliers_flags_values_far_outside_the_quartiles() {
+    Playground::setup("outliers_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "latencies.json",
+            r#"
+                [
+                    {"ms": 10},
+                    {"ms": 11},
+                    {"ms": 100},
+                    {"ms": 12},
+                    {"ms": 13},
+                    {"ms": 14}
+                ]
+            "#,
+        )]);
+
+        let flagged = nu!(
+            cwd: dirs.test(),
+            "open latencies.json | outliers ms | count | echo $it"
+        );
+
+        let only = nu!(
+            cwd: dirs.test(),
+            "open latencies.json | outliers ms --only | get ms | echo $it"
+        );
+
+        assert_eq!(flagged, "6");
+        assert_eq!(only, "100");
+    })
+}
+
+#[test]
+fn outliers_flags_values_many_standard_deviations_from_the_mean() {
+    Playground::setup("outliers_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "latencies.json",
+            r#"
+                [
+                    {"ms": 10}, {"ms": 10}, {"ms": 10}, {"ms": 10}, {"ms": 10},
+                    {"ms": 10}, {"ms": 10}, {"ms": 10}, {"ms": 10}, {"ms": 50}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "open latencies.json | outliers ms --zscore 2 --only | get ms | echo $it"
+        );
+
+        assert_eq!(actual, "50");
+    })
+}
+
+#[test]
+fn outliers_errors_with_fewer_than_four_values_for_iqr() {
+    Playground::setup("outliers_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "latencies.json",
+            r#"
+                [
+                    {"ms": 10},
+                    {"ms": 11},
+                    {"ms": 100}
+                ]
+            "#,
+        )]);
+
+        let actual = nu_error!(
+            cwd: dirs.test(),
+            "open latencies.json | outliers ms"
+        );
+
+        assert!(actual.contains("Not enough values for the IQR method"));
+    })
+}
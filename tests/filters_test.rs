@@ -258,6 +258,67 @@ fn converts_from_json_text_recognizing_objects_independendtly_to_structured_tabl
     })
 }
 
+#[test]
+fn from_json_accepts_documents_nested_up_to_max_depth() {
+    Playground::setup("filter_from_json_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "nested.txt",
+            r#"
+                {"a": {"b": 1}}
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "open nested.txt | from-json --max-depth 2 | get a.b | echo $it"
+        );
+
+        assert_eq!(actual, "1");
+    })
+}
+
+#[test]
+fn from_json_rejects_documents_nested_past_max_depth() {
+    Playground::setup("filter_from_json_test_4", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "nested.txt",
+            r#"
+                {"a": {"b": 1}}
+            "#,
+        )]);
+
+        let actual = nu_error!(
+            cwd: dirs.test(),
+            "open nested.txt | from-json --max-depth 1"
+        );
+
+        assert!(actual.contains("nested too deeply"));
+    })
+}
+
+#[test]
+fn from_json_rejects_documents_nested_past_the_default_depth() {
+    Playground::setup("filter_from_json_test_5", |dirs, sandbox| {
+        let deepest = format!("{}1{}", "[".repeat(128), "]".repeat(128));
+        let too_deep = format!("{}1{}", "[".repeat(129), "]".repeat(129));
+
+        sandbox.with_files(vec![
+            FileWithContent("deepest.txt", &deepest),
+            FileWithContent("too_deep.txt", &too_deep),
+        ]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "open deepest.txt | from-json | count | echo $it"
+        );
+
+        let too_deep = nu_error!(cwd: dirs.test(), "open too_deep.txt | from-json");
+
+        assert_eq!(actual, "1");
+        assert!(too_deep.contains("nested too deeply"));
+    })
+}
+
 #[test]
 fn converts_structured_table_to_json_text() {
     Playground::setup("filter_to_json_test", |dirs, sandbox| {
@@ -288,6 +349,132 @@ fn converts_structured_table_to_json_text() {
     })
 }
 
+#[test]
+fn converts_table_to_jsonl_text_and_back_with_from_json_objects() {
+    Playground::setup("filter_to_jsonl_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "katz.json",
+            r#"
+                {
+                    "katz": [
+                        {"name":   "Yehuda", "rusty_luck": 1},
+                        {"name": "Jonathan", "rusty_luck": 2},
+                        {"name":   "Andres", "rusty_luck": 3}
+                    ]
+                }
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open katz.json
+                | get katz
+                | to-jsonl
+                | from-json --objects
+                | where name == "Jonathan"
+                | get rusty_luck
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "2");
+    })
+}
+
+#[test]
+fn to_jsonl_writes_each_value_on_exactly_one_line() {
+    Playground::setup("filter_to_jsonl_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "notes.json",
+            r#"
+                [
+                    {"title": "first", "body": "line one\nline two"},
+                    {"title": "second", "body": "just one line"}
+                ]
+            "#,
+        )]);
+
+        nu!(
+            cwd: dirs.test(),
+            "open notes.json | save notes.jsonl"
+        );
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "open notes.jsonl --raw | lines | count | echo $it"
+        );
+
+        assert_eq!(actual, "2");
+    })
+}
+
+#[test]
+fn to_sql_escapes_quotes_in_strings() {
+    Playground::setup("filter_to_sql_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_caballeros.json",
+            r#"
+                [
+                    {"name": "Andrés", "nick": "o'brien"}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "open los_tres_caballeros.json | to-sql --table amigos | echo $it"
+        );
+
+        assert!(actual
+            .contains(r#"INSERT INTO "amigos" ("name", "nick") VALUES ('Andrés', 'o''brien');"#));
+    })
+}
+
+#[test]
+fn to_sql_writes_null_for_nothing_and_missing_cells() {
+    Playground::setup("filter_to_sql_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_caballeros.json",
+            r#"
+                [
+                    {"name": "Andrés", "rusty_luck": null},
+                    {"name": "Jonathan"}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "open los_tres_caballeros.json | to-sql --table amigos | echo $it"
+        );
+
+        assert!(actual.contains(r#"VALUES ('Andrés', NULL);"#));
+        assert!(actual.contains(r#"VALUES ('Jonathan', NULL);"#));
+    })
+}
+
+#[test]
+fn to_sql_writes_dates_as_iso_strings() {
+    Playground::setup("filter_to_sql_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "events.json",
+            r#"
+                [
+                    {"at": 1577836800}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "open events.json | into-date at | to-sql --table events | echo $it"
+        );
+
+        assert!(actual.contains("VALUES ('2020-01-01T00:00:00+00:00');"));
+    })
+}
+
 #[test]
 fn can_convert_table_to_tsv_text_and_from_tsv_text_back_into_table() {
     let actual = nu!(
@@ -585,6 +772,64 @@ fn can_convert_table_to_toml_text_and_from_toml_text_back_into_table() {
     assert_eq!(actual, "nu");
 }
 
+#[test]
+fn converts_nested_toml_tables_and_arrays_of_tables() {
+    Playground::setup("filter_from_toml_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "config.toml",
+            r#"
+                [server.tls]
+                port = 443
+
+                [[members]]
+                name = "Andrés"
+
+                [[members]]
+                name = "Jonathan"
+            "#,
+        )]);
+
+        let port = nu!(
+            cwd: dirs.test(),
+            "open config.toml | get server.tls.port | echo $it"
+        );
+
+        let members = nu!(
+            cwd: dirs.test(),
+            r#"open config.toml | get members.name | str-collect --separator "," | echo $it"#
+        );
+
+        assert_eq!(port, "443");
+        assert_eq!(members, "Andrés,Jonathan");
+    })
+}
+
+#[test]
+fn converts_toml_datetimes_with_offsets_into_dates() {
+    Playground::setup("filter_from_toml_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "release.toml",
+            r#"
+                published = 2020-01-01T00:00:00Z
+                planned = 2020-01-01
+            "#,
+        )]);
+
+        let published = nu!(
+            cwd: dirs.test(),
+            "open release.toml | get published | describe | echo $it"
+        );
+
+        let planned = nu!(
+            cwd: dirs.test(),
+            "open release.toml | get planned | describe | echo $it"
+        );
+
+        assert_eq!(published, "date");
+        assert_eq!(planned, "string");
+    })
+}
+
 #[test]
 fn can_convert_table_to_yaml_text_and_from_yaml_text_back_into_table() {
     let actual = nu!(
@@ -601,6 +846,159 @@ fn can_convert_table_to_yaml_text_and_from_yaml_text_back_into_table() {
     assert_eq!(actual, "nushell");
 }
 
+#[test]
+fn from_yaml_accepts_documents_nested_up_to_max_depth() {
+    Playground::setup("filter_from_yaml_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "nested.txt",
+            r#"
+                a: {b: 1}
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "open nested.txt | from-yaml --max-depth 2 | get a.b | echo $it"
+        );
+
+        assert_eq!(actual, "1");
+    })
+}
+
+#[test]
+fn from_yaml_rejects_documents_nested_past_max_depth() {
+    Playground::setup("filter_from_yaml_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "nested.txt",
+            r#"
+                a: {b: 1}
+            "#,
+        )]);
+
+        let actual = nu_error!(
+            cwd: dirs.test(),
+            "open nested.txt | from-yaml --max-depth 1"
+        );
+
+        assert!(actual.contains("nested too deeply"));
+    })
+}
+
+#[test]
+fn converts_set_cookie_header_to_row() {
+    Playground::setup("filter_from_cookie_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "set_cookie.txt",
+            r#"
+                Set-Cookie: session=abc123; path=/; EXPIRES=Wed, 21 Oct 2015 07:28:00 GMT; Max-Age=3600; Secure; HttpOnly; bogus
+            "#,
+        )]);
+
+        let session = nu!(
+            cwd: dirs.test(),
+            "open set_cookie.txt | from-cookie | get session | echo $it"
+        );
+
+        let path = nu!(
+            cwd: dirs.test(),
+            "open set_cookie.txt | from-cookie | get Path | echo $it"
+        );
+
+        let expires = nu!(
+            cwd: dirs.test(),
+            "open set_cookie.txt | from-cookie | get Expires | describe | echo $it"
+        );
+
+        let strict = nu_error!(
+            cwd: dirs.test(),
+            "open set_cookie.txt | from-cookie --strict"
+        );
+
+        assert_eq!(session, "abc123");
+        assert_eq!(path, "/");
+        assert_eq!(expires, "date");
+        assert!(strict.contains("Malformed cookie"));
+    })
+}
+
+#[test]
+fn can_encode_and_decode_cookies() {
+    let actual = nu!(
+        cwd: ".",
+        r#"echo "session=abc123; theme=dark; path=/; httponly" | from-cookie | to-cookie | echo $it"#
+    );
+
+    assert_eq!(actual, "session=abc123; theme=dark; Path=/; HttpOnly");
+}
+
+#[test]
+fn converts_from_csv_detecting_tabs() {
+    Playground::setup("filter_from_csv_auto_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "members.txt",
+            "first_name\tlast_name\trusty_luck\n\
+             Andrés\tRobalino, Jr\t1\n\
+             Jonathan\tTurner\t1",
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open members.txt
+                | from-csv --auto
+                | get last_name
+                | str-collect --separator "/"
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "Robalino, Jr/Turner");
+    })
+}
+
+#[test]
+fn converts_from_csv_detecting_semicolons() {
+    Playground::setup("filter_from_csv_auto_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "members.txt",
+            r#"
+                first_name;last_name;city
+                Andrés;Robalino;Guayaquil, EC
+                Jonathan;Turner;Wellington, NZ
+            "#,
+        )]);
+
+        let delimiter = nu!(
+            cwd: dirs.test(),
+            "open members.txt | sniff-delimiter | get delimiter | echo $it"
+        );
+
+        let actual = nu!(
+            cwd: dirs.test(), h::pipeline(
+            r#"
+                open members.txt
+                | from-csv --auto
+                | get city
+                | str-collect --separator "/"
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(delimiter, ";");
+        assert_eq!(actual, "Guayaquil, EC/Wellington, NZ");
+    })
+}
+
+#[test]
+fn from_csv_rejects_a_separator_with_auto() {
+    let actual = nu_error!(
+        cwd: "tests/fixtures/formats",
+        "open caco3_plastics.csv --raw | from-csv --auto --separator ';'"
+    );
+
+    assert!(actual.contains("Can't use --separator with --auto"));
+}
+
 #[test]
 fn can_encode_and_decode_urlencoding() {
     let actual = nu!(
@@ -815,3 +1213,95 @@ fn get() {
         assert_eq!(actual, "Turner");
     })
 }
+
+#[test]
+fn converts_table_to_markdown_with_truncated_cells() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", h::pipeline(
+        r#"
+            open caco3_plastics.csv
+            | first 1
+            | pick origin
+            | to-md --max-col-width 3
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "| origin || --- || SP… |");
+}
+
+#[test]
+fn converts_table_to_fixed_width_text() {
+    Playground::setup("filter_to_fixed_width_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "members.json",
+            r#"
+                [
+                    {"name": "日本", "commits": 10},
+                    {"name": "jonathan", "commits": 200}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "open members.json | to-fixed-width | echo $it"
+        );
+
+        assert_eq!(
+            actual,
+            "name      commits\
+             日本      10\
+             jonathan  200"
+        );
+    })
+}
+
+#[test]
+fn converts_table_to_fixed_width_text_with_numbers_on_the_right() {
+    Playground::setup("filter_to_fixed_width_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "members.json",
+            r#"
+                [
+                    {"name": "日本", "commits": 10},
+                    {"name": "jonathan", "commits": 200}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "open members.json | to-fixed-width --right-align | echo $it"
+        );
+
+        assert_eq!(
+            actual,
+            "name      commits\
+             日本           10\
+             jonathan      200"
+        );
+    })
+}
+
+#[test]
+fn converts_table_to_html_escaping_truncated_cells() {
+    Playground::setup("filter_to_html_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "tags.json",
+            r#"
+                [{"tag": "<b>&</b>"}]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "open tags.json | to-html --max-col-width 5 | echo $it"
+        );
+
+        assert_eq!(
+            actual,
+            "<table><tr><th>tag</th></tr><tr><td>&lt;b&gt;&amp;…</td></tr></table>"
+        );
+    })
+}